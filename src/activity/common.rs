@@ -10,19 +10,20 @@
 //! These are the responses that are in common or have
 //! very high similarity across the Activity endpoints.
 
+use common::DateTime;
 
 /// Represents a subscription, this is returned by
 /// some endpoints in
 /// [Notifications](../notifications/index.html)
 /// and
 /// [Watching](../watching/index.html)
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Subscription {
 
     pub subscribed:     bool,
     pub ignored:        bool,
     pub reason:         Option<String>,
-    pub created_at:     String,
+    pub created_at:     DateTime,
     pub url:            String,
     /// URL to the notification thread, set in the
     /// notifications endpoints
@@ -19,13 +19,14 @@
 use std::collections::BTreeMap;
 use serde_json::Value;
 
-use common::{Repository, User};
+use common::{DateTime, Repository, User};
 use client::Client;
 use error;
+use path::{self, Owner, RepoName};
 use utils;
 
 /// The response of most event requests.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Event {
     #[serde(rename = "type")]
     pub event_type: String,
@@ -34,13 +35,13 @@ pub struct Event {
     pub repo:       Repository,
     pub actor:      User,
     pub org:        Option<Organization>,
-    pub created_at: String,
+    pub created_at: DateTime,
     pub id:         u64
 }
 
 /// Sub-component of the Event response
 //TODO?: move to common?
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Organization {
     pub id:          u64,
     pub login:       String,
@@ -60,7 +61,7 @@ pub trait EventsExt {
     /// Returns the list of public events.
     /// ## Endpoint:
     /// GET /events
-    fn get_events(&mut self) -> Result<Vec<Event>, error::Error>;
+    fn get_events(&self) -> Result<Vec<Event>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/events/#list-repository-events)\]
     /// Returns the list of repository events.
@@ -69,7 +70,7 @@ pub trait EventsExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repo_events(&mut self, owner: String, repo: String) -> Result<Vec<Event>, error::Error>;
+    fn get_repo_events(&self, owner: Owner, repo: RepoName) -> Result<Vec<Event>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/events/#list-public-events-for-a-network-of-repositories)\]
     /// Returns a list public events for a network of repositories.
@@ -78,7 +79,7 @@ pub trait EventsExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_networks_owner_repo_events(&mut self, owner: String, repo: String) -> Result<Vec<Event>, error::Error>;
+    fn get_networks_owner_repo_events(&self, owner: Owner, repo: RepoName) -> Result<Vec<Event>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/events/#list-public-events-for-an-organization)\]
     /// Returns a list public events for an organization.
@@ -86,7 +87,7 @@ pub trait EventsExt {
     /// GET /orgs/:org/events
     /// ## Parameters:
     /// * `org`: Name of the organization
-    fn get_orgs_org_events(&mut self, org: String) -> Result<Vec<Event>, error::Error>;
+    fn get_orgs_org_events(&self, org: String) -> Result<Vec<Event>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/events/#list-events-that-a-user-has-received)\]
     /// Returns a list events that a user has received.
@@ -94,7 +95,7 @@ pub trait EventsExt {
     /// GET /users/:username/received_events
     /// ## Parameters:
     /// * `username`: Name of the user
-    fn get_users_username_received_events(&mut self, username: String) -> Result<Vec<Event>, error::Error>;
+    fn get_users_username_received_events(&self, username: String) -> Result<Vec<Event>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/events/#list-public-events-that-a-user-has-received)\]
     /// Returns a list public events that a user has received.
@@ -102,7 +103,7 @@ pub trait EventsExt {
     /// GET /users/:username/received_events/public
     /// ## Parameters:
     /// * `username`: Name of the user
-    fn get_users_username_received_events_public(&mut self, username: String) -> Result<Vec<Event>, error::Error>;
+    fn get_users_username_received_events_public(&self, username: String) -> Result<Vec<Event>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/events/#list-events-performed-by-a-user)\]
     /// Returns a list events performed by a user.
@@ -110,7 +111,7 @@ pub trait EventsExt {
     /// GET /users/:username/events
     /// ## Parameters:
     /// * `username`: Name of the user
-    fn get_users_username_events(&mut self, username: String) -> Result<Vec<Event>, error::Error>;
+    fn get_users_username_events(&self, username: String) -> Result<Vec<Event>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/events/#list-public-events-performed-by-a-user)\]
     /// Returns a list public events performed by a user.
@@ -118,7 +119,7 @@ pub trait EventsExt {
     /// GET /users/:username/events/public
     /// ## Parameters:
     /// * `username`: Name of the user
-    fn get_users_username_events_public(&mut self, username: String) -> Result<Vec<Event>, error::Error>;
+    fn get_users_username_events_public(&self, username: String) -> Result<Vec<Event>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/events/#list-events-for-an-organization)\]
     /// Returns a list events for an organization.
@@ -127,45 +128,45 @@ pub trait EventsExt {
     /// ## Parameters:
     /// * `username`: Name of the user
     /// * `org`: Name of the organization
-    fn get_users_username_events_orgs_org(&mut self, username: String, org: String) -> Result<Vec<Event>, error::Error>;
+    fn get_users_username_events_orgs_org(&self, username: String, org: String) -> Result<Vec<Event>, error::Error>;
 }
 
 impl EventsExt for Client {
 
-    fn get_events(&mut self) -> Result<Vec<Event>, error::Error> {
+    fn get_events(&self) -> Result<Vec<Event>, error::Error> {
         utils::request_endpoint(self, "/events".into())
     }
 
-    fn get_repo_events(&mut self, owner: String, repo: String) -> Result<Vec<Event>, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/events", owner, repo))
+    fn get_repo_events(&self, owner: Owner, repo: RepoName) -> Result<Vec<Event>, error::Error> {
+        utils::request_endpoint(self, format!("/repos/{}/{}/events", owner.to_path(), repo.to_path()))
     }
 
-    fn get_networks_owner_repo_events(&mut self, owner: String, repo: String) -> Result<Vec<Event>, error::Error> {
-        utils::request_endpoint(self, format!("/networks/{}/{}/events", owner, repo))
+    fn get_networks_owner_repo_events(&self, owner: Owner, repo: RepoName) -> Result<Vec<Event>, error::Error> {
+        utils::request_endpoint(self, format!("/networks/{}/{}/events", owner.to_path(), repo.to_path()))
     }
 
-    fn get_orgs_org_events(&mut self, org: String) -> Result<Vec<Event>, error::Error> {
-        utils::request_endpoint(self, format!("/orgs/{}/events", org))
+    fn get_orgs_org_events(&self, org: String) -> Result<Vec<Event>, error::Error> {
+        utils::request_endpoint(self, format!("/orgs/{}/events", path::escape_segment(&org)))
     }
 
-    fn get_users_username_received_events(&mut self, username: String) -> Result<Vec<Event>, error::Error> {
-        utils::request_endpoint(self, format!("/users/{}/received_events", username))
+    fn get_users_username_received_events(&self, username: String) -> Result<Vec<Event>, error::Error> {
+        utils::request_endpoint(self, format!("/users/{}/received_events", path::escape_segment(&username)))
     }
 
-    fn get_users_username_received_events_public(&mut self, username: String) -> Result<Vec<Event>, error::Error> {
-        utils::request_endpoint(self, format!("/users/{}/received_events/public", username))
+    fn get_users_username_received_events_public(&self, username: String) -> Result<Vec<Event>, error::Error> {
+        utils::request_endpoint(self, format!("/users/{}/received_events/public", path::escape_segment(&username)))
     }
 
-    fn get_users_username_events(&mut self, username: String) -> Result<Vec<Event>, error::Error> {
-        utils::request_endpoint(self, format!("/users/{}/events", username))
+    fn get_users_username_events(&self, username: String) -> Result<Vec<Event>, error::Error> {
+        utils::request_endpoint(self, format!("/users/{}/events", path::escape_segment(&username)))
     }
 
-    fn get_users_username_events_public(&mut self, username: String) -> Result<Vec<Event>, error::Error> {
-        utils::request_endpoint(self, format!("/users/{}/events/public", username))
+    fn get_users_username_events_public(&self, username: String) -> Result<Vec<Event>, error::Error> {
+        utils::request_endpoint(self, format!("/users/{}/events/public", path::escape_segment(&username)))
     }
 
-    fn get_users_username_events_orgs_org(&mut self, username: String, org: String) -> Result<Vec<Event>, error::Error> {
-        utils::request_endpoint(self, format!("/users/{}/events/orgs/{}", username, org))
+    fn get_users_username_events_orgs_org(&self, username: String, org: String) -> Result<Vec<Event>, error::Error> {
+        utils::request_endpoint(self, format!("/users/{}/events/orgs/{}", path::escape_segment(&username), path::escape_segment(&org)))
     }
 }
 
@@ -17,7 +17,7 @@ use error;
 use utils;
 
 ///Response to feeds requests
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Feeds {
     pub timeline_url:                   String,
     pub user_url:                       String,
@@ -30,7 +30,7 @@ pub struct Feeds {
     pub links: Links
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Links {
     pub timeline:                   FeedsElem,
     pub user:                       FeedsElem,
@@ -41,7 +41,7 @@ pub struct Links {
     pub current_user_organizations: Option<FeedsElem>,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct FeedsElem {
     pub href: String,
     #[serde(rename = "type")]
@@ -59,11 +59,11 @@ pub trait FeedsExt {
     /// Returns the list of feeds.
     /// ## Endpoint:
     /// GET /feeds
-    fn get_feeds(&mut self) -> Result<Feeds, error::Error>;
+    fn get_feeds(&self) -> Result<Feeds, error::Error>;
 }
 
 impl FeedsExt for Client {
-    fn get_feeds(&mut self) -> Result<Feeds, error::Error> {
+    fn get_feeds(&self) -> Result<Feeds, error::Error> {
         utils::request_endpoint(self, "/feeds".into())
     }
 }
@@ -12,36 +12,67 @@
 //!
 //! Reference: https://developer.github.com/v3/activity/notifications/
 
-use hyper::{Error as HyperError, Url};
-use serde_json;
+use hyper::method::Method;
 
 use activity::common::Subscription;
-use common::Repository;
+use common::{DateTime, Repository};
 use client::Client;
 use error;
-use utils;
+use path::{Owner, RepoName};
+
+string_enum! {
+    /// Reason for which a notification was received.
+    pub enum Reason {
+        Assign      => "assign",
+        Author      => "author",
+        Comment     => "comment",
+        Invitation  => "invitation",
+        Manual      => "manual",
+        Mention     => "mention",
+        StateChange => "state_change",
+        Subscribed  => "subscribed",
+        TeamMention => "team_mention"
+    }
+}
+
+string_enum! {
+    /// Type of the subject of a notification.
+    pub enum SubjectType {
+        Issue       => "Issue",
+        PullRequest => "PullRequest",
+        Commit      => "Commit",
+        Release     => "Release"
+    }
+}
 
 ///Response to most notifications requests
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Notification {
     pub id:           String,
     pub repository:   Repository,
     pub subject:      Subject,
-    pub reason:       Option<String>,
+    pub reason:       Option<Reason>,
     pub unread:       bool,
-    pub updated_at:   String,
-    pub last_read_at: Option<String>,
+    pub updated_at:   DateTime,
+    pub last_read_at: Option<DateTime>,
     pub url:          String
 }
 
 /// Sub-component of the Notification response
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Subject {
     pub title: String,
     pub url:   String,
     pub latest_comment_url: String,
     #[serde(rename = "type")]
-    pub subject_type: String
+    pub subject_type: SubjectType
+}
+
+///Internal parameter
+#[derive(Serialize)]
+struct SubscriptionParam {
+    subscribed: bool,
+    ignored:    bool
 }
 
 
@@ -61,13 +92,15 @@ pub trait NotificationsExt {
     /// * `participating`: Default: false, Only show
     /// notifications where the user is directly
     /// participating or mentioned.
-    /// * `since`: Name of the repository.
-    /// * `before`: Name of the repository.
-    fn get_notifications(&mut self,
+    /// * `since`: Only show notifications updated after
+    /// this time.
+    /// * `before`: Only show notifications updated before
+    /// this time.
+    fn get_notifications(&self,
                          all: Option<bool>,
                          participating: Option<bool>,
-                         since: Option<String>,
-                         before: Option<String>) -> Result<Vec<Notification>, error::Error>;
+                         since: Option<DateTime>,
+                         before: Option<DateTime>) -> Result<Vec<Notification>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/notifications/#list-your-notifications-in-a-repository)\]
     /// Returns the list of notifications in a repository.
@@ -76,15 +109,17 @@ pub trait NotificationsExt {
     /// ## Parameters:
     /// * `all`: Owner of the repository.
     /// * `participating`: Name of the repository.
-    /// * `since`: Name of the repository.
-    /// * `before`: Name of the repository.
-    fn get_repos_owner_repo_notifications(&mut self,
-                                          owner: String,
-                                          repo: String,
+    /// * `since`: Only show notifications updated after
+    /// this time.
+    /// * `before`: Only show notifications updated before
+    /// this time.
+    fn get_repos_owner_repo_notifications(&self,
+                                          owner: Owner,
+                                          repo: RepoName,
                                           all: Option<bool>,
                                           participating: Option<bool>,
-                                          since: Option<String>,
-                                          before: Option<String>) -> Result<Vec<Notification>, error::Error>;
+                                          since: Option<DateTime>,
+                                          before: Option<DateTime>) -> Result<Vec<Notification>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/notifications/#mark-as-read)\]
     /// Marks notifications as read.
@@ -94,7 +129,7 @@ pub trait NotificationsExt {
     /// * `last_read_at`: Default: "Time.now", The last
     /// point at which notifications were checked in the
     /// ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn put_notifications(&mut self, last_read_at: String) -> Result<(), error::Error>;
+    fn put_notifications(&self, last_read_at: DateTime) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/notifications/#mark-notifications-as-read-in-a-repository)\]
     /// Marks notifications in a repository as read.
@@ -106,7 +141,7 @@ pub trait NotificationsExt {
     /// * `last_read_at`: Default: "Time.now", The last
     /// point at which notifications were checked in the
     /// ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn put_repos_owner_repo_notifications(&mut self, owner: String, repo: String, last_read_at: String) -> Result<(), error::Error>;
+    fn put_repos_owner_repo_notifications(&self, owner: Owner, repo: RepoName, last_read_at: DateTime) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/notifications/#view-a-single-thread)\]
     /// Returns a single thread.
@@ -114,7 +149,7 @@ pub trait NotificationsExt {
     /// GET /notifications/threads/:id
     /// ## Parameters:
     /// * `id`: Thread id.
-    fn get_notifications_threads_id(&mut self, id: String) -> Result<Notification, error::Error>;
+    fn get_notifications_threads_id(&self, id: String) -> Result<Notification, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/notifications/#mark-a-thread-as-read)\]
     /// Marks a single as thread.
@@ -122,7 +157,7 @@ pub trait NotificationsExt {
     /// PATCH /notifications/threads/:id
     /// ## Parameters:
     /// * `id`: Thread id.
-    fn patch_botifications_threads_id(&mut self, id: String) -> Result<(), error::Error>;
+    fn patch_botifications_threads_id(&self, id: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/notifications/#get-a-thread-subscription)\]
     /// Returns a single as thread subscription.
@@ -130,7 +165,7 @@ pub trait NotificationsExt {
     /// GET /notifications/threads/:id/subscription
     /// ## Parameters:
     /// * `id`: Thread id.
-    fn get_notifications_threads_id_subscription(&mut self, id: String) -> Result<Subscription, error::Error>;
+    fn get_notifications_threads_id_subscription(&self, id: String) -> Result<Subscription, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/notifications/#set-a-thread-subscription)\]
     /// Sets a thread subscription.
@@ -140,7 +175,7 @@ pub trait NotificationsExt {
     /// * `id`: Thread id.
     /// * `subscribed`: Determines if notifications should be received from this thread.
     /// * `ignored`: Determines if all notifications should be blocked from this thread.
-    fn put_notifications_threads_id_subscription(&mut self, id: String, subscribed: bool, ignored: bool) -> Result<Subscription, error::Error>;
+    fn put_notifications_threads_id_subscription(&self, id: String, subscribed: bool, ignored: bool) -> Result<Subscription, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/notifications/#delete-a-thread-subscription)\]
     /// Deletes a thread subscription.
@@ -149,157 +184,137 @@ pub trait NotificationsExt {
     /// ## Parameters:
     /// * `id`: Thread id.
     ///Reference:
-    fn delete_notifications_threads_id_subscription(&mut self, id: String) -> Result<(), error::Error>;
+    fn delete_notifications_threads_id_subscription(&self, id: String) -> Result<(), error::Error>;
 
 }
 
 impl NotificationsExt for Client {
 
-    fn get_notifications(&mut self, all: Option<bool>, participating: Option<bool>, since: Option<String>, before: Option<String>) -> Result<Vec<Notification>, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/notifications", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = all {
-                query_pairs.append_pair("all", &format!("{}", param)[..]);
-            }
-
-            if let Some(param) = participating {
-                query_pairs.append_pair("participating", &format!("{}", param)[..]);
-            }
-
-            if let Some(param) = since {
-                query_pairs.append_pair("since", &param[..]);
-            }
-
-            if let Some(param) = before {
-                query_pairs.append_pair("before", &param[..]);
-            }
-        }
-        utils::request_endpoint(self, format!("/notifications?{}", url.query().unwrap()))
+    fn get_notifications(&self, all: Option<bool>, participating: Option<bool>, since: Option<DateTime>, before: Option<DateTime>) -> Result<Vec<Notification>, error::Error> {
+        self.request(Method::Get, "/notifications")
+            .query_opt("all", all)
+            .query_opt("participating", participating)
+            .query_opt("since", since)
+            .query_opt("before", before)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_repos_owner_repo_notifications(&mut self, owner: String, repo: String, all: Option<bool>, participating: Option<bool>, since: Option<String>, before: Option<String>) -> Result<Vec<Notification>, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/repos/{}/{}/notifications", self.api_url, owner, repo)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = all {
-                query_pairs.append_pair("all", &format!("{}", param)[..]);
-            }
-
-            if let Some(param) = participating {
-                query_pairs.append_pair("participating", &format!("{}", param)[..]);
-            }
-
-            if let Some(param) = since {
-                query_pairs.append_pair("since", &param[..]);
-            }
-
-            if let Some(param) = before {
-                query_pairs.append_pair("before", &param[..]);
-            }
-        }
-
-        utils::request_endpoint(self, format!("/repos/{}/{}/notifications?{}", owner, repo, url.query().unwrap()))
+    fn get_repos_owner_repo_notifications(&self, owner: Owner, repo: RepoName, all: Option<bool>, participating: Option<bool>, since: Option<DateTime>, before: Option<DateTime>) -> Result<Vec<Notification>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/notifications")
+            .query_opt("all", all)
+            .query_opt("participating", participating)
+            .query_opt("since", since)
+            .query_opt("before", before)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn put_notifications(&mut self, last_read_at: String) -> Result<(), error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/notifications", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("last_read_at", &last_read_at[..]);
-        }
-
-        match self.put(format!("/notifications?{}", url.query().unwrap()), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn put_notifications(&self, last_read_at: DateTime) -> Result<(), error::Error> {
+        self.request(Method::Put, "/notifications")
+            .query("last_read_at", last_read_at)
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn put_repos_owner_repo_notifications(&mut self, owner: String, repo: String, last_read_at: String) -> Result<(), error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/repos/{}/{}notifications", self.api_url, owner, repo)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("last_read_at", &last_read_at[..]);
-        }
-
-        match self.put(format!("/repos/{}/{}notifications?{}", owner, repo, url.query().unwrap()), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn put_repos_owner_repo_notifications(&self, owner: Owner, repo: RepoName, last_read_at: DateTime) -> Result<(), error::Error> {
+        self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/notifications")
+            .query("last_read_at", last_read_at)
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn get_notifications_threads_id(&mut self, id: String) -> Result<Notification, error::Error> {
-        utils::request_endpoint(self, format!("/notifications/threads/{}", id))
+    fn get_notifications_threads_id(&self, id: String) -> Result<Notification, error::Error> {
+        self.request(Method::Get, "/notifications/threads")
+            .segment(id)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn patch_botifications_threads_id(&mut self, id: String) -> Result<(), error::Error> {
-        match self.patch(format!("/notifications/threads/{}", id), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn patch_botifications_threads_id(&self, id: String) -> Result<(), error::Error> {
+        self.request(Method::Patch, "/notifications/threads")
+            .segment(id)
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn get_notifications_threads_id_subscription(&mut self, id: String) -> Result<Subscription, error::Error> {
-        utils::request_endpoint(self, format!("/notifications/threads/{}/subscription", id))
+    fn get_notifications_threads_id_subscription(&self, id: String) -> Result<Subscription, error::Error> {
+        self.request(Method::Get, "/notifications/threads")
+            .segment(id)
+            .path("/subscription")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn put_notifications_threads_id_subscription(&mut self, id: String, subscribed: bool, ignored: bool) -> Result<Subscription, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/notifications/threads/{}/subscription", self.api_url, id)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
+    fn put_notifications_threads_id_subscription(&self, id: String, subscribed: bool, ignored: bool) -> Result<Subscription, error::Error> {
+        self.request(Method::Put, "/notifications/threads")
+            .segment(id)
+            .path("/subscription")
+            .json(&SubscriptionParam {
+                subscribed: subscribed,
+                ignored:    ignored
+            })
+            .send()
+            .map(|response| response.data)
+    }
 
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("subscribed", &format!("{}", subscribed)[..]);
-            query_pairs.append_pair("ignored",    &format!("{}", ignored)[..]);
-        }
+    fn delete_notifications_threads_id_subscription(&self, id: String) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/notifications/threads")
+            .segment(id)
+            .path("/subscription")
+            .send_empty()
+            .map(|_| ())
+    }
+}
 
-        let mut response     = try!(self.put(format!("/notifications/threads/{}/subscription?{}", id, url.query().unwrap()), None));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+#[cfg(test)]
+mod notifications_test {
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use auth::auth::Auth;
+    use client::Client;
+    use common::DateTime;
+    use path::{Owner, RepoName};
+
+    use super::NotificationsExt;
+
+    #[test]
+    fn put_repos_owner_repo_notifications() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address  = listener.local_addr().unwrap();
+
+        //Answers a single request and returns its request line
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request_line = String::new();
+            {
+                let mut reader = BufReader::new(&stream);
+                let mut line   = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+            }
+            stream.write_all(b"HTTP/1.1 205 Reset Content\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").unwrap();
+            request_line
+        });
 
-    }
+        let client                 = Client::with_url(&format!("http://{}", address), "rustyhub-test/0.0.0", Auth::NoAuth);
+        let owner:        Owner    = "octocat".parse().unwrap();
+        let repo:         RepoName = "Hello-World".parse().unwrap();
+        let last_read_at: DateTime = DateTime::from("2016-01-01T00:00:00Z");
 
-    fn delete_notifications_threads_id_subscription(&mut self, id: String) -> Result<(), error::Error> {
-        match self.delete(format!("/notifications/threads/{}/subscription", id), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+        client.put_repos_owner_repo_notifications(owner, repo, last_read_at).unwrap();
+        assert!(server.join().unwrap().starts_with("PUT /repos/octocat/Hello-World/notifications?last_read_at="));
     }
 }
 
-//TODO:TESTS
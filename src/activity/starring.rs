@@ -12,42 +12,40 @@
 //!
 //! Reference: https://developer.github.com/v3/activity/starring/
 
-use hyper::{Error as HyperError, Url};
-use hyper::header::{Accept, ContentLength, qitem};
-use hyper::mime::{Mime, TopLevel, SubLevel};
+use hyper::method::Method;
 
-use common::{Repository, User};
+use common::{DateTime, Repository, User};
 use client::Client;
 use error;
-use utils;
+use path::{Owner, RepoName};
 
 ///Response returned by the timestamp variant of list stargazers
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListStarTimeStamp {
-    pub starred_at: String,
+    pub starred_at: DateTime,
     pub user:       User
 }
 
 ///Response returned by the timestamp variant of list
 /// stargazers
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListRepoStarTimeStamp {
-    pub starred_at: String,
+    pub starred_at: DateTime,
     pub repo:       Repository
 }
 
-///Enum for input of sorting in get user starred endpoints
-pub enum Sort {
-    Created,
-    Updated
+string_enum! {
+    ///Enum for input of sorting in get user starred endpoints
+    pub enum Sort {
+        Created => "created",
+        Updated => "updated"
+    }
 }
 
-///Enum for input of dorting direction in get user starred
-/// endpoints
-pub enum Direction {
-    Ascending,
-    Descending
-}
+pub use common::Direction;
+
+/// Media type of the timestamp variants of the endpoints.
+const STAR_MEDIA_TYPE: &'static str = "vnd.github.v3.star+json";
 
 
 ////////////////////////////////////////////////////////////
@@ -63,7 +61,7 @@ pub trait StarringExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_stargazers(&mut self, owner: String, repo: String) -> Result<Vec<User>, error::Error>;
+    fn get_repos_owner_repo_stargazers(&self, owner: Owner, repo: RepoName) -> Result<Vec<User>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#list-stargazers)\]
     /// Returns the list of stargazers with timestamp.
@@ -72,7 +70,7 @@ pub trait StarringExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_stargazers_timestamp(&mut self, owner: String, repo: String) -> Result<Vec<ListStarTimeStamp>, error::Error>;
+    fn get_repos_owner_repo_stargazers_timestamp(&self, owner: Owner, repo: RepoName) -> Result<Vec<ListStarTimeStamp>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#list-repositories-being-starred)\]
     /// Returns the list of repositories being starred by
@@ -87,7 +85,7 @@ pub trait StarringExt {
     /// * `direction`: Default: `Direction::Ascending`, sort
     /// in ascending (`Direction::Ascending`) or in
     /// descending (`Direction::Descending`) order.
-    fn get_users_username_starred(&mut self, username: String, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Repository>, error::Error>;
+    fn get_users_username_starred(&self, username: String, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Repository>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#list-repositories-being-starred)\]
     /// Returns the list of repositories with timestamp
@@ -102,7 +100,7 @@ pub trait StarringExt {
     /// * `direction`: Default: `Direction::Ascending`, sort
     /// in ascending (`Direction::Ascending`) or in
     /// descending (`Direction::Descending`) order.
-    fn get_users_username_starred_timestamp(&mut self, username: String, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<ListRepoStarTimeStamp>, error::Error>;
+    fn get_users_username_starred_timestamp(&self, username: String, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<ListRepoStarTimeStamp>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#list-repositories-being-starred)\]
     /// Returns the list of repositories being starred by
@@ -116,7 +114,7 @@ pub trait StarringExt {
     /// * `direction`: Default: `Direction::Ascending`, sort
     /// in ascending (`Direction::Ascending`) or in
     /// descending (`Direction::Descending`) order.
-    fn get_user_starred(&mut self, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Repository>, error::Error>;
+    fn get_user_starred(&self, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Repository>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#list-repositories-being-starred)\]
     /// Returns the list of repositories with timestamp
@@ -130,7 +128,7 @@ pub trait StarringExt {
     /// * `direction`: Default: `Direction::Ascending`, sort
     /// in ascending (`Direction::Ascending`) or in
     /// descending (`Direction::Descending`) order.
-    fn get_user_starred_timestamp(&mut self, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<ListRepoStarTimeStamp>, error::Error>;
+    fn get_user_starred_timestamp(&self, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<ListRepoStarTimeStamp>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#check-if-you-are-starring-a-repository)\]
     /// Returns whether an authenticated user is starring a
@@ -143,7 +141,7 @@ pub trait StarringExt {
     /// ## Return Values:
     /// * If repository is starred: returns Ok(())
     /// * If repository is not starred: returns Error::Github
-    fn get_user_starred_owner_repo(&mut self, owner: String, repo: String) -> Result<(), error::Error>;
+    fn get_user_starred_owner_repo(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#star-a-repository)\]
     /// Stars a repository.
@@ -152,7 +150,7 @@ pub trait StarringExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn put_user_starred_owner_repo(&mut self, owner: String, repo: String) -> Result<(), error::Error>;
+    fn put_user_starred_owner_repo(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#unstar-a-repository)\]
     /// Unstars a repository.
@@ -161,188 +159,91 @@ pub trait StarringExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn delete_user_starred_owner_repo(&mut self, owner: String, repo: String) -> Result<(), error::Error>;
+    fn delete_user_starred_owner_repo(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error>;
 }
 
 impl StarringExt for Client {
 
-    fn get_repos_owner_repo_stargazers(&mut self, owner: String, repo: String) -> Result<Vec<User>, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/stargazers", owner, repo))
+    fn get_repos_owner_repo_stargazers(&self, owner: Owner, repo: RepoName) -> Result<Vec<User>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/stargazers")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_repos_owner_repo_stargazers_timestamp(&mut self, owner: String, repo: String) -> Result<Vec<ListStarTimeStamp>, error::Error> {
-
-        let mut header = self.get_default_headers();
-        header.remove::<Accept>();
-        header.set(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.github.v3.star+json".to_string()), vec![]))]));
-
-        utils::request_endpoint_with_headers(self, format!("/repos/{}/{}/stargazers", owner, repo), Some(header))
+    fn get_repos_owner_repo_stargazers_timestamp(&self, owner: Owner, repo: RepoName) -> Result<Vec<ListStarTimeStamp>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/stargazers")
+            .accept(STAR_MEDIA_TYPE)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_users_username_starred(&mut self, username: String, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Repository>, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/users/{}/starred", self.api_url, username)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = sort {
-                let sorting = match param {
-                    Sort::Created => "created",
-                    Sort::Updated => "updated",
-                };
-                query_pairs.append_pair("sort", &sorting[..]);
-            }
-
-            if let Some(param) = direction {
-                let dir = match param {
-                    Direction::Ascending  => "asc",
-                    Direction::Descending => "desc",
-                };
-                query_pairs.append_pair("direction", &dir[..]);
-            }
-
-        }
-
-        utils::request_endpoint(self, format!("/users/{}/starred?{}", username, url.query().unwrap()))
+    fn get_users_username_starred(&self, username: String, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Repository>, error::Error> {
+        self.request(Method::Get, "/users")
+            .segment(username)
+            .path("/starred")
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_user_starred(&mut self, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Repository>, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/user/starred", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = sort {
-                let sorting = match param {
-                    Sort::Created => "created",
-                    Sort::Updated => "updated",
-                };
-                query_pairs.append_pair("sort", &sorting[..]);
-            }
-
-            if let Some(param) = direction {
-                let dir = match param {
-                    Direction::Ascending  => "asc",
-                    Direction::Descending => "desc",
-                };
-                query_pairs.append_pair("direction", &dir[..]);
-            }
-
-        }
-
-        utils::request_endpoint(self, format!("/users/starred?{}", url.query().unwrap()))
-
+    fn get_user_starred(&self, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Repository>, error::Error> {
+        self.request(Method::Get, "/user/starred")
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_users_username_starred_timestamp(&mut self, username: String, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<ListRepoStarTimeStamp>, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/users/{}/starred", self.api_url, username)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = sort {
-                let sorting = match param {
-                    Sort::Created => "created",
-                    Sort::Updated => "updated",
-                };
-                query_pairs.append_pair("sort", &sorting[..]);
-            }
-
-            if let Some(param) = direction {
-                let dir = match param {
-                    Direction::Ascending  => "asc",
-                    Direction::Descending => "desc",
-                };
-                query_pairs.append_pair("direction", &dir[..]);
-            }
-
-        }
-
-        let mut header = self.get_default_headers();
-        header.remove::<Accept>();
-        header.set(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.github.v3.star+json".to_string()), vec![]))]));
-
-        utils::request_endpoint_with_headers(self, format!("/users/{}/starred?{}", username, url.query().unwrap()), Some(header))
-
+    fn get_users_username_starred_timestamp(&self, username: String, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<ListRepoStarTimeStamp>, error::Error> {
+        self.request(Method::Get, "/users")
+            .segment(username)
+            .path("/starred")
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .accept(STAR_MEDIA_TYPE)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_user_starred_timestamp(&mut self, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<ListRepoStarTimeStamp>, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/user/starred", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = sort {
-                let sorting = match param {
-                    Sort::Created => "created",
-                    Sort::Updated => "updated",
-                };
-                query_pairs.append_pair("sort", &sorting[..]);
-            }
-
-            if let Some(param) = direction {
-                let dir = match param {
-                    Direction::Ascending  => "asc",
-                    Direction::Descending => "desc",
-                };
-                query_pairs.append_pair("direction", &dir[..]);
-            }
-
-        }
-
-        let mut header = self.get_default_headers();
-        header.remove::<Accept>();
-        header.set(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.github.v3.star+json".to_string()), vec![]))]));
-
-        utils::request_endpoint_with_headers(self, format!("/user/starred?{}", url.query().unwrap()), Some(header))
-
+    fn get_user_starred_timestamp(&self, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<ListRepoStarTimeStamp>, error::Error> {
+        self.request(Method::Get, "/user/starred")
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .accept(STAR_MEDIA_TYPE)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_user_starred_owner_repo(&mut self, owner: String, repo: String) -> Result<(), error::Error> {
-        utils::request_endpoint(self, format!("/user/starred/{}/{}", owner, repo))
+    fn get_user_starred_owner_repo(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error> {
+        self.request(Method::Get, "/user/starred")
+            .segment(owner)
+            .segment(repo)
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn put_user_starred_owner_repo(&mut self, owner: String, repo: String) -> Result<(), error::Error> {
-
-        let mut header = self.get_default_headers();
-        header.set(ContentLength(0u64));
-
-        match self.get(format!("/user/starred/{}/{}", owner, repo), Some(header)) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn put_user_starred_owner_repo(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error> {
+        self.request(Method::Put, "/user/starred")
+            .segment(owner)
+            .segment(repo)
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn delete_user_starred_owner_repo(&mut self, owner: String, repo: String) -> Result<(), error::Error> {
-        match self.delete(format!("/user/starred/{}/{}", owner, repo), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn delete_user_starred_owner_repo(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/user/starred")
+            .segment(owner)
+            .segment(repo)
+            .send_empty()
+            .map(|_| ())
     }
 }
 
-//TODO: TESTS
+//TODO: TESTS
\ No newline at end of file
@@ -12,13 +12,20 @@
 //!
 //! Reference: https://developer.github.com/v3/activity/watching/
 
-use hyper::{Error as HyperError, Url};
+use hyper::method::Method;
 
 use activity::common::Subscription;
 use client::Client;
 use common::{Repository, User};
 use error;
-use utils;
+use path::{Owner, RepoName};
+
+///Internal parameter
+#[derive(Serialize)]
+struct SubscriptionParam {
+    subscribed: bool,
+    ignored:    bool
+}
 
 
 ////////////////////////////////////////////////////////////
@@ -34,7 +41,7 @@ pub trait WatchingExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_subscribers(&mut self, owner: String, repo: String) -> Result<User, error::Error>;
+    fn get_repos_owner_repo_subscribers(&self, owner: Owner, repo: RepoName) -> Result<User, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/watching/#list-repositories-being-watched)\]
     /// Returns the list of repositories being watched by a
@@ -43,14 +50,14 @@ pub trait WatchingExt {
     /// GET /users/:username/subscriptions
     /// ## Parameters:
     /// * `username`: Name of the user.
-    fn get_users_username_subscription(&mut self, username: String) -> Result<Repository, error::Error>;
+    fn get_users_username_subscription(&self, username: String) -> Result<Repository, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/watching/#list-repositories-being-watched)\]
     /// Returns the list of repositories being watched by an
     /// authenticated user.
     /// ## Endpoint:
     /// GET /user/subscriptions
-    fn get_user_subscription(&mut self) -> Result<Repository, error::Error>;
+    fn get_user_subscription(&self) -> Result<Repository, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/watching/#get-a-repository-subscription)\]
     /// Returns the repository subscription.
@@ -59,7 +66,7 @@ pub trait WatchingExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_subscription(&mut self, owner: String, repo: String) -> Result<Subscription, error::Error>;
+    fn get_repos_owner_repo_subscription(&self, owner: Owner, repo: RepoName) -> Result<Subscription, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/watching/#get-a-repository-subscription)\]
     /// Sets a repository subscription.
@@ -70,7 +77,7 @@ pub trait WatchingExt {
     /// * `repo`: Name of the repository.
     /// * `subscribed`: Determines if notifications should be received from this repository.
     /// * `ignored`: Determines if all notifications should be blocked from this repository.
-    fn put_repos_owner_repo_subscription(&mut self, owner: String, repo: String, subscribed: bool, ignored: bool) -> Result<Subscription, error::Error>;
+    fn put_repos_owner_repo_subscription(&self, owner: Owner, repo: RepoName, subscribed: bool, ignored: bool) -> Result<Subscription, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/watching/#get-a-repository-subscription)\]
     /// Deletes a repository subscription/ unfollows
@@ -80,50 +87,63 @@ pub trait WatchingExt {
     /// ## Parameters:
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn delete_repos_owner_subscription(&mut self, owner: String, repo: String) -> Result<(), error::Error>;
+    fn delete_repos_owner_subscription(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error>;
 }
 
 impl WatchingExt for Client {
 
-    fn get_repos_owner_repo_subscribers(&mut self, owner: String, repo: String) -> Result<User, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/subscribers", owner, repo))
+    fn get_repos_owner_repo_subscribers(&self, owner: Owner, repo: RepoName) -> Result<User, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/subscribers")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_users_username_subscription(&mut self, username: String) -> Result<Repository, error::Error> {
-        utils::request_endpoint(self, format!("/users/{}/subscriptions", username))
+    fn get_users_username_subscription(&self, username: String) -> Result<Repository, error::Error> {
+        self.request(Method::Get, "/users")
+            .segment(username)
+            .path("/subscriptions")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_user_subscription(&mut self) -> Result<Repository, error::Error> {
-        utils::request_endpoint(self, "/user/subscriptions".into())
+    fn get_user_subscription(&self) -> Result<Repository, error::Error> {
+        self.request(Method::Get, "/user/subscriptions")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_repos_owner_repo_subscription(&mut self, owner: String, repo: String) -> Result<Subscription, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/subscription", owner, repo))
+    fn get_repos_owner_repo_subscription(&self, owner: Owner, repo: RepoName) -> Result<Subscription, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/subscription")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn put_repos_owner_repo_subscription(&mut self, owner: String, repo: String, subscribed: bool, ignored: bool) -> Result<Subscription, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/repos/{}/{}/subscription", self.api_url, owner, repo)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("subscribed", &format!("{}", subscribed)[..]);
-            query_pairs.append_pair("ignored",    &format!("{}", ignored)[..]);
-        }
-
-        utils::request_endpoint(self, format!("/repos/{}/{}/subscription?{}", owner, repo, url.query().unwrap()))
+    fn put_repos_owner_repo_subscription(&self, owner: Owner, repo: RepoName, subscribed: bool, ignored: bool) -> Result<Subscription, error::Error> {
+        self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/subscription")
+            .json(&SubscriptionParam {
+                subscribed: subscribed,
+                ignored:    ignored
+            })
+            .send()
+            .map(|response| response.data)
     }
 
-    fn delete_repos_owner_subscription(&mut self, owner: String, repo: String) -> Result<(), error::Error> {
-        match self.delete(format!("/repos/{}/{}/subscription", owner, repo), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn delete_repos_owner_subscription(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/subscription")
+            .send_empty()
+            .map(|_| ())
     }
 }
 
@@ -27,6 +27,11 @@ pub enum Auth {
     /// * Client ID.
     /// * Client secret.
     OAuth2KeySecret(String, String),
+    /// Use the Github API authenticated as an installation
+    /// of a Github App, ex. to create check runs.
+    /// ## Parameters
+    /// * Installation access token.
+    InstallationToken(String),
     /// Use the Github API authenticated with a username
     /// password and optionally with a a two-factor
     /// OTP
@@ -0,0 +1,56 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Checks
+//!
+//! Modules for subsections of Checks in the Github API docs,
+//! available with the `preview-checks` feature. Check runs
+//! and check suites are created by Github Apps, the client
+//! should use `Auth::InstallationToken`.
+//!
+//! Reference: https://developer.github.com/v3/checks/
+
+use common::{DateTime, User};
+
+/// Endpoints for check runs.
+pub mod runs;
+/// Endpoints for check suites.
+pub mod suites;
+
+string_enum! {
+    /// Status of a check run or a check suite.
+    pub enum CheckStatus {
+        Queued     => "queued",
+        InProgress => "in_progress",
+        Completed  => "completed"
+    }
+}
+
+string_enum! {
+    /// Conclusion of a completed check run or check suite.
+    pub enum CheckConclusion {
+        Success        => "success",
+        Failure        => "failure",
+        Neutral        => "neutral",
+        Cancelled      => "cancelled",
+        TimedOut       => "timed_out",
+        ActionRequired => "action_required"
+    }
+}
+
+/// Github App which created a check run or a check suite.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct App {
+    pub id:           u64,
+    pub owner:        Option<User>,
+    pub name:         String,
+    pub description:  Option<String>,
+    pub external_url: Option<String>,
+    pub html_url:     String,
+    pub created_at:   DateTime,
+    pub updated_at:   DateTime
+}
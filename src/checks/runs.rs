@@ -0,0 +1,574 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Check Runs
+//!
+//! These are the responses and API call functions related
+//! to the check runs endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/checks/runs/
+
+use hyper::method::Method;
+
+use checks::{App, CheckConclusion, CheckStatus};
+use client::Client;
+use common::DateTime;
+use error;
+use path::{Owner, RefName, RepoName};
+use preview::Preview;
+
+/// Maximum number of annotations the API accepts in a
+/// request, more annotations are sent with further updates
+/// of the check run.
+pub const MAX_ANNOTATIONS: usize = 50;
+
+string_enum! {
+    /// Level of an annotation.
+    pub enum AnnotationLevel {
+        Notice  => "notice",
+        Warning => "warning",
+        Failure => "failure"
+    }
+}
+
+string_enum! {
+    /// Filter of the check runs of a reference.
+    pub enum Filter {
+        Latest => "latest",
+        All    => "all"
+    }
+}
+
+/// Return type for Check run endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CheckRun {
+    pub id:           u64,
+    pub head_sha:     String,
+    pub external_id:  Option<String>,
+    pub url:          String,
+    pub html_url:     Option<String>,
+    pub details_url:  Option<String>,
+    pub status:       CheckStatus,
+    pub conclusion:   Option<CheckConclusion>,
+    pub started_at:   Option<DateTime>,
+    pub completed_at: Option<DateTime>,
+    pub output:       CheckRunOutputSummary,
+    pub name:         String,
+    pub check_suite:  Option<CheckSuiteId>,
+    pub app:          Option<App>
+}
+
+/// Sub-component of CheckRun
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CheckRunOutputSummary {
+    pub title:             Option<String>,
+    pub summary:           Option<String>,
+    pub text:              Option<String>,
+    pub annotations_count: u64,
+    pub annotations_url:   String
+}
+
+/// Sub-component of CheckRun
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CheckSuiteId {
+    pub id: u64
+}
+
+/// Annotation of a line range of a file, returned by the
+/// list annotations endpoint and sent in the output of a
+/// check run.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Annotation {
+    /// Path of the file, relative to the repository.
+    pub path:             String,
+    pub start_line:       u64,
+    pub end_line:         u64,
+    /// Only used when `start_line` and `end_line` are
+    /// equal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column:     Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column:       Option<u64>,
+    pub annotation_level: AnnotationLevel,
+    pub message:          String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title:            Option<String>,
+    /// Details of the annotation, ex. the raw output of a
+    /// linter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_details:      Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob_href:        Option<String>
+}
+
+impl Annotation {
+
+    /// Creates an annotation of lines of a file.
+    pub fn new(path: &str, start_line: u64, end_line: u64, level: AnnotationLevel, message: &str) -> Annotation {
+        Annotation {
+            path:             path.to_string(),
+            start_line:       start_line,
+            end_line:         end_line,
+            start_column:     None,
+            end_column:       None,
+            annotation_level: level,
+            message:          message.to_string(),
+            title:            None,
+            raw_details:      None,
+            blob_href:        None
+        }
+    }
+}
+
+/// Image shown in the output of a check run.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Image {
+    pub alt:       String,
+    pub image_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption:   Option<String>
+}
+
+/// Output of a check run, any number of annotations can be
+/// given, they are sent by groups of `MAX_ANNOTATIONS`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CheckRunOutput {
+    pub title:       String,
+    /// Summary of the check run, supports Markdown.
+    pub summary:     String,
+    /// Details of the check run, supports Markdown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text:        Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub images:      Vec<Image>
+}
+
+impl CheckRunOutput {
+
+    /// Creates an output with a title and a summary.
+    pub fn new(title: &str, summary: &str) -> CheckRunOutput {
+        CheckRunOutput {
+            title:       title.to_string(),
+            summary:     summary.to_string(),
+            text:        None,
+            annotations: Vec::new(),
+            images:      Vec::new()
+        }
+    }
+
+    /// Sets the details of the output.
+    pub fn text(mut self, text: &str) -> CheckRunOutput {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Adds an annotation to the output.
+    pub fn annotation(mut self, annotation: Annotation) -> CheckRunOutput {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Adds an image to the output.
+    pub fn image(mut self, image: Image) -> CheckRunOutput {
+        self.images.push(image);
+        self
+    }
+
+    //Splits the output in the outputs of the requests, the
+    //first one keeps everything but the annotations past the
+    //maximum, which are sent with the title and summary
+    fn split(mut self) -> (CheckRunOutput, Vec<CheckRunOutput>) {
+
+        let mut rest = Vec::new();
+        if self.annotations.len() > MAX_ANNOTATIONS {
+            let remaining = self.annotations.split_off(MAX_ANNOTATIONS);
+            for annotations in remaining.chunks(MAX_ANNOTATIONS) {
+                let mut output = CheckRunOutput::new(&self.title[..], &self.summary[..]);
+                output.annotations = annotations.to_vec();
+                rest.push(output);
+            }
+        }
+
+        (self, rest)
+    }
+}
+
+/// Parameters for the creation of a check run.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CheckRunParam {
+    /// Name of the check, ex. "lint".
+    pub name:         String,
+    /// SHA of the checked commit.
+    pub head_sha:     String,
+    /// URL of the details of the check on the site of the
+    /// integrator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url:  Option<String>,
+    /// Reference of the check run in the system of the
+    /// integrator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id:  Option<String>,
+    /// Status of the check run, `Queued` if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status:       Option<CheckStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at:   Option<DateTime>,
+    /// Conclusion of the check run, required with a
+    /// `completed_at` or a `Completed` status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion:   Option<CheckConclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output:       Option<CheckRunOutput>
+}
+
+impl CheckRunParam {
+
+    /// Creates the parameters of a queued check run of a
+    /// commit.
+    pub fn new(name: &str, head_sha: &str) -> CheckRunParam {
+        CheckRunParam {
+            name:         name.to_string(),
+            head_sha:     head_sha.to_string(),
+            details_url:  None,
+            external_id:  None,
+            status:       None,
+            started_at:   None,
+            conclusion:   None,
+            completed_at: None,
+            output:       None
+        }
+    }
+}
+
+/// Parameters for the update of a check run, only the
+/// fields that are set are changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct CheckRunEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:         Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url:  Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id:  Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status:       Option<CheckStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at:   Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion:   Option<CheckConclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output:       Option<CheckRunOutput>
+}
+
+impl CheckRunEdit {
+
+    /// Creates an edit that leaves the check run unchanged.
+    pub fn new() -> CheckRunEdit {
+        CheckRunEdit::default()
+    }
+
+    /// Sets the name of the check run.
+    pub fn name(mut self, name: &str) -> CheckRunEdit {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the URL of the details of the check run.
+    pub fn details_url(mut self, details_url: &str) -> CheckRunEdit {
+        self.details_url = Some(details_url.to_string());
+        self
+    }
+
+    /// Sets the reference of the check run in the system
+    /// of the integrator.
+    pub fn external_id(mut self, external_id: &str) -> CheckRunEdit {
+        self.external_id = Some(external_id.to_string());
+        self
+    }
+
+    /// Sets the status of the check run.
+    pub fn status(mut self, status: CheckStatus) -> CheckRunEdit {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the time the check run started.
+    pub fn started_at(mut self, started_at: DateTime) -> CheckRunEdit {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Completes the check run with a conclusion.
+    pub fn conclusion(mut self, conclusion: CheckConclusion) -> CheckRunEdit {
+        self.conclusion = Some(conclusion);
+        self
+    }
+
+    /// Sets the time the check run completed.
+    pub fn completed_at(mut self, completed_at: DateTime) -> CheckRunEdit {
+        self.completed_at = Some(completed_at);
+        self
+    }
+
+    /// Sets the output of the check run.
+    pub fn output(mut self, output: CheckRunOutput) -> CheckRunEdit {
+        self.output = Some(output);
+        self
+    }
+}
+
+///Internal parameter
+#[derive(Deserialize)]
+struct CheckRunList {
+    check_runs: Vec<CheckRun>
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait CheckRunsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/checks/runs/#create-a-check-run)\]
+    /// Creates a check run, the annotations past
+    /// `MAX_ANNOTATIONS` are sent with updates of the check
+    /// run.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/check-runs
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `check_run`: Parameters of the check run.
+    fn post_repos_owner_repo_check_runs(&self, owner: Owner, repo: RepoName, check_run: CheckRunParam) -> Result<CheckRun, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/runs/#update-a-check-run)\]
+    /// Updates a check run, the annotations past
+    /// `MAX_ANNOTATIONS` are sent with further updates.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/check-runs/:check_run_id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `check_run_id`: ID of the check run.
+    /// * `check_run`: Changes to the check run.
+    fn patch_repos_owner_repo_check_runs_check_run_id(&self, owner: Owner, repo: RepoName, check_run_id: u64, check_run: CheckRunEdit) -> Result<CheckRun, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/runs/#get-a-single-check-run)\]
+    /// Returns a check run.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/check-runs/:check_run_id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `check_run_id`: ID of the check run.
+    fn get_repos_owner_repo_check_runs_check_run_id(&self, owner: Owner, repo: RepoName, check_run_id: u64) -> Result<CheckRun, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/runs/#list-check-runs-for-a-specific-ref)\]
+    /// Returns the check runs of a reference.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/commits/:ref/check-runs
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `_ref`: SHA, branch name or tag name.
+    /// * `check_name`: Only returns the check runs with this
+    /// name.
+    /// * `status`: Only returns the check runs with this
+    /// status.
+    /// * `filter`: `Latest` check runs by default or `All`.
+    fn get_repos_owner_repo_commits_ref_check_runs(&self,
+                                                   owner: Owner,
+                                                   repo: RepoName,
+                                                   _ref: RefName,
+                                                   check_name: Option<String>,
+                                                   status: Option<CheckStatus>,
+                                                   filter: Option<Filter>) -> Result<Vec<CheckRun>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/runs/#list-check-runs-in-a-check-suite)\]
+    /// Returns the check runs of a check suite.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/check-suites/:check_suite_id/check-runs
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `check_suite_id`: ID of the check suite.
+    /// * `check_name`: Only returns the check runs with this
+    /// name.
+    /// * `status`: Only returns the check runs with this
+    /// status.
+    /// * `filter`: `Latest` check runs by default or `All`.
+    fn get_repos_owner_repo_check_suites_check_suite_id_check_runs(&self,
+                                                                   owner: Owner,
+                                                                   repo: RepoName,
+                                                                   check_suite_id: u64,
+                                                                   check_name: Option<String>,
+                                                                   status: Option<CheckStatus>,
+                                                                   filter: Option<Filter>) -> Result<Vec<CheckRun>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/runs/#list-annotations-for-a-check-run)\]
+    /// Returns the annotations of a check run.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/check-runs/:check_run_id/annotations
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `check_run_id`: ID of the check run.
+    fn get_repos_owner_repo_check_runs_check_run_id_annotations(&self, owner: Owner, repo: RepoName, check_run_id: u64) -> Result<Vec<Annotation>, error::Error>;
+}
+
+impl CheckRunsExt for Client {
+
+    fn post_repos_owner_repo_check_runs(&self, owner: Owner, repo: RepoName, mut check_run: CheckRunParam) -> Result<CheckRun, error::Error> {
+
+        let rest = match check_run.output.take() {
+            Some(output) => {
+                let (first, rest) = output.split();
+                check_run.output = Some(first);
+                rest
+            },
+            None => Vec::new()
+        };
+
+        let mut created: CheckRun = try!(self.request(Method::Post, "/repos")
+            .segment(owner.clone())
+            .segment(repo.clone())
+            .path("/check-runs")
+            .json(&check_run)
+            .preview(Preview::Checks)
+            .send()
+            .map(|response| response.data));
+
+        //Remaining annotations, at most MAX_ANNOTATIONS each
+        for output in rest {
+            created = try!(self.patch_repos_owner_repo_check_runs_check_run_id(owner.clone(), repo.clone(), created.id, CheckRunEdit::new().output(output)));
+        }
+        Ok(created)
+    }
+
+    fn patch_repos_owner_repo_check_runs_check_run_id(&self, owner: Owner, repo: RepoName, check_run_id: u64, mut check_run: CheckRunEdit) -> Result<CheckRun, error::Error> {
+
+        let mut edits = vec![];
+        match check_run.output.take() {
+            Some(output) => {
+                let (first, rest) = output.split();
+                check_run.output = Some(first);
+                edits.push(check_run);
+                edits.extend(rest.into_iter().map(|output| CheckRunEdit::new().output(output)));
+            },
+            None => edits.push(check_run)
+        }
+
+        let mut updated = None;
+        for edit in edits {
+            updated = Some(try!(self.request(Method::Patch, "/repos")
+                .segment(owner.clone())
+                .segment(repo.clone())
+                .path("/check-runs")
+                .segment(check_run_id)
+                .json(&edit)
+                .preview(Preview::Checks)
+                .send()
+                .map(|response| response.data)));
+        }
+        Ok(updated.expect("at least one edit is sent"))
+    }
+
+    fn get_repos_owner_repo_check_runs_check_run_id(&self, owner: Owner, repo: RepoName, check_run_id: u64) -> Result<CheckRun, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/check-runs")
+            .segment(check_run_id)
+            .preview(Preview::Checks)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_commits_ref_check_runs(&self,
+                                                   owner: Owner,
+                                                   repo: RepoName,
+                                                   _ref: RefName,
+                                                   check_name: Option<String>,
+                                                   status: Option<CheckStatus>,
+                                                   filter: Option<Filter>) -> Result<Vec<CheckRun>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/commits")
+            .tail(_ref.as_str())
+            .path("/check-runs")
+            .query_opt("check_name", check_name)
+            .query_opt("status", status)
+            .query_opt("filter", filter)
+            .query("per_page", 100u32)
+            .preview(Preview::Checks)
+            .send_all_with(|list: CheckRunList| list.check_runs)
+    }
+
+    fn get_repos_owner_repo_check_suites_check_suite_id_check_runs(&self,
+                                                                   owner: Owner,
+                                                                   repo: RepoName,
+                                                                   check_suite_id: u64,
+                                                                   check_name: Option<String>,
+                                                                   status: Option<CheckStatus>,
+                                                                   filter: Option<Filter>) -> Result<Vec<CheckRun>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/check-suites")
+            .segment(check_suite_id)
+            .path("/check-runs")
+            .query_opt("check_name", check_name)
+            .query_opt("status", status)
+            .query_opt("filter", filter)
+            .query("per_page", 100u32)
+            .preview(Preview::Checks)
+            .send_all_with(|list: CheckRunList| list.check_runs)
+    }
+
+    fn get_repos_owner_repo_check_runs_check_run_id_annotations(&self, owner: Owner, repo: RepoName, check_run_id: u64) -> Result<Vec<Annotation>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/check-runs")
+            .segment(check_run_id)
+            .path("/annotations")
+            .query("per_page", 100u32)
+            .preview(Preview::Checks)
+            .send_all()
+    }
+}
+
+#[cfg(test)]
+mod runs_test {
+
+    use super::{Annotation, AnnotationLevel, CheckRunOutput, MAX_ANNOTATIONS};
+
+    #[test]
+    fn output_split() {
+        let mut output = CheckRunOutput::new("Lint", "120 warnings").text("Details");
+        for line in 0..120 {
+            output = output.annotation(Annotation::new("src/lib.rs", line, line, AnnotationLevel::Warning, "unused"));
+        }
+
+        let (first, rest) = output.split();
+        assert!(first.annotations.len() == MAX_ANNOTATIONS);
+        assert!(first.text == Some(String::from("Details")));
+        assert!(rest.len() == 2);
+        assert!(rest[0].annotations.len() == MAX_ANNOTATIONS);
+        assert!(rest[1].annotations.len() == 20);
+        assert!(rest[1].annotations[0].start_line == 100);
+        assert!(rest[1].title == "Lint" && rest[1].text == None);
+    }
+}
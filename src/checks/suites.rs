@@ -0,0 +1,213 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Check Suites
+//!
+//! These are the responses and API call functions related
+//! to the check suites endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/checks/suites/
+
+use hyper::method::Method;
+
+use checks::{App, CheckConclusion, CheckStatus};
+use client::Client;
+use common::{DateTime, Repository};
+use error;
+use path::{Owner, RefName, RepoName};
+use preview::Preview;
+
+/// Return type for Check suite endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CheckSuite {
+    pub id:          u64,
+    pub head_branch: Option<String>,
+    pub head_sha:    String,
+    pub status:      Option<CheckStatus>,
+    pub conclusion:  Option<CheckConclusion>,
+    pub url:         String,
+    pub before:      Option<String>,
+    pub after:       Option<String>,
+    pub app:         Option<App>,
+    pub repository:  Option<Repository>,
+    pub created_at:  Option<DateTime>,
+    pub updated_at:  Option<DateTime>
+}
+
+/// Return type of the check suites preferences endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CheckSuitePreferences {
+    pub preferences: Preferences,
+    pub repository:  Repository
+}
+
+/// Sub-component of CheckSuitePreferences, also the
+/// parameters of the check suites preferences endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Preferences {
+    pub auto_trigger_checks: Vec<AutoTriggerCheck>
+}
+
+/// Whether a check suite is created automatically for the
+/// pushes to the repository, for a Github App.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AutoTriggerCheck {
+    pub app_id:  u64,
+    pub setting: bool
+}
+
+///Internal parameter
+#[derive(Serialize)]
+struct CheckSuiteParam {
+    head_sha: String
+}
+
+///Internal parameter
+#[derive(Deserialize)]
+struct CheckSuiteList {
+    check_suites: Vec<CheckSuite>
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait CheckSuitesExt {
+
+    /// \[[Reference](https://developer.github.com/v3/checks/suites/#get-a-single-check-suite)\]
+    /// Returns a check suite.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/check-suites/:check_suite_id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `check_suite_id`: ID of the check suite.
+    fn get_repos_owner_repo_check_suites_check_suite_id(&self, owner: Owner, repo: RepoName, check_suite_id: u64) -> Result<CheckSuite, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/suites/#list-check-suites-for-a-specific-ref)\]
+    /// Returns the check suites of a reference.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/commits/:ref/check-suites
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `_ref`: SHA, branch name or tag name.
+    /// * `app_id`: Only returns the check suites of this
+    /// Github App.
+    /// * `check_name`: Only returns the check suites having
+    /// a check run with this name.
+    fn get_repos_owner_repo_commits_ref_check_suites(&self,
+                                                     owner: Owner,
+                                                     repo: RepoName,
+                                                     _ref: RefName,
+                                                     app_id: Option<u64>,
+                                                     check_name: Option<String>) -> Result<Vec<CheckSuite>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/suites/#set-preferences-for-check-suites-on-a-repository)\]
+    /// Sets whether check suites are created automatically
+    /// for the pushes to a repository.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/check-suites/preferences
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `preferences`: Settings of the Github Apps.
+    fn patch_repos_owner_repo_check_suites_preferences(&self, owner: Owner, repo: RepoName, preferences: Preferences) -> Result<CheckSuitePreferences, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/suites/#create-a-check-suite)\]
+    /// Creates a check suite for a commit, when they are not
+    /// created automatically.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/check-suites
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `head_sha`: SHA of the commit.
+    fn post_repos_owner_repo_check_suites(&self, owner: Owner, repo: RepoName, head_sha: String) -> Result<CheckSuite, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/checks/suites/#rerequest-check-suite)\]
+    /// Requests the check suite to be run again.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/check-suites/:check_suite_id/rerequest
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `check_suite_id`: ID of the check suite.
+    fn post_repos_owner_repo_check_suites_check_suite_id_rerequest(&self, owner: Owner, repo: RepoName, check_suite_id: u64) -> Result<(), error::Error>;
+}
+
+impl CheckSuitesExt for Client {
+
+    fn get_repos_owner_repo_check_suites_check_suite_id(&self, owner: Owner, repo: RepoName, check_suite_id: u64) -> Result<CheckSuite, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/check-suites")
+            .segment(check_suite_id)
+            .preview(Preview::Checks)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_commits_ref_check_suites(&self,
+                                                     owner: Owner,
+                                                     repo: RepoName,
+                                                     _ref: RefName,
+                                                     app_id: Option<u64>,
+                                                     check_name: Option<String>) -> Result<Vec<CheckSuite>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/commits")
+            .tail(_ref.as_str())
+            .path("/check-suites")
+            .query_opt("app_id", app_id)
+            .query_opt("check_name", check_name)
+            .query("per_page", 100u32)
+            .preview(Preview::Checks)
+            .send_all_with(|list: CheckSuiteList| list.check_suites)
+    }
+
+    fn patch_repos_owner_repo_check_suites_preferences(&self, owner: Owner, repo: RepoName, preferences: Preferences) -> Result<CheckSuitePreferences, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/check-suites/preferences")
+            .json(&preferences)
+            .preview(Preview::Checks)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_check_suites(&self, owner: Owner, repo: RepoName, head_sha: String) -> Result<CheckSuite, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/check-suites")
+            .json(&CheckSuiteParam {
+                head_sha: head_sha
+            })
+            .preview(Preview::Checks)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_check_suites_check_suite_id_rerequest(&self, owner: Owner, repo: RepoName, check_suite_id: u64) -> Result<(), error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/check-suites")
+            .segment(check_suite_id)
+            .path("/rerequest")
+            .preview(Preview::Checks)
+            .send_empty()
+            .map(|_| ())
+    }
+}
+
+//TODO: TESTS
@@ -6,12 +6,13 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use std::io::Read;
+use std::io::{self, Read, Write};
+use std::sync::{Arc, RwLock};
 
 use serde_json;
 
 use hyper::{Error as HyperError, Url};
-use hyper::client::{Client as HyperClient, Body, Response};
+use hyper::client::{Client as HyperClient, Body, RedirectPolicy, Response};
 //use hyper::error::Error as HyperError;
 use hyper::header::{Accept,
                     Authorization,
@@ -25,27 +26,97 @@ use hyper::method::Method;
 use hyper::mime::{Mime, TopLevel, SubLevel};
 use hyper::status::StatusCode;
 
+use std::fmt;
+
 use error;
 use auth::auth::Auth;
+use miscellaneous::meta::MetaExt;
+use miscellaneous::rate_limit::RateLimitElement;
+use preview::{self, Preview};
+use request::RequestBuilder;
+use utils;
 
 /// The default API URL.
 static DEFAULT_API_URL: &'static str = "https://api.github.com";
+/// The default uploads URL, used by endpoints sending files.
+static DEFAULT_UPLOADS_URL: &'static str = "https://uploads.github.com";
+/// The default GraphQL endpoint URL.
+static DEFAULT_GRAPHQL_URL: &'static str = "https://api.github.com/graphql";
+/// Maximum number of redirects followed by a request.
+const MAX_REDIRECTS: usize = 10;
+
+/// Version of a Github Enterprise server, as reported by
+/// the `installed_version` field of the meta endpoint.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ServerVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64
+}
+
+impl ServerVersion {
+
+    /// Creates a version from its components.
+    pub fn new(major: u64, minor: u64, patch: u64) -> ServerVersion {
+        ServerVersion {
+            major: major,
+            minor: minor,
+            patch: patch
+        }
+    }
+
+    /// Parses a version in the `"major.minor.patch"` format,
+    /// missing components are considered to be 0.
+    pub fn parse(version: &str) -> Option<ServerVersion> {
+        let mut components = [0u64; 3];
+        for (index, part) in version.trim().split('.').enumerate() {
+            if index >= components.len() {
+                break;
+            }
+            match part.parse::<u64>() {
+                Ok(value) => components[index] = value,
+                Err(_)    => return None
+            }
+        }
+        Some(ServerVersion::new(components[0], components[1], components[2]))
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
 
 /// Struct with client state, values in this struct may be modified at any time,
 /// the new values will be used from the next API call
 /// Handles averything related to HTTP
-#[derive(Debug)]
+/// The client can be shared across threads, clones are
+/// cheap and share the connection pool, the server version
+/// and the rate limit status.
+#[derive(Clone, Debug)]
 pub struct Client {
 
     /// Internal http client.
-    pub http_client:    HyperClient,
+    pub http_client:    Arc<HyperClient>,
     /// Base URL to the API, can be modified to use the enterprise API.
     pub api_url:        String,
+    /// Base URL used by endpoints uploading files.
+    pub uploads_url:    String,
+    /// URL of the GraphQL endpoint.
+    pub graphql_url:    String,
     /// Value used for the User-Agent key in request headers.
     pub user_agent:     String,
     /// Optional authorization token, acquired from https://github.com/settings/tokens,
     /// should be set to None when purely accessing endpoints requiring no authentication.
     pub authentication: Auth,
+    /// Version of the Github Enterprise server, `None` when
+    /// using github.com or when the version is unknown.
+    server_version:     Arc<RwLock<Option<ServerVersion>>>,
+    /// Rate limit status reported by the last response.
+    rate_limit:         Arc<RwLock<Option<RateLimitElement>>>,
+    /// Preview media types sent with every request.
+    pub previews:       Vec<Preview>
 }
 
 impl Client {
@@ -57,11 +128,111 @@ impl Client {
 
     /// Creates a Client state an API URL other than the default.
     pub fn with_url(url: &str, user_agent: &str, auth: Auth) -> Client {
+        //Redirects are followed by the requests themselves, the
+        //downloads must not send the authentication along
+        let mut http_client = HyperClient::new();
+        http_client.set_redirect_policy(RedirectPolicy::FollowNone);
+
         Client {
-            http_client:     HyperClient::new(),
+            http_client:     Arc::new(http_client),
             api_url:         url.to_string(),
+            uploads_url:     DEFAULT_UPLOADS_URL.to_string(),
+            graphql_url:     DEFAULT_GRAPHQL_URL.to_string(),
             user_agent:      user_agent.to_string(),
-            authentication:  auth
+            authentication:  auth,
+            server_version:  Arc::new(RwLock::new(None)),
+            rate_limit:      Arc::new(RwLock::new(None)),
+            previews:        Vec::new()
+        }
+    }
+
+    /// Creates a Client state for a Github Enterprise server
+    /// and detects the version of the server.
+    /// ## Parameters
+    /// * `hostname`: Hostname of the server, ex.
+    /// "github.example.com", the scheme defaults to https.
+    pub fn enterprise(hostname: &str, user_agent: &str, auth: Auth) -> Result<Client, error::Error> {
+        let client = Client::with_enterprise_host(hostname, user_agent, auth);
+        try!(client.detect_server_version());
+        Ok(client)
+    }
+
+    /// Creates a Client state for a Github Enterprise server
+    /// without contacting it, the server version is left
+    /// unknown.
+    /// ## Parameters
+    /// * `hostname`: Hostname of the server, ex.
+    /// "github.example.com", the scheme defaults to https.
+    pub fn with_enterprise_host(hostname: &str, user_agent: &str, auth: Auth) -> Client {
+
+        let host = hostname.trim_right_matches('/');
+        let base = if host.starts_with("http://") || host.starts_with("https://") {
+            host.to_string()
+        } else {
+            format!("https://{}", host)
+        };
+
+        let mut client = Client::with_url(&format!("{}/api/v3", base)[..], user_agent, auth);
+        client.uploads_url = format!("{}/api/uploads", base);
+        client.graphql_url = format!("{}/api/graphql", base);
+        client
+    }
+
+    /// Queries the meta endpoint for the `installed_version`
+    /// of the server and stores it as the server version.
+    /// github.com does not report a version, in which case
+    /// the server version is set to `None`.
+    pub fn detect_server_version(&self) -> Result<Option<ServerVersion>, error::Error> {
+        let meta    = try!(self.get_meta());
+        let version = meta.installed_version.as_ref().and_then(|version| ServerVersion::parse(&version[..]));
+        self.set_server_version(version);
+        Ok(version)
+    }
+
+    /// Returns the version of the Github Enterprise server,
+    /// `None` when using github.com or when the version is
+    /// unknown.
+    pub fn server_version(&self) -> Option<ServerVersion> {
+        *self.server_version.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Sets the version of the Github Enterprise server,
+    /// shared with all clones of this client.
+    pub fn set_server_version(&self, version: Option<ServerVersion>) {
+        *self.server_version.write().unwrap_or_else(|err| err.into_inner()) = version;
+    }
+
+    /// Returns the core rate limit status reported by the
+    /// last response, `None` if no request was made yet.
+    pub fn rate_limit(&self) -> Option<RateLimitElement> {
+        self.rate_limit.read().unwrap_or_else(|err| err.into_inner()).clone()
+    }
+
+    /// Checks that the server supports an endpoint.
+    /// Always succeeds when the server version is unknown.
+    /// ## Parameters
+    /// * `endpoint`: Endpoint being requested, used in the
+    /// returned error.
+    /// * `required`: First Github Enterprise version
+    /// supporting the endpoint.
+    pub fn require_version(&self, endpoint: &str, required: ServerVersion) -> Result<(), error::Error> {
+        match self.server_version() {
+            Some(installed) if installed < required => {
+                Err(error::Error::Unsupported(error::UnsupportedError {
+                    endpoint:  endpoint.to_string(),
+                    required:  required,
+                    installed: installed
+                }))
+            },
+            _ => Ok(())
+        }
+    }
+
+    /// Sends the media type of a preview with every request
+    /// of the client.
+    pub fn enable_preview(&mut self, preview: Preview) {
+        if !self.previews.contains(&preview) {
+            self.previews.push(preview);
         }
     }
 
@@ -70,6 +241,9 @@ impl Client {
         let mut headers = Headers::new();
         headers.set(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.github.v3+json".to_string()), vec![]))])); //"application/vnd.github.v3+json"
         headers.set(UserAgent(self.user_agent[..].to_owned()));
+        for enabled in &self.previews {
+            preview::add_preview(&mut headers, *enabled);
+        }
         return headers;
     }
 
@@ -77,6 +251,8 @@ impl Client {
     fn get_redirect(source_url: &String, response: &Response) -> Option<String> {
         match response.status {
             StatusCode::MovedPermanently  |
+            StatusCode::Found             |
+            StatusCode::SeeOther          |
             StatusCode::TemporaryRedirect |
             StatusCode::PermanentRedirect =>
                 response.headers.get().map(|&Location(ref loc)| {
@@ -84,7 +260,12 @@ impl Client {
                         info!("{} as been permanently redirected, please notify
                               the rustyhub developer that it has been moved to {}", source_url, loc);
                     }
-                    loc.clone()
+                    //Relative locations are resolved against the
+                    //redirected URL
+                    Url::parse(&source_url[..])
+                        .and_then(|base| base.join(&loc[..]))
+                        .map(|url| url.into_string())
+                        .unwrap_or_else(|_| loc.clone())
                 }),
             _ => None
         }
@@ -94,21 +275,39 @@ impl Client {
         match response.status {
             StatusCode::BadRequest  |
             StatusCode::UnprocessableEntity |
-            StatusCode::NotFound => {
+            StatusCode::NotFound |
+            StatusCode::Conflict => {
                 let body_data = match Client::response_to_string(response) {
                     Ok(data) => data,
                     Err(err) => return Some(err)
                 };
 
+                //Some endpoints answer with an empty body, ex. the
+                //check of a collaborator
+                if body_data.trim().is_empty() {
+                    return Some(error::Error::Status(response.status));
+                }
+
                 match serde_json::from_str(&body_data) {
-                    Ok(err_response) => return Some(error::Error::Github(err_response)),
-                    Err(err)         => return Some(error::Error::Parsing(err))
+                    Ok(err_response) => {
+                        if response.status == StatusCode::Conflict {
+                            return Some(error::Error::Conflict(err_response));
+                        }
+                        return Some(error::Error::Github(err_response));
+                    },
+                    Err(err) => return Some(error::Error::Parsing(err))
                 }
             },
             _ => None
         }
     }
 
+    fn update_rate_limit(&self, response: &Response) {
+        if let Some(rate_limit) = utils::get_rate_limit(&response.headers) {
+            *self.rate_limit.write().unwrap_or_else(|err| err.into_inner()) = Some(rate_limit);
+        }
+    }
+
     ///Utility to extract the body of an API response.
     pub fn response_to_string(response: &mut Response) -> Result<String, error::Error> {
         let mut body_data = String::new();
@@ -151,6 +350,10 @@ impl Client {
                 headers.set(Authorization(Bearer{token: token[..].to_owned()}));
                 Ok(url)
             },
+            &Auth::InstallationToken(ref token) => {
+                headers.set(Authorization(format!("token {}", token)));
+                Ok(url)
+            },
             &Auth::OAuth2KeySecret(ref client_id, ref client_secret) => {
 
                 let mut url_parsed = match Url::parse(&url[..]) {
@@ -192,13 +395,25 @@ impl Client {
     }
 
     fn make_request(&self, method: Method, endpoint: String, headers: Option<Headers>) -> Result<Response, error::Error> {
+        self.request_url(method, format!("{}{}", self.api_url, endpoint), headers)
+    }
+
+    fn make_request_body(&self,
+                         method: Method,
+                         endpoint: String,
+                         headers: Option<Headers>,
+                         body: String) -> Result<Response, error::Error> {
+        self.request_url_body(method, format!("{}{}", self.api_url, endpoint), headers, body)
+    }
+
+    ///Request to a full URL rather than an endpoint of the
+    ///API, ex. the GraphQL endpoint or a URL returned in a
+    ///response.
+    pub fn request_url(&self, method: Method, mut url: String, headers: Option<Headers>) -> Result<Response, error::Error> {
 
         //if no headers use default
         let mut request_header = headers.unwrap_or_else(|| self.get_default_headers());
 
-        //Build full URL
-        let mut url = format!("{}{}", self.api_url, endpoint);
-
         //Set the authentication
         url = try!(Client::set_request_authentication(&self.authentication, url, &mut request_header));
 
@@ -210,13 +425,21 @@ impl Client {
             .map_err(error::Error::HTTP));
 
         //Handle redirects
+        let mut redirects = 0;
         while let Some(loc) = Client::get_redirect(&url, &mut response) {
+            redirects += 1;
+            if redirects > MAX_REDIRECTS {
+                return Err(error::Error::Status(response.status));
+            }
             response = try!(self.http_client.get(&loc[..])
                 .headers(request_header_copy.clone())
                 .send()
                 .map_err(error::Error::HTTP));
+            url = loc;
         }
 
+        self.update_rate_limit(&response);
+
         //Handle error
         if let Some(err) = Client::get_error(&mut response) {
             return Err(err)
@@ -225,17 +448,17 @@ impl Client {
         Ok(response)
     }
 
-    fn make_request_body(&self,
-                         method: Method,
-                         endpoint: String,
-                         headers: Option<Headers>,
-                         body: String) -> Result<Response, error::Error> {
+    ///Request with a body to a full URL rather than an
+    ///endpoint of the API, ex. the GraphQL endpoint or the
+    ///uploads URL.
+    pub fn request_url_body(&self,
+                            method: Method,
+                            mut url: String,
+                            headers: Option<Headers>,
+                            body: String) -> Result<Response, error::Error> {
         //if no headers use default
         let mut request_header = headers.unwrap_or_else(|| self.get_default_headers());
 
-        //Build full URL
-        let mut url = format!("{}{}", self.api_url, endpoint);
-
         //Set the authentication
         url = try!(Client::set_request_authentication(&self.authentication, url, &mut request_header));
 
@@ -250,14 +473,22 @@ impl Client {
             .map_err(error::Error::HTTP));
 
         //Handle redirects
+        let mut redirects = 0;
         while let Some(loc) = Client::get_redirect(&url, &mut response) {
+            redirects += 1;
+            if redirects > MAX_REDIRECTS {
+                return Err(error::Error::Status(response.status));
+            }
             response = try!(self.http_client.request(method.clone(), &loc[..])
                 .headers(request_header_copy.clone())
                 .body(Body::BufBody(&body_copy.clone().into_bytes()[..], body_len))
                 .send()
                 .map_err(error::Error::HTTP));
+            url = loc;
         }
 
+        self.update_rate_limit(&response);
+
         //Handle error
         if let Some(err) = Client::get_error(&mut response) {
             return Err(err)
@@ -266,6 +497,92 @@ impl Client {
         Ok(response)
     }
 
+    ///Request streaming the content of `reader` as its body
+    ///to a full URL, ex. the uploads URL. Redirects are not
+    ///followed since the body can not be sent again.
+    pub fn upload_url<R: Read>(&self,
+                               method: Method,
+                               mut url: String,
+                               headers: Option<Headers>,
+                               reader: &mut R,
+                               length: u64) -> Result<Response, error::Error> {
+        //if no headers use default
+        let mut request_header = headers.unwrap_or_else(|| self.get_default_headers());
+
+        //Set the authentication
+        url = try!(Client::set_request_authentication(&self.authentication, url, &mut request_header));
+
+        let mut response = try!(self.http_client.request(method, &url[..])
+            .headers(request_header)
+            .body(Body::SizedBody(reader, length))
+            .send()
+            .map_err(error::Error::HTTP));
+
+        self.update_rate_limit(&response);
+
+        //Handle error
+        if let Some(err) = Client::get_error(&mut response) {
+            return Err(err)
+        }
+
+        Ok(response)
+    }
+
+    ///Downloads a full URL to `writer`, ex. the archive of a
+    ///migration or the asset of a release, returns the number
+    ///of bytes written. Redirects are followed without the
+    ///authentication, the storage they point to rejects it.
+    pub fn download_url<W: Write>(&self, mut url: String, headers: Option<Headers>, writer: &mut W) -> Result<u64, error::Error> {
+
+        //if no headers use default
+        let mut request_header = headers.unwrap_or_else(|| self.get_default_headers());
+
+        //Set the authentication
+        url = try!(Client::set_request_authentication(&self.authentication, url, &mut request_header));
+
+        let mut response = try!(self.http_client.get(&url[..])
+            .headers(request_header)
+            .send()
+            .map_err(error::Error::HTTP));
+
+        self.update_rate_limit(&response);
+
+        //Handle redirects, without the authentication
+        let mut redirects = 0;
+        while let Some(loc) = Client::get_redirect(&url, &mut response) {
+            redirects += 1;
+            if redirects > MAX_REDIRECTS {
+                return Err(error::Error::Status(response.status));
+            }
+            let mut redirect_header = Headers::new();
+            redirect_header.set(UserAgent(self.user_agent.clone()));
+            response = try!(self.http_client.get(&loc[..])
+                .headers(redirect_header)
+                .send()
+                .map_err(error::Error::HTTP));
+            url = loc;
+        }
+
+        //Handle error
+        if let Some(err) = Client::get_error(&mut response) {
+            return Err(err)
+        }
+        if !response.status.is_success() {
+            return Err(error::Error::Status(response.status));
+        }
+
+        io::copy(&mut response, writer).map_err(error::Error::STDIO)
+    }
+
+
+    ///Starts building a request to an endpoint.
+    ///## Parameters
+    ///* `path`: Literal beginning of the path of the
+    ///endpoint, ex. "/repos", the rest of the path can be
+    ///added with escaped segments.
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        RequestBuilder::new(self, method, path)
+    }
 
     //HTTP Methods
     ///GET request with optionally overridden headers.
@@ -326,19 +643,130 @@ impl Client {
 #[cfg(test)]
 mod client_test {
 
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use hyper::status::StatusCode;
+
+    use auth::auth::Auth;
+    use error;
+    use super::{ServerVersion, MAX_REDIRECTS};
+
+    //Answers `responses` in order, one per connection, and
+    //returns the request line and headers of each request
+    fn serve(responses: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address  = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            responses.into_iter().map(|response| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = String::new();
+                {
+                    let mut reader = BufReader::new(&stream);
+                    let mut line   = String::new();
+                    while reader.read_line(&mut line).unwrap() > 2 {
+                        request.push_str(&line[..]);
+                        line.clear();
+                    }
+                }
+                stream.write_all(response.as_bytes()).unwrap();
+                request
+            }).collect()
+        });
+        (address, server)
+    }
+
     #[test]
     fn client_new() {
-        let client = super::Client::new("rustyhub-test/0.0.0", Some("test-token".to_string()));
-        assert!(client.api_url    == String::from("https://api.github.com"));
-        assert!(client.user_agent == String::from("rustyhub-test/0.0.0"));
-        assert!(client.auth_token == Some(String::from("test-token")));
+        let client = super::Client::new("rustyhub-test/0.0.0", Auth::OAuth2Token("test-token".to_string()));
+        assert!(client.api_url        == String::from("https://api.github.com"));
+        assert!(client.uploads_url    == String::from("https://uploads.github.com"));
+        assert!(client.user_agent     == String::from("rustyhub-test/0.0.0"));
+        assert!(client.authentication == Auth::OAuth2Token(String::from("test-token")));
+        assert!(client.server_version() == None);
+        assert!(client.rate_limit()     == None);
     }
 
     #[test]
     fn client_with_url() {
-        let client = super::Client::with_url("https://api.github.com/", "rustyhub-test/0.0.0", Some("test-token".to_string()));
-        assert!(client.api_url    == String::from("https://api.github.com/"));
-        assert!(client.user_agent == String::from("rustyhub-test/0.0.0"));
-        assert!(client.auth_token == Some(String::from("test-token")));
+        let client = super::Client::with_url("https://api.github.com/", "rustyhub-test/0.0.0", Auth::OAuth2Token("test-token".to_string()));
+        assert!(client.api_url        == String::from("https://api.github.com/"));
+        assert!(client.user_agent     == String::from("rustyhub-test/0.0.0"));
+        assert!(client.authentication == Auth::OAuth2Token(String::from("test-token")));
+    }
+
+    #[test]
+    fn client_with_enterprise_host() {
+        let client = super::Client::with_enterprise_host("github.example.com/", "rustyhub-test/0.0.0", Auth::NoAuth);
+        assert!(client.api_url     == String::from("https://github.example.com/api/v3"));
+        assert!(client.uploads_url == String::from("https://github.example.com/api/uploads"));
+        assert!(client.graphql_url == String::from("https://github.example.com/api/graphql"));
+
+        let client = super::Client::with_enterprise_host("http://github.example.com", "rustyhub-test/0.0.0", Auth::NoAuth);
+        assert!(client.api_url     == String::from("http://github.example.com/api/v3"));
+    }
+
+    #[test]
+    fn server_version_parse() {
+        assert!(ServerVersion::parse("2.8.3")  == Some(ServerVersion::new(2, 8, 3)));
+        assert!(ServerVersion::parse("2.10")   == Some(ServerVersion::new(2, 10, 0)));
+        assert!(ServerVersion::parse("2.x.0")  == None);
+        assert!(ServerVersion::new(2, 10, 0)   >  ServerVersion::new(2, 9, 5));
+    }
+
+    #[test]
+    fn client_require_version() {
+        let client = super::Client::with_enterprise_host("github.example.com", "rustyhub-test/0.0.0", Auth::NoAuth);
+        assert!(client.require_version("GET /test", ServerVersion::new(2, 8, 0)).is_ok());
+
+        //Clones share the server version
+        client.clone().set_server_version(Some(ServerVersion::new(2, 7, 4)));
+        match client.require_version("GET /test", ServerVersion::new(2, 8, 0)) {
+            Err(error::Error::Unsupported(err)) => assert!(err.installed == ServerVersion::new(2, 7, 4)),
+            _ => panic!("expected Error::Unsupported")
+        }
+        assert!(client.require_version("GET /test", ServerVersion::new(2, 7, 0)).is_ok());
+    }
+
+    #[test]
+    fn client_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<super::Client>();
+    }
+
+    #[test]
+    fn client_download_url_redirect() {
+        let (address, server) = serve(vec![
+            "HTTP/1.1 302 Found\r\nLocation: /storage/archive\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nContent-Length: 7\r\nConnection: close\r\n\r\narchive"
+        ]);
+        let client = super::Client::new("rustyhub-test/0.0.0", Auth::OAuth2Token("test-token".to_string()));
+
+        let mut content = Vec::new();
+        assert!(client.download_url(format!("{}/archive", address), None, &mut content).unwrap() == 7);
+        assert!(content == b"archive".to_vec());
+
+        //The relative location is resolved and the redirect
+        //does not send the authentication
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /archive "));
+        assert!(requests[0].contains("test-token"));
+        assert!(requests[1].starts_with("GET /storage/archive "));
+        assert!(!requests[1].contains("test-token"));
+    }
+
+    #[test]
+    fn client_download_url_redirect_limit() {
+        let (address, server) = serve(vec!["HTTP/1.1 302 Found\r\nLocation: /archive\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"; MAX_REDIRECTS + 1]);
+        let client = super::Client::new("rustyhub-test/0.0.0", Auth::NoAuth);
+
+        let mut content: Vec<u8> = Vec::new();
+        match client.download_url(format!("{}/archive", address), None, &mut content) {
+            Err(error::Error::Status(StatusCode::Found)) => (),
+            _ => panic!("expected Error::Status(Found)")
+        }
+        assert!(server.join().unwrap().len() == MAX_REDIRECTS + 1);
     }
 }
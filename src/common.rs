@@ -5,8 +5,79 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de;
+use serde_json::{self, Map, Value};
+
+/// Timestamp of responses and parameters, kept as the ISO
+/// 8601 string sent by the API, ex. "2016-01-31T23:59:59Z".
+/// With the `chrono` feature it can be converted to and from
+/// a `chrono::DateTime<UTC>`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DateTime(String);
+
+impl DateTime {
+
+    /// Returns the timestamp as sent by the API.
+    pub fn as_str(&self) -> &str {
+        &self.0[..]
+    }
+
+    /// Parses the timestamp.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> Result<::chrono::DateTime<::chrono::UTC>, ::chrono::ParseError> {
+        self.0.parse()
+    }
+}
+
+impl<'a> From<&'a str> for DateTime {
+    fn from(value: &'a str) -> DateTime {
+        DateTime(value.to_string())
+    }
+}
+
+impl From<String> for DateTime {
+    fn from(value: String) -> DateTime {
+        DateTime(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<::chrono::DateTime<::chrono::UTC>> for DateTime {
+    fn from(value: ::chrono::DateTime<::chrono::UTC>) -> DateTime {
+        DateTime(value.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+    }
+}
+
+impl ::std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for DateTime {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Deserialize for DateTime {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<DateTime, D::Error> {
+        let value: String = try!(Deserialize::deserialize(deserializer));
+        Ok(DateTime(value))
+    }
+}
+
+string_enum! {
+    /// Sorting direction of returned lists.
+    pub enum Direction {
+        Ascending  => "asc",
+        Descending => "desc"
+    }
+}
+
 ///Common structures found across many Github API responses
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Repository {
     pub id:                u64,
     pub name:              String,
@@ -70,10 +141,11 @@ pub struct Repository {
     pub has_wiki:          Option<bool>,
     pub has_pages:         Option<bool>,
     pub has_downloads:     Option<bool>,
-    pub pushed_at:         Option<String>,
-    pub created_at:        Option<String>,
-    pub updated_at:        Option<String>,
-    pub permissions:       Option<Permissions>
+    pub pushed_at:         Option<DateTime>,
+    pub created_at:        Option<DateTime>,
+    pub updated_at:        Option<DateTime>,
+    pub permissions:       Option<Permissions>,
+    pub license:           Option<LicenseSummary>
 }
 
 /// Sub-component for the Repository and the response type
@@ -83,7 +155,7 @@ pub struct User {
     pub login:               String,
     pub id:                  u64,
     pub avatar_url:          String,
-    pub gravatar_id:         String,
+    pub gravatar_id:         Option<String>,
     pub url:                 String,
     pub html_url:            Option<String>,
     pub followers_url:       Option<String>,
@@ -100,24 +172,277 @@ pub struct User {
     pub site_admin:          Option<bool>
 }
 
+/// Sub-component of the Repository and response element
+/// of the list licenses endpoint, the license is sent with
+/// the `preview-licenses` feature.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LicenseSummary {
+    pub key:      String,
+    pub name:     String,
+    pub spdx_id:  Option<String>,
+    pub url:      Option<String>,
+    pub featured: Option<bool>
+}
+
 /// Sub-component of the Repository
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Permissions {
     pub admin: bool,
     pub push:  bool,
     pub pull:  bool
 }
 
+/// Team of an organization, sub-component of some
+/// responses, ex. the restrictions of a protected branch.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Team {
+    pub id:               u64,
+    pub url:              String,
+    pub name:             String,
+    pub slug:             String,
+    pub description:      Option<String>,
+    pub privacy:          Option<String>,
+    pub permission:       Option<String>,
+    pub members_url:      Option<String>,
+    pub repositories_url: Option<String>
+}
+
 ///Common structures found across some Github API responses.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Comment {
     pub id:         u64,
     pub url:        String,
     pub html_url:   Option<String>,
     pub body:       String,
     pub user:       User,
-    pub created_at: String,
-    pub updated_at: String
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub reactions:  Option<Reactions>
+}
+
+/// Count of the reactions to an issue or a comment, sent
+/// with the `preview-reactions` feature.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Reactions {
+    pub url:         String,
+    pub total_count: u64,
+    #[serde(rename = "+1")]
+    pub plus_one:    u64,
+    #[serde(rename = "-1")]
+    pub minus_one:   u64,
+    pub laugh:       u64,
+    pub confused:    u64,
+    pub heart:       u64,
+    pub hooray:      u64
+}
+
+/// Field of the edit parameters, it makes the difference
+/// between a field left unchanged, which is not sent, and a
+/// field removed, which is sent as null.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Edit<T> {
+    /// The field is left unchanged.
+    Unchanged,
+    /// The field is removed, ex. the milestone of an issue.
+    Null,
+    /// The field is set to the value.
+    Set(T)
+}
+
+impl<T> Edit<T> {
+    /// Returns true if the field is left unchanged, used to
+    /// skip the field when serializing.
+    pub fn is_unchanged(&self) -> bool {
+        match *self {
+            Edit::Unchanged => true,
+            _               => false
+        }
+    }
+}
+
+impl<T> Default for Edit<T> {
+    fn default() -> Edit<T> {
+        Edit::Unchanged
+    }
+}
+
+impl<T> From<T> for Edit<T> {
+    fn from(value: T) -> Edit<T> {
+        Edit::Set(value)
+    }
+}
+
+impl<T: Serialize> Serialize for Edit<T> {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        match *self {
+            Edit::Set(ref value) => value.serialize(serializer),
+            _                    => serializer.serialize_none()
+        }
+    }
 }
 
-//TODO: Tests
+/// A response along with its fields that are not part of
+/// the type yet, ex. `WithExtra<Issue>`, so the response
+/// survives a round trip when new fields are added to the
+/// API. The fields are added back when serializing.
+///
+/// The unknown fields of nested objects are kept under the
+/// key of their object, ex. `{"user": {"node_id": ...}}`.
+/// Optional fields absent from the response are serialized
+/// as `null`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithExtra<T> {
+    pub data:  T,
+    pub extra: Map<String, Value>
+}
+
+//Returns the fields of `map` missing from `known`, the
+//fields of nested objects and arrays are compared too
+fn unknown_fields(map: Map<String, Value>, known: &Map<String, Value>) -> Map<String, Value> {
+
+    let mut unknown = Map::new();
+
+    for (key, value) in map {
+        let value = match known.get(&key) {
+            Some(known_value) => nested_unknown_fields(value, known_value),
+            None              => Some(value)
+        };
+        if let Some(value) = value {
+            unknown.insert(key, value);
+        }
+    }
+
+    unknown
+}
+
+//Returns the unknown fields of a known field, `None` if it
+//has none. The elements of arrays without unknown fields
+//are kept as empty objects so the indices match.
+fn nested_unknown_fields(value: Value, known: &Value) -> Option<Value> {
+    match *known {
+        Value::Object(ref known) => match value {
+            Value::Object(map) => {
+                let unknown = unknown_fields(map, known);
+                if unknown.is_empty() { None } else { Some(Value::Object(unknown)) }
+            },
+            _ => None
+        },
+        Value::Array(ref known) => match value {
+            Value::Array(values) => {
+                let unknown: Vec<Value> = values.into_iter()
+                                                .zip(known)
+                                                .map(|(value, known)| nested_unknown_fields(value, known).unwrap_or_else(|| Value::Object(Map::new())))
+                                                .collect();
+                if unknown.iter().all(|value| *value == Value::Object(Map::new())) { None } else { Some(Value::Array(unknown)) }
+            },
+            _ => None
+        },
+        _ => None
+    }
+}
+
+//Adds the unknown fields back to `map`
+fn add_unknown_fields(map: &mut Map<String, Value>, unknown: &Map<String, Value>) {
+    for (key, unknown_value) in unknown {
+        if !map.contains_key(key) {
+            map.insert(key.clone(), unknown_value.clone());
+        } else if let Some(value) = map.get_mut(key) {
+            add_nested_unknown_fields(value, unknown_value);
+        }
+    }
+}
+
+fn add_nested_unknown_fields(value: &mut Value, unknown: &Value) {
+    match (value, unknown) {
+        (&mut Value::Object(ref mut map), &Value::Object(ref unknown)) => add_unknown_fields(map, unknown),
+        (&mut Value::Array(ref mut values), &Value::Array(ref unknown)) => {
+            for (value, unknown) in values.iter_mut().zip(unknown) {
+                add_nested_unknown_fields(value, unknown);
+            }
+        },
+        _ => ()
+    }
+}
+
+impl<T: Deserialize + Serialize> Deserialize for WithExtra<T> {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<WithExtra<T>, D::Error> {
+
+        let value = try!(Value::deserialize(deserializer));
+        let data: T = try!(serde_json::from_value(value.clone()).map_err(|err| de::Error::custom(err.to_string())));
+
+        //Keeps the fields that are not serialized back
+        let extra = match (value, serde_json::to_value(&data)) {
+            (Value::Object(map), Value::Object(known)) => unknown_fields(map, &known),
+            (Value::Object(map), _)                    => map,
+            _                                          => Map::new()
+        };
+
+        Ok(WithExtra {
+            data:  data,
+            extra: extra
+        })
+    }
+}
+
+impl<T: Serialize> Serialize for WithExtra<T> {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        match serde_json::to_value(&self.data) {
+            Value::Object(mut map) => {
+                add_unknown_fields(&mut map, &self.extra);
+                Value::Object(map).serialize(serializer)
+            },
+            value => value.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod common_test {
+
+    use serde_json::{self, Value};
+
+    use common::{DateTime, WithExtra};
+    use enterprise::admin_stats::AllStats;
+    use issues::Label;
+
+    #[test]
+    fn date_time() {
+        let date_time: DateTime = serde_json::from_str("\"2016-01-31T23:59:59Z\"").unwrap();
+        assert!(date_time.as_str() == "2016-01-31T23:59:59Z");
+        assert!(serde_json::to_string(&date_time).unwrap() == "\"2016-01-31T23:59:59Z\"");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn date_time_chrono() {
+        use chrono::{Datelike, TimeZone, UTC};
+
+        let date_time = DateTime::from("2016-01-31T23:59:59Z");
+        assert!(date_time.to_chrono().unwrap().day() == 31);
+        assert!(DateTime::from(UTC.ymd(2016, 1, 31).and_hms(23, 59, 59)) == date_time);
+    }
+
+    #[test]
+    fn with_extra() {
+        let json = r#"{"color":"f29513","default":true,"description":null,"id":1,"name":"bug","node_id":"MDU6TGFiZWwx","url":"https://api.github.com/repos/octocat/Hello-World/labels/bug"}"#;
+
+        let label: WithExtra<Label> = serde_json::from_str(json).unwrap();
+        assert!(label.data.name == "bug");
+        assert!(label.extra.len() == 1);
+        assert!(label.extra.get("node_id") == Some(&Value::String("MDU6TGFiZWwx".to_string())));
+
+        assert!(serde_json::to_string(&label).unwrap() == json);
+    }
+
+    #[test]
+    fn with_extra_nested() {
+        let json = r#"{"comments":{"total_commit_comments":6,"total_gist_comments":28,"total_issue_comments":366,"total_pull_request_comments":30},"gists":{"private_gists":151,"public_gists":25,"total_gists":178},"hooks":{"active_hooks":23,"inactive_hooks":4,"total_hooks":27},"issues":{"closed_issues":96,"open_issues":83,"total_issues":179},"milestones":{"closed_milestones":1,"open_milestones":6,"total_milestones":7},"orgs":{"disabled_orgs":0,"total_orgs":33,"total_team_members":314,"total_teams":60},"pages":{"total_pages":36},"pulls":{"mergeable_pulls":21,"merged_pulls":60,"total_pulls":86,"unmergeable_pulls":3},"repos":{"archived_repos":2,"fork_repos":18,"org_repos":51,"root_repos":194,"total_pushes":3082,"total_repos":212,"total_wikis":15},"users":{"admin_users":45,"suspended_users":21,"total_users":254}}"#;
+
+        let stats: WithExtra<AllStats> = serde_json::from_str(json).unwrap();
+        assert!(stats.data.repos.total_repos == 212);
+        assert!(stats.extra.len() == 1);
+        assert!(stats.extra.get("repos").and_then(|repos| repos.find("archived_repos")) == Some(&Value::U64(2)));
+
+        assert!(serde_json::to_string(&stats).unwrap() == json);
+    }
+}
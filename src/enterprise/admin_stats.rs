@@ -0,0 +1,342 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Admin Stats
+//!
+//! These are the responses and API call functions related
+//! to the admin stats endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/enterprise/admin_stats/
+
+use std::collections::BTreeMap;
+
+use serde;
+use serde::de;
+
+use client::Client;
+use error;
+use utils;
+
+/// Response to the all stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AllStats {
+    pub repos:      RepoStats,
+    pub hooks:      HookStats,
+    pub pages:      PageStats,
+    pub orgs:       OrgStats,
+    pub users:      UserStats,
+    pub pulls:      PullStats,
+    pub issues:     IssueStats,
+    pub milestones: MilestoneStats,
+    pub gists:      GistStats,
+    pub comments:   CommentStats
+}
+
+/// Response to the repository stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RepoStats {
+    pub total_repos:  u64,
+    pub root_repos:   u64,
+    pub fork_repos:   u64,
+    pub org_repos:    u64,
+    pub total_pushes: u64,
+    pub total_wikis:  u64
+}
+
+/// Response to the hook stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct HookStats {
+    pub total_hooks:    u64,
+    pub active_hooks:   u64,
+    pub inactive_hooks: u64
+}
+
+/// Response to the pages stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PageStats {
+    pub total_pages: u64
+}
+
+/// Response to the organization stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OrgStats {
+    pub total_orgs:         u64,
+    pub disabled_orgs:      u64,
+    pub total_teams:        u64,
+    pub total_team_members: u64
+}
+
+/// Response to the user stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UserStats {
+    pub total_users:     u64,
+    pub admin_users:     u64,
+    pub suspended_users: u64
+}
+
+/// Response to the pull request stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PullStats {
+    pub total_pulls:       u64,
+    pub merged_pulls:      u64,
+    pub mergeable_pulls:   u64,
+    pub unmergeable_pulls: u64
+}
+
+/// Response to the issue stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IssueStats {
+    pub total_issues:  u64,
+    pub open_issues:   u64,
+    pub closed_issues: u64
+}
+
+/// Response to the milestone stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MilestoneStats {
+    pub total_milestones:  u64,
+    pub open_milestones:   u64,
+    pub closed_milestones: u64
+}
+
+/// Response to the gist stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GistStats {
+    pub total_gists:   u64,
+    pub private_gists: u64,
+    pub public_gists:  u64
+}
+
+/// Response to the comment stats endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CommentStats {
+    pub total_commit_comments:       u64,
+    pub total_gist_comments:         u64,
+    pub total_issue_comments:        u64,
+    pub total_pull_request_comments: u64
+}
+
+//The statistics of a single type are wrapped in an object
+//with the type as only key, ex. {"repos": {...}}
+fn request_stats<T: serde::Deserialize>(client: &Client, kind: &'static str) -> Result<T, error::Error> {
+    let body: BTreeMap<String, T> = try!(utils::request_endpoint(client, format!("/enterprise/stats/{}", kind)));
+    unwrap_stats(body, kind)
+}
+
+fn unwrap_stats<T>(mut body: BTreeMap<String, T>, kind: &'static str) -> Result<T, error::Error> {
+    body.remove(kind).ok_or_else(|| error::Error::Parsing(de::Error::missing_field(kind)))
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait AdminStatsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns all statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/all
+    fn get_enterprise_stats_all(&self) -> Result<AllStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns repository statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/repos
+    fn get_enterprise_stats_repos(&self) -> Result<RepoStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns hook statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/hooks
+    fn get_enterprise_stats_hooks(&self) -> Result<HookStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns pages statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/pages
+    fn get_enterprise_stats_pages(&self) -> Result<PageStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns organization statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/orgs
+    fn get_enterprise_stats_orgs(&self) -> Result<OrgStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns user statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/users
+    fn get_enterprise_stats_users(&self) -> Result<UserStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns pull request statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/pulls
+    fn get_enterprise_stats_pulls(&self) -> Result<PullStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns issue statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/issues
+    fn get_enterprise_stats_issues(&self) -> Result<IssueStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns milestone statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/milestones
+    fn get_enterprise_stats_milestones(&self) -> Result<MilestoneStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns gist statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/gists
+    fn get_enterprise_stats_gists(&self) -> Result<GistStats, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/admin_stats/#get-statistics)\]
+    /// Returns comment statistics.
+    /// ## Endpoint:
+    /// GET /enterprise/stats/comments
+    fn get_enterprise_stats_comments(&self) -> Result<CommentStats, error::Error>;
+}
+
+impl AdminStatsExt for Client {
+
+    fn get_enterprise_stats_all(&self) -> Result<AllStats, error::Error> {
+        utils::request_endpoint(self, "/enterprise/stats/all".into())
+    }
+
+    fn get_enterprise_stats_repos(&self) -> Result<RepoStats, error::Error> {
+        request_stats(self, "repos")
+    }
+
+    fn get_enterprise_stats_hooks(&self) -> Result<HookStats, error::Error> {
+        request_stats(self, "hooks")
+    }
+
+    fn get_enterprise_stats_pages(&self) -> Result<PageStats, error::Error> {
+        request_stats(self, "pages")
+    }
+
+    fn get_enterprise_stats_orgs(&self) -> Result<OrgStats, error::Error> {
+        request_stats(self, "orgs")
+    }
+
+    fn get_enterprise_stats_users(&self) -> Result<UserStats, error::Error> {
+        request_stats(self, "users")
+    }
+
+    fn get_enterprise_stats_pulls(&self) -> Result<PullStats, error::Error> {
+        request_stats(self, "pulls")
+    }
+
+    fn get_enterprise_stats_issues(&self) -> Result<IssueStats, error::Error> {
+        request_stats(self, "issues")
+    }
+
+    fn get_enterprise_stats_milestones(&self) -> Result<MilestoneStats, error::Error> {
+        request_stats(self, "milestones")
+    }
+
+    fn get_enterprise_stats_gists(&self) -> Result<GistStats, error::Error> {
+        request_stats(self, "gists")
+    }
+
+    fn get_enterprise_stats_comments(&self) -> Result<CommentStats, error::Error> {
+        request_stats(self, "comments")
+    }
+}
+
+#[cfg(test)]
+mod admin_stats_test {
+
+    use std::collections::BTreeMap;
+
+    use serde;
+    use serde_json;
+
+    use super::{unwrap_stats, AllStats, CommentStats, GistStats, HookStats, IssueStats, MilestoneStats,
+                OrgStats, PageStats, PullStats, RepoStats, UserStats};
+
+    fn stats<T: serde::Deserialize>(body: &str, kind: &'static str) -> T {
+        let body: BTreeMap<String, T> = serde_json::from_str(body).unwrap();
+        unwrap_stats(body, kind).unwrap()
+    }
+
+    #[test]
+    fn all() {
+        let all: AllStats = serde_json::from_str(include_str!("../../tests/fixtures/enterprise/stats_all.json")).unwrap();
+        assert!(all.repos.total_repos == 212);
+        assert!(all.comments.total_pull_request_comments == 30);
+    }
+
+    #[test]
+    fn repos() {
+        let repos: RepoStats = stats(include_str!("../../tests/fixtures/enterprise/stats_repos.json"), "repos");
+        assert!(repos.total_repos == 212 && repos.fork_repos == 18 && repos.total_wikis == 15);
+    }
+
+    #[test]
+    fn hooks() {
+        let hooks: HookStats = stats(include_str!("../../tests/fixtures/enterprise/stats_hooks.json"), "hooks");
+        assert!(hooks.total_hooks == 27 && hooks.inactive_hooks == 4);
+    }
+
+    #[test]
+    fn pages() {
+        let pages: PageStats = stats(include_str!("../../tests/fixtures/enterprise/stats_pages.json"), "pages");
+        assert!(pages.total_pages == 36);
+    }
+
+    #[test]
+    fn orgs() {
+        let orgs: OrgStats = stats(include_str!("../../tests/fixtures/enterprise/stats_orgs.json"), "orgs");
+        assert!(orgs.total_orgs == 33 && orgs.total_team_members == 314);
+    }
+
+    #[test]
+    fn users() {
+        let users: UserStats = stats(include_str!("../../tests/fixtures/enterprise/stats_users.json"), "users");
+        assert!(users.total_users == 254 && users.suspended_users == 21);
+    }
+
+    #[test]
+    fn pulls() {
+        let pulls: PullStats = stats(include_str!("../../tests/fixtures/enterprise/stats_pulls.json"), "pulls");
+        assert!(pulls.total_pulls == 86 && pulls.unmergeable_pulls == 3);
+    }
+
+    #[test]
+    fn issues() {
+        let issues: IssueStats = stats(include_str!("../../tests/fixtures/enterprise/stats_issues.json"), "issues");
+        assert!(issues.total_issues == 179 && issues.closed_issues == 96);
+    }
+
+    #[test]
+    fn milestones() {
+        let milestones: MilestoneStats = stats(include_str!("../../tests/fixtures/enterprise/stats_milestones.json"), "milestones");
+        assert!(milestones.total_milestones == 7 && milestones.closed_milestones == 1);
+    }
+
+    #[test]
+    fn gists() {
+        let gists: GistStats = stats(include_str!("../../tests/fixtures/enterprise/stats_gists.json"), "gists");
+        assert!(gists.total_gists == 178 && gists.public_gists == 25);
+    }
+
+    #[test]
+    fn comments() {
+        let comments: CommentStats = stats(include_str!("../../tests/fixtures/enterprise/stats_comments.json"), "comments");
+        assert!(comments.total_issue_comments == 366 && comments.total_pull_request_comments == 30);
+    }
+
+    #[test]
+    fn missing_kind() {
+        let body: BTreeMap<String, PageStats> = serde_json::from_str(include_str!("../../tests/fixtures/enterprise/stats_pages.json")).unwrap();
+        assert!(unwrap_stats(body, "repos").is_err());
+    }
+}
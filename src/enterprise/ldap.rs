@@ -0,0 +1,149 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # LDAP
+//!
+//! These are the responses and API call functions related
+//! to the LDAP endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/enterprise/ldap/
+
+use serde_json;
+
+use client::Client;
+use enterprise::SyncStatus;
+use error;
+use path;
+
+/// Response to the update LDAP mapping for a user
+/// endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LdapUser {
+    pub ldap_dn:             String,
+    pub login:               String,
+    pub id:                  u64,
+    pub avatar_url:          String,
+    pub gravatar_id:         String,
+    pub url:                 String,
+    pub html_url:            Option<String>,
+    pub followers_url:       Option<String>,
+    pub following_url:       Option<String>,
+    pub gists_url:           Option<String>,
+    pub starred_url:         Option<String>,
+    pub subscriptions_url:   Option<String>,
+    pub organizations_url:   Option<String>,
+    pub repos_url:           Option<String>,
+    pub events_url:          Option<String>,
+    pub received_events_url: Option<String>,
+    #[serde(rename = "type")]
+    pub user_type:           Option<String>,
+    pub site_admin:          Option<bool>
+}
+
+/// Response to the update LDAP mapping for a team
+/// endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LdapTeam {
+    pub ldap_dn:          String,
+    pub id:               u64,
+    pub url:              String,
+    pub name:             String,
+    pub slug:             String,
+    pub description:      Option<String>,
+    pub privacy:          Option<String>,
+    pub permission:       Option<String>,
+    pub members_url:      String,
+    pub repositories_url: String
+}
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct LdapMappingParam {
+    ldap_dn: String
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait LdapExt {
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/ldap/#update-ldap-mapping-for-a-user)\]
+    /// Updates the LDAP mapping of a user.
+    /// ## Endpoint:
+    /// PATCH /admin/ldap/users/:username/mapping
+    /// ## Parameters
+    /// * `username`: Name of the user.
+    /// * `ldap_dn`: Distinguished name of the LDAP entry.
+    fn patch_admin_ldap_users_username_mapping(&self, username: String, ldap_dn: String) -> Result<LdapUser, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/ldap/#sync-ldap-mapping-for-a-user)\]
+    /// Queues a synchronization job for a user.
+    /// ## Endpoint:
+    /// POST /admin/ldap/users/:username/sync
+    /// ## Parameters
+    /// * `username`: Name of the user.
+    fn post_admin_ldap_users_username_sync(&self, username: String) -> Result<SyncStatus, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/ldap/#update-ldap-mapping-for-a-team)\]
+    /// Updates the LDAP mapping of a team.
+    /// ## Endpoint:
+    /// PATCH /admin/ldap/teams/:team_id/mapping
+    /// ## Parameters
+    /// * `team_id`: ID of the team.
+    /// * `ldap_dn`: Distinguished name of the LDAP entry.
+    fn patch_admin_ldap_teams_team_id_mapping(&self, team_id: u64, ldap_dn: String) -> Result<LdapTeam, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/ldap/#sync-ldap-mapping-for-a-team)\]
+    /// Queues a synchronization job for a team.
+    /// ## Endpoint:
+    /// POST /admin/ldap/teams/:team_id/sync
+    /// ## Parameters
+    /// * `team_id`: ID of the team.
+    fn post_admin_ldap_teams_team_id_sync(&self, team_id: u64) -> Result<SyncStatus, error::Error>;
+}
+
+impl LdapExt for Client {
+
+    fn patch_admin_ldap_users_username_mapping(&self, username: String, ldap_dn: String) -> Result<LdapUser, error::Error> {
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&LdapMappingParam{
+            ldap_dn: ldap_dn
+        }).map_err(error::Error::Parsing));
+
+        let mut response     = try!(self.patch_body(format!("/admin/ldap/users/{}/mapping", path::escape_segment(&username)), None, body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+
+    fn post_admin_ldap_users_username_sync(&self, username: String) -> Result<SyncStatus, error::Error> {
+        let mut response     = try!(self.post(format!("/admin/ldap/users/{}/sync", path::escape_segment(&username)), None));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+
+    fn patch_admin_ldap_teams_team_id_mapping(&self, team_id: u64, ldap_dn: String) -> Result<LdapTeam, error::Error> {
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&LdapMappingParam{
+            ldap_dn: ldap_dn
+        }).map_err(error::Error::Parsing));
+
+        let mut response     = try!(self.patch_body(format!("/admin/ldap/teams/{}/mapping", team_id), None, body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+
+    fn post_admin_ldap_teams_team_id_sync(&self, team_id: u64) -> Result<SyncStatus, error::Error> {
+        let mut response     = try!(self.post(format!("/admin/ldap/teams/{}/sync", team_id), None));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+}
+
+//TODO: TESTS
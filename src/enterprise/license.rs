@@ -0,0 +1,103 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # License
+//!
+//! These are the responses and API call functions related
+//! to the license endpoint of the API.
+//!
+//! Reference: https://developer.github.com/v3/enterprise/license/
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use client::Client;
+use common::DateTime;
+use error;
+use utils;
+
+/// Response to the license endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LicenseInfo {
+    pub seats:                 Seats,
+    pub seats_used:            Seats,
+    pub seats_available:       Seats,
+    pub kind:                  String,
+    pub days_until_expiration: u64,
+    pub expire_at:             DateTime
+}
+
+/// Number of seats of a license, given as the string
+/// "unlimited" by the API for unlimited licenses.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Seats {
+    Count(u64),
+    Unlimited
+}
+
+impl Serialize for Seats {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        match *self {
+            Seats::Count(count) => serializer.serialize_u64(count),
+            Seats::Unlimited    => serializer.serialize_str("unlimited")
+        }
+    }
+}
+
+impl Deserialize for Seats {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Seats, D::Error> {
+        match try!(Value::deserialize(deserializer)) {
+            Value::U64(count)                                => Ok(Seats::Count(count)),
+            Value::String(ref seats) if seats == "unlimited" => Ok(Seats::Unlimited),
+            _                                                => Err(de::Error::custom(String::from("expected a number or \"unlimited\"")))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait LicenseExt {
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/license/#get-license-information)\]
+    /// Returns the license information of the server.
+    /// ## Endpoint:
+    /// GET /enterprise/settings/license
+    fn get_enterprise_settings_license(&self) -> Result<LicenseInfo, error::Error>;
+}
+
+impl LicenseExt for Client {
+
+    fn get_enterprise_settings_license(&self) -> Result<LicenseInfo, error::Error> {
+        utils::request_endpoint(self, "/enterprise/settings/license".into())
+    }
+}
+
+#[cfg(test)]
+mod license_test {
+
+    use serde_json;
+
+    use super::{LicenseInfo, Seats};
+
+    #[test]
+    fn seats() {
+        let license: LicenseInfo = serde_json::from_str(include_str!("../../tests/fixtures/enterprise/license.json")).unwrap();
+        assert!(license.seats == Seats::Count(1400));
+        assert!(license.seats_used == Seats::Count(1316));
+        assert!(license.seats_available == Seats::Count(84));
+
+        let license: LicenseInfo = serde_json::from_str(include_str!("../../tests/fixtures/enterprise/license_unlimited.json")).unwrap();
+        assert!(license.seats == Seats::Unlimited);
+        assert!(license.seats_used == Seats::Count(1316));
+        assert!(license.seats_available == Seats::Unlimited);
+        assert!(serde_json::to_string(&license.seats).unwrap() == "\"unlimited\"");
+
+        assert!(serde_json::from_str::<Seats>("\"many\"").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Enterprise
+//!
+//! Modules for subsections of Enterprise in the Github API
+//! docs. These endpoints are only available on Github
+//! Enterprise, see `Client::enterprise`.
+//!
+//! Reference: https://developer.github.com/v3/enterprise/
+
+use hyper::header::{Accept, Headers, qitem};
+use hyper::mime::{Mime, TopLevel, SubLevel};
+
+use client::{Client, ServerVersion};
+
+/// Endpoints for admin stats.
+pub mod admin_stats;
+/// Endpoints for LDAP mappings.
+pub mod ldap;
+/// Endpoints for license information.
+pub mod license;
+/// Endpoints for creating organizations.
+pub mod organizations;
+/// Endpoints for pre-receive environments.
+pub mod pre_receive_environments;
+/// Endpoints for pre-receive hooks.
+pub mod pre_receive_hooks;
+/// Endpoints for user administration.
+pub mod users;
+
+/// Response to the LDAP sync endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SyncStatus {
+    pub status: String
+}
+
+/// First Github Enterprise version supporting the
+/// pre-receive endpoints.
+const PRE_RECEIVE_VERSION: ServerVersion = ServerVersion { major: 2, minor: 6, patch: 0 };
+
+/// Returns the default headers with the Accept header
+/// required by the pre-receive endpoints while in preview.
+fn pre_receive_headers(client: &Client) -> Headers {
+    let mut header = client.get_default_headers();
+    header.remove::<Accept>();
+    header.set(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.github.eye-scream-preview".to_string()), vec![]))]));
+    header
+}
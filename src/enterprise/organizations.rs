@@ -0,0 +1,63 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Organizations
+//!
+//! These are the responses and API call functions related
+//! to the organization administration endpoints of the
+//! API.
+//!
+//! Reference: https://developer.github.com/v3/enterprise/orgs/
+
+use serde_json;
+
+use client::Client;
+use error;
+use organizations::Organization;
+
+/// Input parameters for creating an organization.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OrganizationCreateParam {
+    /// The organization's username.
+    pub login:        String,
+    /// The login of the user who will manage this
+    /// organization.
+    pub admin:        String,
+    /// The organization's display name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_name: Option<String>
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait EnterpriseOrganizationsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/orgs/#create-an-organization)\]
+    /// Creates an organization.
+    /// ## Endpoint:
+    /// POST /admin/organizations
+    /// ## Parameters
+    /// * `org`: Parameters for the new organization.
+    fn post_admin_organizations(&self, org: OrganizationCreateParam) -> Result<Organization, error::Error>;
+}
+
+impl EnterpriseOrganizationsExt for Client {
+
+    fn post_admin_organizations(&self, org: OrganizationCreateParam) -> Result<Organization, error::Error> {
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&org).map_err(error::Error::Parsing));
+
+        let mut response     = try!(self.post_body("/admin/organizations".to_string(), None, body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+}
+
+//TODO: TESTS
@@ -0,0 +1,192 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Pre-receive Environments
+//!
+//! These are the responses and API call functions related
+//! to the pre-receive environments endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/enterprise/pre_receive_environments/
+
+use serde_json;
+
+use client::Client;
+use common::DateTime;
+use enterprise::{pre_receive_headers, PRE_RECEIVE_VERSION};
+use error;
+use utils;
+
+/// Response to the pre-receive environments endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PreReceiveEnvironment {
+    pub id:                  u64,
+    pub name:                String,
+    pub image_url:           String,
+    pub url:                 String,
+    pub html_url:            String,
+    pub default_environment: bool,
+    pub created_at:          DateTime,
+    pub hooks_count:         u64,
+    pub download:            Download
+}
+
+/// Sub-component of PreReceiveEnvironment and the
+/// response to the download status endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Download {
+    pub url:           String,
+    /// `"not_started"`, `"in_progress"`, `"success"` or
+    /// `"failed"`.
+    pub state:         String,
+    pub downloaded_at: Option<DateTime>,
+    pub message:       Option<String>
+}
+
+/// Input parameters for creating and editing a
+/// pre-receive environment.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PreReceiveEnvironmentParam {
+    /// The new pre-receive environment's name.
+    pub name:      String,
+    /// URL from which to download a tarball of this
+    /// environment.
+    pub image_url: String
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait PreReceiveEnvironmentsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_environments/#get-a-single-pre-receive-environment)\]
+    /// Returns a single pre-receive environment.
+    /// ## Endpoint:
+    /// GET /admin/pre-receive-environments/:id
+    /// ## Parameters
+    /// * `id`: ID of the pre-receive environment.
+    fn get_admin_pre_receive_environments_id(&self, id: u64) -> Result<PreReceiveEnvironment, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_environments/#list-pre-receive-environments)\]
+    /// Returns the list of pre-receive environments.
+    /// ## Endpoint:
+    /// GET /admin/pre-receive-environments
+    fn get_admin_pre_receive_environments(&self) -> Result<Vec<PreReceiveEnvironment>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_environments/#create-a-pre-receive-environment)\]
+    /// Creates a pre-receive environment.
+    /// ## Endpoint:
+    /// POST /admin/pre-receive-environments
+    /// ## Parameters
+    /// * `environment`: Parameters of the environment.
+    fn post_admin_pre_receive_environments(&self, environment: PreReceiveEnvironmentParam) -> Result<PreReceiveEnvironment, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_environments/#edit-a-pre-receive-environment)\]
+    /// Edits a pre-receive environment.
+    /// ## Endpoint:
+    /// PATCH /admin/pre-receive-environments/:id
+    /// ## Parameters
+    /// * `id`: ID of the pre-receive environment.
+    /// * `environment`: Parameters of the environment.
+    fn patch_admin_pre_receive_environments_id(&self, id: u64, environment: PreReceiveEnvironmentParam) -> Result<PreReceiveEnvironment, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_environments/#delete-a-pre-receive-environment)\]
+    /// Deletes a pre-receive environment.
+    /// ## Endpoint:
+    /// DELETE /admin/pre-receive-environments/:id
+    /// ## Parameters
+    /// * `id`: ID of the pre-receive environment.
+    fn delete_admin_pre_receive_environments_id(&self, id: u64) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_environments/#get-a-pre-receive-environments-download-status)\]
+    /// Returns the status of the most recent download of
+    /// a pre-receive environment.
+    /// ## Endpoint:
+    /// GET /admin/pre-receive-environments/:id/downloads/latest
+    /// ## Parameters
+    /// * `id`: ID of the pre-receive environment.
+    fn get_admin_pre_receive_environments_id_downloads_latest(&self, id: u64) -> Result<Download, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_environments/#trigger-a-pre-receive-environment-download)\]
+    /// Triggers a new download of a pre-receive
+    /// environment's tarball.
+    /// ## Endpoint:
+    /// POST /admin/pre-receive-environments/:id/downloads
+    /// ## Parameters
+    /// * `id`: ID of the pre-receive environment.
+    fn post_admin_pre_receive_environments_id_downloads(&self, id: u64) -> Result<Download, error::Error>;
+}
+
+impl PreReceiveEnvironmentsExt for Client {
+
+    fn get_admin_pre_receive_environments_id(&self, id: u64) -> Result<PreReceiveEnvironment, error::Error> {
+        try!(self.require_version("GET /admin/pre-receive-environments/:id", PRE_RECEIVE_VERSION));
+        let header = pre_receive_headers(self);
+        utils::request_endpoint_with_headers(self, format!("/admin/pre-receive-environments/{}", id), Some(header))
+    }
+
+    fn get_admin_pre_receive_environments(&self) -> Result<Vec<PreReceiveEnvironment>, error::Error> {
+        try!(self.require_version("GET /admin/pre-receive-environments", PRE_RECEIVE_VERSION));
+        let header = pre_receive_headers(self);
+        utils::request_endpoint_with_headers(self, "/admin/pre-receive-environments".to_string(), Some(header))
+    }
+
+    fn post_admin_pre_receive_environments(&self, environment: PreReceiveEnvironmentParam) -> Result<PreReceiveEnvironment, error::Error> {
+
+        try!(self.require_version("POST /admin/pre-receive-environments", PRE_RECEIVE_VERSION));
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&environment).map_err(error::Error::Parsing));
+
+        let     header       = pre_receive_headers(self);
+        let mut response     = try!(self.post_body("/admin/pre-receive-environments".to_string(), Some(header), body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+
+    fn patch_admin_pre_receive_environments_id(&self, id: u64, environment: PreReceiveEnvironmentParam) -> Result<PreReceiveEnvironment, error::Error> {
+
+        try!(self.require_version("PATCH /admin/pre-receive-environments/:id", PRE_RECEIVE_VERSION));
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&environment).map_err(error::Error::Parsing));
+
+        let     header       = pre_receive_headers(self);
+        let mut response     = try!(self.patch_body(format!("/admin/pre-receive-environments/{}", id), Some(header), body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+
+    fn delete_admin_pre_receive_environments_id(&self, id: u64) -> Result<(), error::Error> {
+
+        try!(self.require_version("DELETE /admin/pre-receive-environments/:id", PRE_RECEIVE_VERSION));
+
+        let header = pre_receive_headers(self);
+        match self.delete(format!("/admin/pre-receive-environments/{}", id), Some(header)) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+
+    fn get_admin_pre_receive_environments_id_downloads_latest(&self, id: u64) -> Result<Download, error::Error> {
+        try!(self.require_version("GET /admin/pre-receive-environments/:id/downloads/latest", PRE_RECEIVE_VERSION));
+        let header = pre_receive_headers(self);
+        utils::request_endpoint_with_headers(self, format!("/admin/pre-receive-environments/{}/downloads/latest", id), Some(header))
+    }
+
+    fn post_admin_pre_receive_environments_id_downloads(&self, id: u64) -> Result<Download, error::Error> {
+
+        try!(self.require_version("POST /admin/pre-receive-environments/:id/downloads", PRE_RECEIVE_VERSION));
+
+        let     header       = pre_receive_headers(self);
+        let mut response     = try!(self.post(format!("/admin/pre-receive-environments/{}/downloads", id), Some(header)));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+}
+
+//TODO: TESTS
@@ -0,0 +1,199 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Pre-receive Hooks
+//!
+//! These are the responses and API call functions related
+//! to the pre-receive hooks endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/enterprise/pre_receive_hooks/
+
+use serde_json;
+
+use client::Client;
+use enterprise::{pre_receive_headers, PRE_RECEIVE_VERSION};
+use enterprise::pre_receive_environments::PreReceiveEnvironment;
+use error;
+use utils;
+
+/// Response to the pre-receive hooks endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PreReceiveHook {
+    pub id:                             u64,
+    pub name:                           String,
+    /// `"enabled"`, `"disabled"` or `"testing"`.
+    pub enforcement:                    String,
+    pub script:                         String,
+    pub script_repository:              ScriptRepository,
+    pub environment:                    PreReceiveEnvironment,
+    pub allow_downstream_configuration: bool
+}
+
+/// Sub-component of PreReceiveHook
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ScriptRepository {
+    pub id:        u64,
+    pub full_name: String,
+    pub url:       String,
+    pub html_url:  String
+}
+
+/// Input parameters for creating a pre-receive hook.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PreReceiveHookCreate {
+    /// The name of the hook.
+    pub name:                           String,
+    /// The script that the hook runs.
+    pub script:                         String,
+    /// The repository where the script is kept.
+    pub script_repository:              ScriptRepositoryParam,
+    /// The pre-receive environment where the script is
+    /// executed.
+    pub environment:                    EnvironmentParam,
+    /// Default: `"disabled"`, the state of enforcement for
+    /// this hook, `"enabled"`, `"disabled"` or `"testing"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforcement:                    Option<String>,
+    /// Default: false, whether enforcement can be
+    /// overridden at the org or repo level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_downstream_configuration: Option<bool>
+}
+
+/// Input parameters for editing a pre-receive hook, fields
+/// set to `None` are left unchanged.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PreReceiveHookEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:                           Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script:                         Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script_repository:              Option<ScriptRepositoryParam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment:                    Option<EnvironmentParam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforcement:                    Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_downstream_configuration: Option<bool>
+}
+
+/// Sub-component of PreReceiveHookCreate and
+/// PreReceiveHookEdit.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ScriptRepositoryParam {
+    /// Full name of the repository, ex. "org/hooks".
+    pub full_name: String
+}
+
+/// Sub-component of PreReceiveHookCreate and
+/// PreReceiveHookEdit.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct EnvironmentParam {
+    /// ID of the pre-receive environment.
+    pub id: u64
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait PreReceiveHooksExt {
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_hooks/#get-a-single-pre-receive-hook)\]
+    /// Returns a single pre-receive hook.
+    /// ## Endpoint:
+    /// GET /admin/pre-receive-hooks/:id
+    /// ## Parameters
+    /// * `id`: ID of the pre-receive hook.
+    fn get_admin_pre_receive_hooks_id(&self, id: u64) -> Result<PreReceiveHook, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_hooks/#list-pre-receive-hooks)\]
+    /// Returns the list of pre-receive hooks.
+    /// ## Endpoint:
+    /// GET /admin/pre-receive-hooks
+    fn get_admin_pre_receive_hooks(&self) -> Result<Vec<PreReceiveHook>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_hooks/#create-a-pre-receive-hook)\]
+    /// Creates a pre-receive hook.
+    /// ## Endpoint:
+    /// POST /admin/pre-receive-hooks
+    /// ## Parameters
+    /// * `hook`: Parameters of the hook.
+    fn post_admin_pre_receive_hooks(&self, hook: PreReceiveHookCreate) -> Result<PreReceiveHook, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_hooks/#edit-a-pre-receive-hook)\]
+    /// Edits a pre-receive hook.
+    /// ## Endpoint:
+    /// PATCH /admin/pre-receive-hooks/:id
+    /// ## Parameters
+    /// * `id`: ID of the pre-receive hook.
+    /// * `hook`: Parameters to be changed.
+    fn patch_admin_pre_receive_hooks_id(&self, id: u64, hook: PreReceiveHookEdit) -> Result<PreReceiveHook, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/enterprise/pre_receive_hooks/#delete-a-pre-receive-hook)\]
+    /// Deletes a pre-receive hook.
+    /// ## Endpoint:
+    /// DELETE /admin/pre-receive-hooks/:id
+    /// ## Parameters
+    /// * `id`: ID of the pre-receive hook.
+    fn delete_admin_pre_receive_hooks_id(&self, id: u64) -> Result<(), error::Error>;
+}
+
+impl PreReceiveHooksExt for Client {
+
+    fn get_admin_pre_receive_hooks_id(&self, id: u64) -> Result<PreReceiveHook, error::Error> {
+        try!(self.require_version("GET /admin/pre-receive-hooks/:id", PRE_RECEIVE_VERSION));
+        let header = pre_receive_headers(self);
+        utils::request_endpoint_with_headers(self, format!("/admin/pre-receive-hooks/{}", id), Some(header))
+    }
+
+    fn get_admin_pre_receive_hooks(&self) -> Result<Vec<PreReceiveHook>, error::Error> {
+        try!(self.require_version("GET /admin/pre-receive-hooks", PRE_RECEIVE_VERSION));
+        let header = pre_receive_headers(self);
+        utils::request_endpoint_with_headers(self, "/admin/pre-receive-hooks".to_string(), Some(header))
+    }
+
+    fn post_admin_pre_receive_hooks(&self, hook: PreReceiveHookCreate) -> Result<PreReceiveHook, error::Error> {
+
+        try!(self.require_version("POST /admin/pre-receive-hooks", PRE_RECEIVE_VERSION));
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&hook).map_err(error::Error::Parsing));
+
+        let     header       = pre_receive_headers(self);
+        let mut response     = try!(self.post_body("/admin/pre-receive-hooks".to_string(), Some(header), body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+
+    fn patch_admin_pre_receive_hooks_id(&self, id: u64, hook: PreReceiveHookEdit) -> Result<PreReceiveHook, error::Error> {
+
+        try!(self.require_version("PATCH /admin/pre-receive-hooks/:id", PRE_RECEIVE_VERSION));
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&hook).map_err(error::Error::Parsing));
+
+        let     header       = pre_receive_headers(self);
+        let mut response     = try!(self.patch_body(format!("/admin/pre-receive-hooks/{}", id), Some(header), body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+
+    fn delete_admin_pre_receive_hooks_id(&self, id: u64) -> Result<(), error::Error> {
+
+        try!(self.require_version("DELETE /admin/pre-receive-hooks/:id", PRE_RECEIVE_VERSION));
+
+        let header = pre_receive_headers(self);
+        match self.delete(format!("/admin/pre-receive-hooks/{}", id), Some(header)) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+//TODO: TESTS
@@ -0,0 +1,180 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Users
+//!
+//! These are the responses and API call functions related
+//! to the user administration endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/users/administration/
+
+use hyper::header::ContentLength;
+use serde_json;
+
+use client::Client;
+use common::DateTime;
+use error;
+use path;
+
+/// Response to the create impersonation OAuth token
+/// endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Authorization {
+    pub id:               u64,
+    pub url:              String,
+    pub app:              App,
+    pub token:            String,
+    pub hashed_token:     Option<String>,
+    pub token_last_eight: Option<String>,
+    pub note:             Option<String>,
+    pub note_url:         Option<String>,
+    pub created_at:       DateTime,
+    pub updated_at:       DateTime,
+    pub scopes:           Vec<String>,
+    pub fingerprint:      Option<String>
+}
+
+/// Sub-component of Authorization
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct App {
+    pub name:      String,
+    pub url:       String,
+    pub client_id: String
+}
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct SuspendParam {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>
+}
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct ScopesParam {
+    scopes: Vec<String>
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait UsersAdministrationExt {
+
+    /// \[[Reference](https://developer.github.com/v3/users/administration/#promote-an-ordinary-user-to-a-site-administrator)\]
+    /// Promotes a user to site administrator.
+    /// ## Endpoint:
+    /// PUT /users/:username/site_admin
+    /// ## Parameters
+    /// * `username`: Name of the user.
+    fn put_users_username_site_admin(&self, username: String) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/users/administration/#demote-a-site-administrator-to-an-ordinary-user)\]
+    /// Demotes a site administrator to an ordinary user.
+    /// ## Endpoint:
+    /// DELETE /users/:username/site_admin
+    /// ## Parameters
+    /// * `username`: Name of the user.
+    fn delete_users_username_site_admin(&self, username: String) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/users/administration/#suspend-a-user)\]
+    /// Suspends a user.
+    /// ## Endpoint:
+    /// PUT /users/:username/suspended
+    /// ## Parameters
+    /// * `username`: Name of the user.
+    /// * `reason`: Reason for the suspension, logged in
+    /// the audit log.
+    fn put_users_username_suspended(&self, username: String, reason: Option<String>) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/users/administration/#unsuspend-a-user)\]
+    /// Unsuspends a user.
+    /// ## Endpoint:
+    /// DELETE /users/:username/suspended
+    /// ## Parameters
+    /// * `username`: Name of the user.
+    fn delete_users_username_suspended(&self, username: String) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/users/administration/#create-an-impersonation-oauth-token)\]
+    /// Creates an impersonation OAuth token for a user.
+    /// ## Endpoint:
+    /// POST /admin/users/:username/authorizations
+    /// ## Parameters
+    /// * `username`: Name of the user.
+    /// * `scopes`: Scopes of the token.
+    fn post_admin_users_username_authorizations(&self, username: String, scopes: Vec<String>) -> Result<Authorization, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/users/administration/#delete-an-impersonation-oauth-token)\]
+    /// Deletes the impersonation OAuth token of a user.
+    /// ## Endpoint:
+    /// DELETE /admin/users/:username/authorizations
+    /// ## Parameters
+    /// * `username`: Name of the user.
+    fn delete_admin_users_username_authorizations(&self, username: String) -> Result<(), error::Error>;
+}
+
+impl UsersAdministrationExt for Client {
+
+    fn put_users_username_site_admin(&self, username: String) -> Result<(), error::Error> {
+
+        let mut header = self.get_default_headers();
+        header.set(ContentLength(0u64));
+
+        match self.put(format!("/users/{}/site_admin", path::escape_segment(&username)), Some(header)) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+
+    fn delete_users_username_site_admin(&self, username: String) -> Result<(), error::Error> {
+        match self.delete(format!("/users/{}/site_admin", path::escape_segment(&username)), None) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+
+    fn put_users_username_suspended(&self, username: String, reason: Option<String>) -> Result<(), error::Error> {
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&SuspendParam{
+            reason: reason
+        }).map_err(error::Error::Parsing));
+
+        match self.put_body(format!("/users/{}/suspended", path::escape_segment(&username)), None, body_data) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+
+    fn delete_users_username_suspended(&self, username: String) -> Result<(), error::Error> {
+        match self.delete(format!("/users/{}/suspended", path::escape_segment(&username)), None) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+
+    fn post_admin_users_username_authorizations(&self, username: String, scopes: Vec<String>) -> Result<Authorization, error::Error> {
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&ScopesParam{
+            scopes: scopes
+        }).map_err(error::Error::Parsing));
+
+        let mut response     = try!(self.post_body(format!("/admin/users/{}/authorizations", path::escape_segment(&username)), None, body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    }
+
+    fn delete_admin_users_username_authorizations(&self, username: String) -> Result<(), error::Error> {
+        match self.delete(format!("/admin/users/{}/authorizations", path::escape_segment(&username)), None) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+//TODO: TESTS
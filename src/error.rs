@@ -8,32 +8,105 @@
 
 use std::io::Error as IOError;
 
+use hyper::status::StatusCode;
+use serde_json::Value;
+
+use client::ServerVersion;
+
 /// Represents all possible errors than can occur while using rustyhub.
 #[derive(Debug)]
 pub enum Error {
 
     /// Error returned by github for invalid API usage.
     Github(GithubError),
+    /// Error returned by github when a request conflicts
+    /// with the state of a resource, ex. a deployment of a
+    /// ref whose required statuses failed.
+    Conflict(GithubError),
+    /// Errors returned by the GraphQL endpoint.
+    GraphQL(Vec<GraphQLError>),
     /// Error returned by hyper when parsing HTTP streams.
     HTTP(::hyper::Error),
+    /// A name used in the path of an endpoint is invalid,
+    /// no request was made.
+    InvalidName(InvalidNameError),
     /// Error returned by serde when parsing JSON data from a string.
     Parsing(::serde_json::Error),
+    /// Unexpected status of a response, ex. of a download.
+    Status(StatusCode),
     /// Errors returned by functions in std
     STDIO(IOError),
+    /// The endpoint is not available on the Github
+    /// Enterprise version of the server.
+    Unsupported(UnsupportedError),
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct GithubError {
     pub message:           String,
     pub documentation_url: Option<String>,
     pub errors:            Option<Vec<GithubErrorErrors>>
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct GithubErrorErrors{
-    pub resources: String,
-    pub field:     String,
-    pub code:      String
+    #[serde(rename = "resource")]
+    pub resources: Option<String>,
+    pub field:     Option<String>,
+    pub code:      Option<String>,
+    /// Message of a custom error.
+    pub message:   Option<String>,
+    /// Statuses of the contexts failing a deployment.
+    pub contexts:  Option<Vec<GithubErrorContext>>
+}
+
+/// Element of the `contexts` of GithubErrorErrors.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GithubErrorContext {
+    pub context: String,
+    pub state:   String
+}
+
+/// Element of the `errors` array of a GraphQL response.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GraphQLError {
+    pub message:    String,
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    /// Path of the field the error is associated to, made
+    /// of field names and list indices.
+    pub path:       Option<Vec<Value>>,
+    pub locations:  Option<Vec<GraphQLErrorLocation>>
+}
+
+/// Sub-component of GraphQLError
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GraphQLErrorLocation {
+    pub line:   u64,
+    pub column: u64
+}
+
+/// Details of a name rejected before making a request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidNameError {
+    /// Kind of the name, ex. "owner" or "reference".
+    pub kind:   String,
+    /// The rejected name.
+    pub name:   String,
+    /// Why the name was rejected.
+    pub reason: String
+}
+
+/// Details of an endpoint unsupported by a Github
+/// Enterprise server.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnsupportedError {
+    /// Endpoint that was requested.
+    pub endpoint:  String,
+    /// First version supporting the endpoint.
+    pub required:  ServerVersion,
+    /// Version installed on the server.
+    pub installed: ServerVersion
 }
 
 //TODO: Tests
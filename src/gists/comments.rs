@@ -12,14 +12,11 @@
 //!
 //! Reference: https://developer.github.com/v3_gists_comments/
 
-use hyper::{Error as HyperError, Url};
-use serde_json;
+use hyper::method::Method;
 
 use client::Client;
 use common::Comment;
-use issues::{Direction, Sort};
 use error;
-use utils;
 
 ///Internal parameter
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -33,13 +30,13 @@ struct CommentBody {
 
 pub trait CommentsExt {
 
-        /// \[[Reference](https://developer.github.com/v3/gists/#list-a-users-gists)\]
+    /// \[[Reference](https://developer.github.com/v3/gists/comments/#list-comments-on-a-gist)\]
     /// Returns the list of comments on a gist.
     /// ## Endpoint:
     /// GET /gists/:gist_id/comments
     /// ## Parameters
     /// * `gist_id`: ID of the gist.
-    fn get_gists_gist_id_comments(&mut self, gist_id: u64) -> Result<Vec<Comment>, error::Error>;
+    fn get_gists_gist_id_comments(&self, gist_id: u64) -> Result<Vec<Comment>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/comments/#get-a-single-comment)\]
     /// Returns a single comment on a gist.
@@ -48,7 +45,7 @@ pub trait CommentsExt {
     /// ## Parameters
     /// * `gist_id`: ID of the gist.
     /// * `id`: ID of the comment.
-    fn get_gists_gist_id_comments_id(&mut self, gist_id: u64, id: u64) -> Result<Comment, error::Error>;
+    fn get_gists_gist_id_comments_id(&self, gist_id: u64, id: u64) -> Result<Comment, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/comments/#create-a-comment)\]
     /// Creates a comment.
@@ -57,7 +54,7 @@ pub trait CommentsExt {
     /// ## Parameters
     /// * `gist_id`: ID of the gist.
     /// * `body`: Contents of the comment.
-    fn post_gists_gist_id_comments(&mut self, gist_id: u64, body: String) -> Result<Comment, error::Error>;
+    fn post_gists_gist_id_comments(&self, gist_id: u64, body: String) -> Result<Comment, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/comments/#edit-a-comment)\]
     /// Edits a comment.
@@ -67,7 +64,7 @@ pub trait CommentsExt {
     /// * `gist_id`: ID of the gist.
     /// * `id`: ID of the comment.
     /// * `body`: Contents of the comment.
-    fn patch_gists_gist_id_comments_id(&mut self, gist_id: u64, id: u64, body: String) -> Result<Comment, error::Error>;
+    fn patch_gists_gist_id_comments_id(&self, gist_id: u64, id: u64, body: String) -> Result<Comment, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/comments/#delete-a-comment)\]
     /// Deletes a comment.
@@ -76,48 +73,58 @@ pub trait CommentsExt {
     /// ## Parameters
     /// * `gist_id`: ID of the gist.
     /// * `id`: ID of the comment.
-    fn delete_gists_gist_id_comments_id(&mut self, gist_id: u64, id: u64) -> Result<(), error::Error>;
+    fn delete_gists_gist_id_comments_id(&self, gist_id: u64, id: u64) -> Result<(), error::Error>;
 }
 
 impl CommentsExt for Client {
 
-    fn get_gists_gist_id_comments(&mut self, gist_id: u64) -> Result<Vec<Comment>, error::Error>{
-        utils::request_endpoint(self, format!("/gists/{}/comments", gist_id))
+    fn get_gists_gist_id_comments(&self, gist_id: u64) -> Result<Vec<Comment>, error::Error>{
+        self.request(Method::Get, "/gists")
+            .segment(gist_id)
+            .path("/comments")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gists_gist_id_comments_id(&mut self, gist_id: u64, id: u64) -> Result<Comment, error::Error>{
-        utils::request_endpoint(self, format!("/gists/{}/comments/{}", gist_id, id))
+    fn get_gists_gist_id_comments_id(&self, gist_id: u64, id: u64) -> Result<Comment, error::Error>{
+        self.request(Method::Get, "/gists")
+            .segment(gist_id)
+            .path("/comments")
+            .segment(id)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn post_gists_gist_id_comments(&mut self, gist_id: u64, body: String) -> Result<Comment, error::Error>{
-
-        //Serialize body
-        let comment_body = try!(serde_json::to_string(&CommentBody{
-            body: body
-        }).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.post_body(format!("/gists/{}/comments", gist_id), None, comment_body));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn post_gists_gist_id_comments(&self, gist_id: u64, body: String) -> Result<Comment, error::Error>{
+        self.request(Method::Post, "/gists")
+            .segment(gist_id)
+            .path("/comments")
+            .json(&CommentBody {
+                body: body
+            })
+            .send()
+            .map(|response| response.data)
     }
 
-    fn patch_gists_gist_id_comments_id(&mut self, gist_id: u64, id: u64, body: String) -> Result<Comment, error::Error>{
-
-        //Serialize body
-        let comment_body = try!(serde_json::to_string(&CommentBody{
-            body: body
-        }).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.patch_body(format!("/gists/{}/comments", gist_id), None, comment_body));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn patch_gists_gist_id_comments_id(&self, gist_id: u64, id: u64, body: String) -> Result<Comment, error::Error>{
+        self.request(Method::Patch, "/gists")
+            .segment(gist_id)
+            .path("/comments")
+            .segment(id)
+            .json(&CommentBody {
+                body: body
+            })
+            .send()
+            .map(|response| response.data)
     }
 
-    fn delete_gists_gist_id_comments_id(&mut self, gist_id: u64, id: u64) -> Result<(), error::Error>{
-        match self.delete(format!("/gists/{}/comments/{}", gist_id, id), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn delete_gists_gist_id_comments_id(&self, gist_id: u64, id: u64) -> Result<(), error::Error>{
+        self.request(Method::Delete, "/gists")
+            .segment(gist_id)
+            .path("/comments")
+            .segment(id)
+            .send_empty()
+            .map(|_| ())
     }
 }
 
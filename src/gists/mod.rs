@@ -13,16 +13,17 @@
 //! Reference: https://developer.github.com/v3/gists/
 
 use std::collections::BTreeMap;
-use hyper::{Error as HyperError, Url};
-use serde_json;
+use hyper::method::Method;
 
 use client::Client;
-use common::User;
+use common::{DateTime, Edit, User};
 use error;
-use utils;
+
+/// Endpoints for gist comments.
+pub mod comments;
 
 /// Return type for Gist endpoints
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Gist {
     pub url:          String,
     pub forks_url:    String,
@@ -39,14 +40,14 @@ pub struct Gist {
     pub html_url:     String,
     pub git_pull_url: String,
     pub git_push_url: String,
-    pub created_at:   String,
-    pub updated_at:   String,
+    pub created_at:   DateTime,
+    pub updated_at:   DateTime,
     pub forks:        Option<Vec<Fork>>,
     pub history:      Option<Vec<Commit>>
 }
 
 /// Sub-component of the Gist response
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct File {
     pub size:      u64,
     pub raw_url:   String,
@@ -57,27 +58,27 @@ pub struct File {
 }
 
 /// Sub-component of the Gist response
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Fork {
     pub user:       User,
     pub url:        String,
     pub id:         String,
-    pub created_at: String,
-    pub updated_at: String
+    pub created_at: DateTime,
+    pub updated_at: DateTime
 }
 
 /// Sub-component of the Gist response
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Commit {
     pub url:           String,
     pub version:       String,
     pub user:          User,
     pub change_status: ChangeStatus,
-    pub committed_at:  String
+    pub committed_at:  DateTime
 }
 
 /// Sub-component of the History
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ChangeStatus {
     pub deletions: u64,
     pub additions: u64,
@@ -85,7 +86,7 @@ pub struct ChangeStatus {
 }
 
 //TODO: More precise doc + example
-/// Input parameters for Gist creation and editing
+/// Input parameters for Gist creation
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct GistParam {
     pub description: String,
@@ -101,6 +102,81 @@ pub struct FileContents {
     pub content: String
 }
 
+/// Input parameters for Gist editing, only the fields and
+/// the files that are set are changed.
+///
+/// ```rust,ignore
+/// let edit = GistEdit::new().file("hello.rs", "fn main() {}").delete_file("old.rs");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct GistEdit {
+    /// Description of the gist.
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub description: Edit<String>,
+    /// Files to change by their current name, `None`
+    /// deletes the file.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub files:       BTreeMap<String, Option<FileEdit>>
+}
+
+///Sub-component of GistEdit
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FileEdit {
+    /// New name of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// New content of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content:  Option<String>
+}
+
+impl GistEdit {
+
+    /// Creates an edit that leaves the gist unchanged.
+    pub fn new() -> GistEdit {
+        GistEdit::default()
+    }
+
+    /// Sets the description of the gist.
+    pub fn description(mut self, description: &str) -> GistEdit {
+        self.description = Edit::Set(description.to_string());
+        self
+    }
+
+    /// Removes the description of the gist.
+    pub fn clear_description(mut self) -> GistEdit {
+        self.description = Edit::Null;
+        self
+    }
+
+    /// Sets the content of a file, the file is created if
+    /// it does not exist.
+    pub fn file(mut self, filename: &str, content: &str) -> GistEdit {
+        self.file_edit(filename).content = Some(content.to_string());
+        self
+    }
+
+    /// Renames a file.
+    pub fn rename_file(mut self, filename: &str, new_filename: &str) -> GistEdit {
+        self.file_edit(filename).filename = Some(new_filename.to_string());
+        self
+    }
+
+    /// Deletes a file.
+    pub fn delete_file(mut self, filename: &str) -> GistEdit {
+        self.files.insert(filename.to_string(), None);
+        self
+    }
+
+    fn file_edit(&mut self, filename: &str) -> &mut FileEdit {
+        let file = self.files.entry(filename.to_string()).or_insert(None);
+        if file.is_none() {
+            *file = Some(FileEdit::default());
+        }
+        file.as_mut().unwrap()
+    }
+}
+
 ////////////////////////////////////////////////////////////
 //                    Extension Trait                     //
 ////////////////////////////////////////////////////////////
@@ -115,7 +191,7 @@ pub trait GistsExt {
     /// * `username`: Name fo the user.
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_users_username_gists(&mut self, username: String, since: String) -> Result<Vec<Gist>, error::Error>;
+    fn get_users_username_gists(&self, username: String, since: DateTime) -> Result<Vec<Gist>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#list-a-users-gists)\]
     /// Returns the list of the authenticated user's gists
@@ -125,7 +201,7 @@ pub trait GistsExt {
     /// ## Parameters
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_gists(&mut self, since: String) -> Result<Vec<Gist>, error::Error>;
+    fn get_gists(&self, since: DateTime) -> Result<Vec<Gist>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#list-all-public-gists)\]
     /// Returns the list of all public gists sorted by most
@@ -135,7 +211,7 @@ pub trait GistsExt {
     /// ## Parameters
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_gists_public(&mut self, since: String) -> Result<Vec<Gist>, error::Error>;
+    fn get_gists_public(&self, since: DateTime) -> Result<Vec<Gist>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#list-starred-gists)\]
     /// Returns the list of the authenticated user's
@@ -145,7 +221,7 @@ pub trait GistsExt {
     /// ## Parameters
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_gists_starred(&mut self, since: String) -> Result<Vec<Gist>, error::Error>;
+    fn get_gists_starred(&self, since: DateTime) -> Result<Vec<Gist>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#get-a-single-gist)\]
     /// Get a single gist.
@@ -153,7 +229,7 @@ pub trait GistsExt {
     /// GET /gists/:id
     /// ## Parameters
     /// * `id`: Gist ID.
-    fn get_gists_id(&mut self, id: u64) -> Result<Gist, error::Error>;
+    fn get_gists_id(&self, id: u64) -> Result<Gist, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#get-a-specific-revision-of-a-gist)\]
     /// Get a specific revision of a gist.
@@ -162,7 +238,7 @@ pub trait GistsExt {
     /// ## Parameters
     /// * `id`: Gist ID.
     /// * `sha`: Gist SHA hash.
-    fn get_gists_id_sha(&mut self, id: u64, sha: String) -> Result<Gist, error::Error>;
+    fn get_gists_id_sha(&self, id: u64, sha: String) -> Result<Gist, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#create-a-gist)\]
     /// Create a gist.
@@ -171,7 +247,7 @@ pub trait GistsExt {
     /// ## Parameters
     /// * `gist`: Input parameters, see Reference and
     /// GistParam.
-    fn post_gists(&mut self, gist: GistParam) -> Result<Gist, error::Error>;
+    fn post_gists(&self, gist: GistParam) -> Result<Gist, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#edit-a-gist)\]
     /// Edit a gist
@@ -180,8 +256,8 @@ pub trait GistsExt {
     /// ## Parameters
     /// * `id`: ID of the gist to be edited.
     /// * `gist`: Input parameters, see Reference and
-    /// GistParam.
-    fn patch_gists_id(&mut self, id: u64, gist: GistParam) -> Result<Gist, error::Error>;
+    /// GistEdit.
+    fn patch_gists_id(&self, id: u64, gist: GistEdit) -> Result<Gist, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#list-gist-commits)\]
     /// List gist commits
@@ -189,7 +265,7 @@ pub trait GistsExt {
     /// GET /gists/:id/commits
     /// ## Parameters
     /// * `id`: ID of the gist.
-    fn get_gists_id_commits(&mut self, id: u64) -> Result<Vec<Commit>, error::Error>;
+    fn get_gists_id_commits(&self, id: u64) -> Result<Vec<Commit>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#star-a-gist)\]
     /// Star a gist
@@ -197,7 +273,7 @@ pub trait GistsExt {
     /// PUT /gists/:id/star
     /// ## Parameters
     /// * `id`: ID of the gist to be starred.
-    fn put_gists_id_star(&mut self, id: u64) -> Result<(), error::Error>;
+    fn put_gists_id_star(&self, id: u64) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#unstar-a-gist)\]
     /// Unstar a gist
@@ -205,7 +281,7 @@ pub trait GistsExt {
     /// DELETE /gists/:id/star
     /// ## Parameters
     /// * `id`: ID of the gist to be unstarred.
-    fn delete_gists_id_star(&mut self, id: u64) -> Result<(), error::Error>;
+    fn delete_gists_id_star(&self, id: u64) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#check-if-a-gist-is-starred)\]
     /// Check if a gist is starred
@@ -213,7 +289,7 @@ pub trait GistsExt {
     /// GET /gists/:id/star
     /// ## Parameters
     /// * `id`: ID of the gist to be checked.
-    fn get_gists_id_star(&mut self, id: u64) -> Result<(), error::Error>;
+    fn get_gists_id_star(&self, id: u64) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#fork-a-gist)\]
     /// Fork a gist
@@ -221,7 +297,7 @@ pub trait GistsExt {
     /// POST /gists/:id/forks
     /// ## Parameters
     /// * `id`: ID of the gist to be forked.
-    fn post_gists_id_forks(&mut self, id: u64) -> Result<Vec<Commit>, error::Error>;
+    fn post_gists_id_forks(&self, id: u64) -> Result<Vec<Commit>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#list-gist-forks)\]
     /// List gist forks
@@ -229,7 +305,7 @@ pub trait GistsExt {
     /// GET /gists/:id/forks
     /// ## Parameters
     /// * `id`: ID of the gist.
-    fn get_gists_id_forks(&mut self, id: u64) -> Result<Vec<Fork>, error::Error>;
+    fn get_gists_id_forks(&self, id: u64) -> Result<Vec<Fork>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gists/#delete-a-gist)\]
     /// Delete a gist
@@ -237,147 +313,124 @@ pub trait GistsExt {
     /// DELETE /gists/:id
     /// ## Parameters
     /// * `id`: ID of the gist to be deleted.
-    fn delete_gists_id(&mut self, id: u64) -> Result<(), error::Error>;
+    fn delete_gists_id(&self, id: u64) -> Result<(), error::Error>;
 }
 
 impl GistsExt for Client {
 
-    fn get_users_username_gists(&mut self, username: String, since: String) -> Result<Vec<Gist>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/users/{}/gists", self.api_url, username)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/users/{}/gists?{}", username, url.query().unwrap()))
+    fn get_users_username_gists(&self, username: String, since: DateTime) -> Result<Vec<Gist>, error::Error>{
+        self.request(Method::Get, "/users")
+            .segment(username)
+            .path("/gists")
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gists(&mut self, since: String) -> Result<Vec<Gist>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/gists", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/gists?{}", url.query().unwrap()))
+    fn get_gists(&self, since: DateTime) -> Result<Vec<Gist>, error::Error>{
+        self.request(Method::Get, "/gists")
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gists_public(&mut self, since: String) -> Result<Vec<Gist>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/gists/public", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/gists/public?{}", url.query().unwrap()))
+    fn get_gists_public(&self, since: DateTime) -> Result<Vec<Gist>, error::Error>{
+        self.request(Method::Get, "/gists/public")
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gists_starred(&mut self, since: String) -> Result<Vec<Gist>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/gists/starred", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/gists/starred?{}", url.query().unwrap()))
+    fn get_gists_starred(&self, since: DateTime) -> Result<Vec<Gist>, error::Error>{
+        self.request(Method::Get, "/gists/starred")
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gists_id(&mut self, id: u64) -> Result<Gist, error::Error>{
-        utils::request_endpoint(self, format!("/gists/{}", id))
+    fn get_gists_id(&self, id: u64) -> Result<Gist, error::Error>{
+        self.request(Method::Get, "/gists")
+            .segment(id)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gists_id_sha(&mut self, id: u64, sha: String) -> Result<Gist, error::Error>{
-        utils::request_endpoint(self, format!("/gists/{}/{}", id, sha))
+    fn get_gists_id_sha(&self, id: u64, sha: String) -> Result<Gist, error::Error>{
+        self.request(Method::Get, "/gists")
+            .segment(id)
+            .segment(sha)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn post_gists(&mut self, gist: GistParam) -> Result<Gist, error::Error>{
-
-        //Serialize body
-        let gist_body = try!(serde_json::to_string(&gist).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.post_body("/gists".to_string(), None, gist_body));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn post_gists(&self, gist: GistParam) -> Result<Gist, error::Error>{
+        self.request(Method::Post, "/gists")
+            .json(&gist)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn patch_gists_id(&mut self, id: u64, gist: GistParam) -> Result<Gist, error::Error>{
-
-        //Serialize body
-        let gist_body = try!(serde_json::to_string(&gist).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.patch_body(format!("/gists/{}", id), None, gist_body));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn patch_gists_id(&self, id: u64, gist: GistEdit) -> Result<Gist, error::Error>{
+        self.request(Method::Patch, "/gists")
+            .segment(id)
+            .json(&gist)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gists_id_commits(&mut self, id: u64) -> Result<Vec<Commit>, error::Error>{
-        utils::request_endpoint(self, format!("/gists/{}/commit", id))
+    fn get_gists_id_commits(&self, id: u64) -> Result<Vec<Commit>, error::Error>{
+        self.request(Method::Get, "/gists")
+            .segment(id)
+            .path("/commits")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn put_gists_id_star(&mut self, id: u64) -> Result<(), error::Error>{
-        match self.put(format!("/gists/{}/star", id), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn put_gists_id_star(&self, id: u64) -> Result<(), error::Error>{
+        self.request(Method::Put, "/gists")
+            .segment(id)
+            .path("/star")
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn delete_gists_id_star(&mut self, id: u64) -> Result<(), error::Error>{
-        match self.delete(format!("/gists/{}/star", id), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn delete_gists_id_star(&self, id: u64) -> Result<(), error::Error>{
+        self.request(Method::Delete, "/gists")
+            .segment(id)
+            .path("/star")
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn get_gists_id_star(&mut self, id: u64) -> Result<(), error::Error>{
-        match self.get(format!("/gists/{}/star", id), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn get_gists_id_star(&self, id: u64) -> Result<(), error::Error>{
+        self.request(Method::Get, "/gists")
+            .segment(id)
+            .path("/star")
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn post_gists_id_forks(&mut self, id: u64) -> Result<Vec<Commit>, error::Error>{
-        let mut response     = try!(self.post(format!("/gists/{}/forks", id), None));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn post_gists_id_forks(&self, id: u64) -> Result<Vec<Commit>, error::Error>{
+        self.request(Method::Post, "/gists")
+            .segment(id)
+            .path("/forks")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gists_id_forks(&mut self, id: u64) -> Result<Vec<Fork>, error::Error>{
-        utils::request_endpoint(self, format!("/gists/{}/forks", id))
+    fn get_gists_id_forks(&self, id: u64) -> Result<Vec<Fork>, error::Error>{
+        self.request(Method::Get, "/gists")
+            .segment(id)
+            .path("/forks")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn delete_gists_id(&mut self, id: u64) -> Result<(), error::Error>{
-        match self.delete(format!("/gists/{}", id), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn delete_gists_id(&self, id: u64) -> Result<(), error::Error>{
+        self.request(Method::Delete, "/gists")
+            .segment(id)
+            .send_empty()
+            .map(|_| ())
     }
 }
 
@@ -16,10 +16,11 @@ use serde_json;
 
 use client::Client;
 use error;
+use path::{self, Owner, RepoName};
 use utils;
 
 /// Response to get a blob.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Blob {
     content:  String,
     encoding: String,
@@ -29,7 +30,7 @@ pub struct Blob {
 }
 
 /// Response to create a blob.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct BlobCreate {
     url:      String,
     sha:      String,
@@ -72,7 +73,7 @@ pub trait BlobExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `sha`: SHA of the blob.
-    fn get_repos_owner_repo_git_blobs_sha(&mut self, owner: String, repo: String, sha: String) -> Result<Blob, error::Error>;
+    fn get_repos_owner_repo_git_blobs_sha(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Blob, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/blobs/#create-a-blob)\]
     /// Creates a blob.
@@ -83,16 +84,16 @@ pub trait BlobExt {
     /// * `repo`: Name of the repository.
     /// * `content`: Desired content of the blob.
     /// * `encoding`: Encoding of the content.
-    fn post_repos_owner_repo_git_blobs(&mut self, owner: String, repo: String, content: String, encoding: BlobEncoding) -> Result<BlobCreate, error::Error>;
+    fn post_repos_owner_repo_git_blobs(&self, owner: Owner, repo: RepoName, content: String, encoding: BlobEncoding) -> Result<BlobCreate, error::Error>;
 }
 
 impl BlobExt for Client {
 
-    fn get_repos_owner_repo_git_blobs_sha(&mut self, owner: String, repo: String, sha: String) -> Result<Blob, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/blobs/{}", owner, repo, sha))
+    fn get_repos_owner_repo_git_blobs_sha(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Blob, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/git/blobs/{}", owner.to_path(), repo.to_path(), path::escape_segment(&sha)))
     }
 
-    fn post_repos_owner_repo_git_blobs(&mut self, owner: String, repo: String, content: String, encoding: BlobEncoding) -> Result<BlobCreate, error::Error>{
+    fn post_repos_owner_repo_git_blobs(&self, owner: Owner, repo: RepoName, content: String, encoding: BlobEncoding) -> Result<BlobCreate, error::Error>{
 
         //Create body
         let body_data = try!(serde_json::to_string(&BlobParam{
@@ -100,7 +101,7 @@ impl BlobExt for Client {
             encoding: encoding.to_str()
         }).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/blobs", owner, repo), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/blobs", owner.to_path(), repo.to_path()), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
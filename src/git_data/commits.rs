@@ -12,29 +12,37 @@
 //!
 //! Reference: https://developer.github.com/v3/git/commits/
 
+use hyper::method::Method;
 use serde_json;
 
 use client::Client;
+use common::DateTime;
 use error;
-use utils;
+use git_data::Verification;
+use path::{Owner, RepoName};
+#[cfg(feature = "preview-signatures")]
+use preview::Preview;
 
 /// Structure representing a commit
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Commit {
     /// SHA of the commit.
-    pub sha:       String,
+    pub sha:          String,
     /// URL of the commit.
-    pub url:       String,
+    pub url:          String,
     /// Author of the commit.
-    pub author:    CommitUser,
+    pub author:       CommitUser,
     /// Committer for this commit.
-    pub committer: CommitUser,
+    pub committer:    CommitUser,
     /// Commit message.
-    pub message:   String,
+    pub message:      String,
     /// Tree object.
-    pub tree:      CommitParent,
+    pub tree:         CommitParent,
     /// Parent commit.
-    pub parents:   Vec<CommitParent>
+    pub parents:      Vec<CommitParent>,
+    /// Signature verification, sent with the
+    /// `preview-signatures` feature.
+    pub verification: Option<Verification>
 }
 
 /// Sub-component of Commit
@@ -43,7 +51,7 @@ pub struct CommitUser {
     /// Date at which the commit was authored or committed,
     /// a timestamp in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`)
     /// format.
-    pub date:  String,
+    pub date:  DateTime,
     /// Name fo author/committer.
     pub name:  String,
     /// Email address of author/commiter
@@ -51,7 +59,7 @@ pub struct CommitUser {
 }
 
 /// Sub-component of Commit
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CommitParent {
     /// URL of the object.
     url: String,
@@ -90,7 +98,7 @@ pub trait CommitsExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `sha`: SHA of the commit.
-    fn get_repos_owner_repo_git_commits_sha(&mut self, owner: String, repo: String, sha: String) -> Result<Commit, error::Error>;
+    fn get_repos_owner_repo_git_commits_sha(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Commit, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/commits/#create-a-commit)\]
     /// Creates a commit.
@@ -100,25 +108,33 @@ pub trait CommitsExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `commit`: Parameters for the commit.
-    fn post_repos_owner_repo_git_commits(&mut self, owner: String, repo: String, commit: CommitParam) -> Result<Commit, error::Error>;
+    fn post_repos_owner_repo_git_commits(&self, owner: Owner, repo: RepoName, commit: CommitParam) -> Result<Commit, error::Error>;
 }
 
 impl CommitsExt for Client {
 
-    fn get_repos_owner_repo_git_commits_sha(&mut self, owner: String, repo: String, sha: String) -> Result<Commit, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/commits/{}", owner, repo, sha))
+    fn get_repos_owner_repo_git_commits_sha(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Commit, error::Error>{
+        let request = self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/git/commits")
+            .segment(sha);
+
+        #[cfg(feature = "preview-signatures")]
+        let request = request.preview(Preview::Signatures);
+
+        request.send().map(|response| response.data)
     }
 
-    fn post_repos_owner_repo_git_commits(&mut self, owner: String, repo: String, commit: CommitParam) -> Result<Commit, error::Error>{
+    fn post_repos_owner_repo_git_commits(&self, owner: Owner, repo: RepoName, commit: CommitParam) -> Result<Commit, error::Error>{
 
         //Create body
         let body_data = try!(serde_json::to_string(&commit).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/commits", owner, repo), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/commits", owner.to_path(), repo.to_path()), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 }
 
-//TODO: Commit signature verification once out of preview
 //TODO: TESTS
@@ -5,10 +5,53 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+//! # Git Data
+//!
+//! Modules for subsections of Git Data in the Github API
+//! docs and the types shared by them.
+//!
+//! Reference: https://developer.github.com/v3/git/
+
 pub mod blobs;
 pub mod commits;
 pub mod references;
 pub mod tags;
 pub mod trees;
 
+string_enum! {
+    /// Type of a git object.
+    pub enum ObjectType {
+        Blob   => "blob",
+        Commit => "commit",
+        Tag    => "tag",
+        Tree   => "tree"
+    }
+}
+
+string_enum! {
+    /// Mode of an entry of a tree.
+    pub enum FileMode {
+        /// Regular file.
+        File         => "100644",
+        /// Executable file.
+        Executable   => "100755",
+        /// Subdirectory, the entry is a tree.
+        Subdirectory => "040000",
+        /// Submodule, the entry is a commit.
+        Submodule    => "160000",
+        /// Symbolic link, the entry is a blob.
+        Symlink      => "120000"
+    }
+}
+
+/// Signature verification of a commit or a tag, available
+/// with the `preview-signatures` feature.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Verification {
+    pub verified:  bool,
+    pub reason:    String,
+    pub signature: Option<String>,
+    pub payload:   Option<String>
+}
+
 //TODO: TESTS
@@ -16,10 +16,12 @@ use serde_json;
 
 use client::Client;
 use error;
+use git_data::ObjectType;
+use path::{Owner, RefName, RepoName};
 use utils;
 
 /// Response type to references endpoints.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Reference {
     #[serde(rename = "ref")]
     pub _ref:   String,
@@ -28,10 +30,10 @@ pub struct Reference {
 }
 
 /// Sub-component of Reference.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Object {
     #[serde(rename = "type")]
-    pub object_type: String,
+    pub object_type: ObjectType,
     pub sha:         String,
     pub url:         String
 }
@@ -67,7 +69,7 @@ pub trait ReferencesExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `_ref`: Name of the fully qualified reference.
-    fn get_repos_owner_repo_git_refs_ref(&mut self, owner: String, repo: String, _ref: String) -> Result<Reference, error::Error>;
+    fn get_repos_owner_repo_git_refs_ref(&self, owner: Owner, repo: RepoName, _ref: RefName) -> Result<Reference, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/refs/#get-all-references)\]
     /// Returns all references.
@@ -76,7 +78,7 @@ pub trait ReferencesExt {
     /// ## Parameters
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_git_refs(&mut self, owner: String, repo: String) -> Result<Vec<Reference>, error::Error>;
+    fn get_repos_owner_repo_git_refs(&self, owner: Owner, repo: RepoName) -> Result<Vec<Reference>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/refs/#get-all-references)\]
     /// Returns all references in the heads namespace.
@@ -85,7 +87,7 @@ pub trait ReferencesExt {
     /// ## Parameters
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_git_refs_heads(&mut self, owner: String, repo: String) -> Result<Vec<Reference>, error::Error>;
+    fn get_repos_owner_repo_git_refs_heads(&self, owner: Owner, repo: RepoName) -> Result<Vec<Reference>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/refs/#get-all-references)\]
     /// Returns all references in the tags namespace.
@@ -94,7 +96,7 @@ pub trait ReferencesExt {
     /// ## Parameters
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_git_refs_tags(&mut self, owner: String, repo: String) -> Result<Vec<Reference>, error::Error>;
+    fn get_repos_owner_repo_git_refs_tags(&self, owner: Owner, repo: RepoName) -> Result<Vec<Reference>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/refs/#create-a-reference)\]
     /// Creates a reference.
@@ -105,7 +107,7 @@ pub trait ReferencesExt {
     /// * `repo`: Name of the repository.
     /// * `_ref`: Name of the fully qualified reference.
     /// * `sha`: SHA1 value to set the reference to.
-    fn post_repos_owner_repo_git_refs(&mut self, owner: String, repo: String, _ref: String, sha: String) -> Result<Reference, error::Error>;
+    fn post_repos_owner_repo_git_refs(&self, owner: Owner, repo: RepoName, _ref: RefName, sha: String) -> Result<Reference, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/refs/#update-a-reference)\]
     /// Updates a reference.
@@ -120,7 +122,7 @@ pub trait ReferencesExt {
     /// force the update or to make sure the update is a
     /// fast-forward update. Leaving this out or setting it
     /// to false will make sure you're not overwriting work.
-    fn patch_repos_owner_repo_git_refs_ref(&mut self, owner: String, repo: String, _ref: String, sha: String, force: Option<bool>) -> Result<Reference, error::Error>;
+    fn patch_repos_owner_repo_git_refs_ref(&self, owner: Owner, repo: RepoName, _ref: RefName, sha: String, force: Option<bool>) -> Result<Reference, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/refs/#delete-a-reference)\]
     /// Deletes a reference.
@@ -130,41 +132,41 @@ pub trait ReferencesExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `_ref`: Name of the fully qualified reference.
-    fn delete_repos_owner_repo_git_refs_ref(&mut self, owner: String, repo: String, _ref: String) -> Result<(), error::Error>;
+    fn delete_repos_owner_repo_git_refs_ref(&self, owner: Owner, repo: RepoName, _ref: RefName) -> Result<(), error::Error>;
 }
 
 impl ReferencesExt for Client {
 
-    fn get_repos_owner_repo_git_refs_ref(&mut self, owner: String, repo: String, _ref: String) -> Result<Reference, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/refs/{}", owner, repo, _ref))
+    fn get_repos_owner_repo_git_refs_ref(&self, owner: Owner, repo: RepoName, _ref: RefName) -> Result<Reference, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/git/refs/{}", owner.to_path(), repo.to_path(), _ref.to_path()))
     }
 
-    fn get_repos_owner_repo_git_refs(&mut self, owner: String, repo: String) -> Result<Vec<Reference>, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/refs", owner, repo))
+    fn get_repos_owner_repo_git_refs(&self, owner: Owner, repo: RepoName) -> Result<Vec<Reference>, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/git/refs", owner.to_path(), repo.to_path()))
     }
 
-    fn get_repos_owner_repo_git_refs_heads(&mut self, owner: String, repo: String) -> Result<Vec<Reference>, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/refs/heads", owner, repo))
+    fn get_repos_owner_repo_git_refs_heads(&self, owner: Owner, repo: RepoName) -> Result<Vec<Reference>, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/git/refs/heads", owner.to_path(), repo.to_path()))
     }
 
-    fn get_repos_owner_repo_git_refs_tags(&mut self, owner: String, repo: String) -> Result<Vec<Reference>, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/refs/tags", owner, repo))
+    fn get_repos_owner_repo_git_refs_tags(&self, owner: Owner, repo: RepoName) -> Result<Vec<Reference>, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/git/refs/tags", owner.to_path(), repo.to_path()))
     }
 
-    fn post_repos_owner_repo_git_refs(&mut self, owner: String, repo: String, _ref: String, sha: String) -> Result<Reference, error::Error>{
+    fn post_repos_owner_repo_git_refs(&self, owner: Owner, repo: RepoName, _ref: RefName, sha: String) -> Result<Reference, error::Error>{
 
         //Create body
         let body_data = try!(serde_json::to_string(&RefCreateParam{
-            _ref: _ref,
+            _ref: _ref.to_string(),
             sha:  sha
         }).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/refs", owner, repo), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/refs", owner.to_path(), repo.to_path()), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn patch_repos_owner_repo_git_refs_ref(&mut self, owner: String, repo: String, _ref: String, sha: String, force: Option<bool>) -> Result<Reference, error::Error>{
+    fn patch_repos_owner_repo_git_refs_ref(&self, owner: Owner, repo: RepoName, _ref: RefName, sha: String, force: Option<bool>) -> Result<Reference, error::Error>{
 
         //Create body
         let body_data = try!(serde_json::to_string(&RefUpdateParam{
@@ -172,13 +174,13 @@ impl ReferencesExt for Client {
             force: force
         }).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.patch_body(format!("/repos/{}/{}/git/refs/{}", owner, repo, _ref), None, body_data));
+        let mut response     = try!(self.patch_body(format!("/repos/{}/{}/git/refs/{}", owner.to_path(), repo.to_path(), _ref.to_path()), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn delete_repos_owner_repo_git_refs_ref(&mut self, owner: String, repo: String, _ref: String) -> Result<(), error::Error>{
-        match self.delete(format!("/repos/{}/{}/git/refs/{}", owner, repo, _ref), None) {
+    fn delete_repos_owner_repo_git_refs_ref(&self, owner: Owner, repo: RepoName, _ref: RefName) -> Result<(), error::Error>{
+        match self.delete(format!("/repos/{}/{}/git/refs/{}", owner.to_path(), repo.to_path(), _ref.to_path()), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
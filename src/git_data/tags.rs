@@ -12,21 +12,27 @@
 //!
 //! Reference: https://developer.github.com/v3/git/commits/
 
+use hyper::method::Method;
 use serde_json;
 
 use client::Client;
+use common::DateTime;
 use error;
-use utils;
+use git_data::{ObjectType, Verification};
+use path::{Owner, RepoName};
+#[cfg(feature = "preview-signatures")]
+use preview::Preview;
 
 /// Return type of tags endpoints.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Tag {
-    pub tag:     String,
-    pub sha:     String,
-    pub url:     String,
-    pub message: String,
-    pub tagger:  Tagger,
-    pub object:  TagObject
+    pub tag:          String,
+    pub sha:          String,
+    pub url:          String,
+    pub message:      String,
+    pub tagger:       Tagger,
+    pub object:       TagObject,
+    pub verification: Option<Verification>
 }
 
 /// Sub-component of Tag.
@@ -38,14 +44,14 @@ pub struct Tagger {
     pub email: String,
     /// Date of when the object was tagged in
     /// ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    pub date:  String
+    pub date:  DateTime
 }
 
 /// Sub-component of Tag.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TagObject {
     #[serde(rename = "type")]
-    pub object_type: String,
+    pub object_type: ObjectType,
     pub sha:         String,
     pub url:         String
 }
@@ -61,7 +67,7 @@ pub struct TagParam {
     pub object: String,
     /// commit, tag or blob
     #[serde(rename = "type")]
-    pub tag_type: String,
+    pub tag_type: ObjectType,
     /// Info about person creating the tag.
     pub tagger: Tagger
 }
@@ -81,7 +87,7 @@ pub trait TagsExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `sha`: SHA of the tag.
-    fn get_repos_owner_repo_git_tags_sha(&mut self, owner: String, repo: String, sha: String) -> Result<Tag, error::Error>;
+    fn get_repos_owner_repo_git_tags_sha(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Tag, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/tags/#create-a-tag-object)\]
     /// Creates a tag.
@@ -91,25 +97,33 @@ pub trait TagsExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `tag`: Parameters for tag creation.
-    fn post_repos_owner_repo_git_tags(&mut self, owner: String, repo: String, tag: TagParam) -> Result<Tag, error::Error>;
+    fn post_repos_owner_repo_git_tags(&self, owner: Owner, repo: RepoName, tag: TagParam) -> Result<Tag, error::Error>;
 }
 
 impl TagsExt for Client {
 
-    fn get_repos_owner_repo_git_tags_sha(&mut self, owner: String, repo: String, sha: String) -> Result<Tag, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/tags/{}", owner, repo, sha))
+    fn get_repos_owner_repo_git_tags_sha(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Tag, error::Error>{
+        let request = self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/git/tags")
+            .segment(sha);
+
+        #[cfg(feature = "preview-signatures")]
+        let request = request.preview(Preview::Signatures);
+
+        request.send().map(|response| response.data)
     }
 
-    fn post_repos_owner_repo_git_tags(&mut self, owner: String, repo: String, tag: TagParam) -> Result<Tag, error::Error>{
+    fn post_repos_owner_repo_git_tags(&self, owner: Owner, repo: RepoName, tag: TagParam) -> Result<Tag, error::Error>{
 
         //Create body
         let body_data = try!(serde_json::to_string(&tag).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/tags", owner, repo), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/tags", owner.to_path(), repo.to_path()), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 }
 
-//TODO: Tag signature verification once out of preview.
 //TODO: TESTS
@@ -16,10 +16,12 @@ use serde_json;
 
 use client::Client;
 use error;
+use git_data::{FileMode, ObjectType};
+use path::{self, Owner, RepoName};
 use utils;
 
 /// Response to trees endpoints.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Tree {
     pub sha:       String,
     pub url:       String,
@@ -33,10 +35,10 @@ pub struct TreeElem {
     /// File referenced in the tree.
     pub path:      String,
     /// The file mode, see reference.
-    pub mode:      String,
+    pub mode:      FileMode,
     /// blob, tree or commit
     #[serde(rename = "type")]
-    pub tree_type: String,
+    pub tree_type: ObjectType,
     /// Size of the content
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size:      Option<u64>,
@@ -71,7 +73,7 @@ pub trait TreesExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `sha`: SHA of the tree.
-    fn get_repos_owner_repo_git_trees_sha(&mut self, owner: String, repo: String, sha: String) -> Result<Tree, error::Error>;
+    fn get_repos_owner_repo_git_trees_sha(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Tree, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/trees/#get-a-tree-recursively)\]
     /// Returns a tree recursively.
@@ -81,7 +83,7 @@ pub trait TreesExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `sha`: SHA of the tree.
-    fn get_repos_owner_repo_git_trees_sha_recursive(&mut self, owner: String, repo: String, sha: String) -> Result<Tree, error::Error>;
+    fn get_repos_owner_repo_git_trees_sha_recursive(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Tree, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/git/trees/#create-a-tree)\]
     /// Creates a tree.
@@ -91,25 +93,25 @@ pub trait TreesExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `tree`: Parameters for tree creation.
-    fn post_repos_owner_repo_git_trees(&mut self, owner: String, repo: String, tree: TreeParam) -> Result<Tree, error::Error>;
+    fn post_repos_owner_repo_git_trees(&self, owner: Owner, repo: RepoName, tree: TreeParam) -> Result<Tree, error::Error>;
 }
 
 impl TreesExt for Client {
 
-    fn get_repos_owner_repo_git_trees_sha(&mut self, owner: String, repo: String, sha: String) -> Result<Tree, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/trees/{}", owner, repo, sha))
+    fn get_repos_owner_repo_git_trees_sha(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Tree, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/git/trees/{}", owner.to_path(), repo.to_path(), path::escape_segment(&sha)))
     }
 
-    fn get_repos_owner_repo_git_trees_sha_recursive(&mut self, owner: String, repo: String, sha: String) -> Result<Tree, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/git/trees/{}?recursive=1", owner, repo, sha))
+    fn get_repos_owner_repo_git_trees_sha_recursive(&self, owner: Owner, repo: RepoName, sha: String) -> Result<Tree, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/git/trees/{}?recursive=1", owner.to_path(), repo.to_path(), path::escape_segment(&sha)))
     }
 
-    fn post_repos_owner_repo_git_trees(&mut self, owner: String, repo: String, tree: TreeParam) -> Result<Tree, error::Error>{
+    fn post_repos_owner_repo_git_trees(&self, owner: Owner, repo: RepoName, tree: TreeParam) -> Result<Tree, error::Error>{
 
         //Create body
         let body_data = try!(serde_json::to_string(&tree).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/trees", owner, repo), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/git/trees", owner.to_path(), repo.to_path()), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
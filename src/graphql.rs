@@ -0,0 +1,288 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # GraphQL
+//!
+//! Queries to the Github GraphQL API, they use the
+//! authentication, the user agent and the GraphQL URL of
+//! the [Client](../client/index.html).
+//!
+//! Reference: https://developer.github.com/v4/
+//!
+//! ### Example
+//!
+//! ```rust,no_run
+//!
+//! extern crate rustyhub;
+//! extern crate serde_json;
+//!
+//! use std::collections::BTreeMap;
+//! use serde_json::Value;
+//!
+//! use rustyhub::auth::auth::Auth;
+//! use rustyhub::client::Client;
+//! use rustyhub::graphql::GraphQLExt;
+//!
+//! fn main() {
+//!
+//!     let client = Client::new("rusyhub-UserAgent", Auth::OAuth2Token("token".to_string()));
+//!
+//!     let mut variables = BTreeMap::new();
+//!     variables.insert("owner".to_string(), Value::String("rust-lang".to_string()));
+//!     variables.insert("name".to_string(),  Value::String("rust".to_string()));
+//!
+//!     let titles: Vec<Value> = client.graphql_nodes("query($owner: String!, $name: String!, $cursor: String) {
+//!                                                        repository(owner: $owner, name: $name) {
+//!                                                            issues(first: 100, after: $cursor) {
+//!                                                                nodes { title }
+//!                                                                pageInfo { hasNextPage endCursor }
+//!                                                            }
+//!                                                        }
+//!                                                    }",
+//!                                                    variables,
+//!                                                    "/repository/issues").unwrap();
+//! }
+//!
+//! ```
+
+use std::collections::BTreeMap;
+
+use hyper::method::Method;
+use serde;
+use serde_json;
+use serde_json::Value;
+
+use client::{Client, ServerVersion};
+use error;
+
+/// First Github Enterprise version supporting GraphQL.
+const GRAPHQL_VERSION: ServerVersion = ServerVersion { major: 2, minor: 9, patch: 0 };
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct GraphQLRequest<'a> {
+    query:     &'a str,
+    variables: &'a BTreeMap<String, Value>
+}
+
+/// Internal response
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct GraphQLResponse {
+    data:    Option<Value>,
+    errors:  Option<Vec<error::GraphQLError>>,
+    //Message of the responses which are not GraphQL results,
+    //ex. "Bad credentials"
+    message: Option<String>
+}
+
+/// The `pageInfo` object of a connection.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PageInfo {
+    #[serde(rename = "hasNextPage")]
+    pub has_next_page: bool,
+    #[serde(rename = "endCursor")]
+    pub end_cursor:    Option<String>
+}
+
+//Error for the data of a query, a connection or a field of
+//a connection missing from a response
+fn missing(message: String) -> error::Error {
+    error::Error::GraphQL(vec![error::GraphQLError {
+        message:    message,
+        error_type: None,
+        path:       None,
+        locations:  None
+    }])
+}
+
+//Returns the data of a response, or its errors. A response
+//without data nor errors is an error carrying its message,
+//or its body when it has none
+fn response_data(response_str: &str) -> Result<Value, error::Error> {
+
+    let graphql_response: GraphQLResponse = try!(serde_json::from_str(response_str).map_err(error::Error::Parsing));
+
+    if let Some(errors) = graphql_response.errors {
+        if !errors.is_empty() {
+            return Err(error::Error::GraphQL(errors));
+        }
+    }
+
+    match graphql_response.data {
+        Some(Value::Null) | None => Err(missing(graphql_response.message.unwrap_or_else(|| response_str.to_string()))),
+        Some(data)               => Ok(data)
+    }
+}
+
+//Returns the nodes and the pageInfo of a connection
+fn connection_page<D: serde::Deserialize>(data: &Value, connection: &str) -> Result<(Vec<D>, PageInfo), error::Error> {
+
+    let connection_data = match data.pointer(connection) {
+        Some(connection_data) if !connection_data.is_null() => connection_data,
+        _ => return Err(missing(format!("no connection at \"{}\" in the data of the query", connection)))
+    };
+
+    let page_nodes = try!(connection_data.pointer("/nodes").cloned().ok_or_else(|| missing(format!("the connection at \"{}\" has no `nodes`", connection))));
+    let page_info  = try!(connection_data.pointer("/pageInfo").cloned().ok_or_else(|| missing(format!("the connection at \"{}\" has no `pageInfo`", connection))));
+
+    let page_nodes = try!(serde_json::from_value(page_nodes).map_err(error::Error::Parsing));
+    let page_info  = try!(serde_json::from_value(page_info).map_err(error::Error::Parsing));
+    Ok((page_nodes, page_info))
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait GraphQLExt {
+
+    /// \[[Reference](https://developer.github.com/v4/guides/forming-calls/)\]
+    /// Runs a query and returns its `data` object as is.
+    /// ## Endpoint:
+    /// POST /graphql
+    /// ## Parameters
+    /// * `query`: The query document.
+    /// * `variables`: Values of the variables of the query.
+    /// ## Return Values:
+    /// * If the response contains errors: returns
+    /// Error::GraphQL, even if partial data was returned.
+    /// * If the response has no data nor errors: returns
+    /// Error::GraphQL with the message of the response.
+    fn graphql_raw(&self, query: &str, variables: BTreeMap<String, Value>) -> Result<Value, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v4/guides/forming-calls/)\]
+    /// Runs a query and deserializes its `data` object.
+    /// ## Endpoint:
+    /// POST /graphql
+    /// ## Parameters
+    /// * `query`: The query document.
+    /// * `variables`: Values of the variables of the query.
+    fn graphql<D: serde::Deserialize>(&self, query: &str, variables: BTreeMap<String, Value>) -> Result<D, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v4/guides/resource-limitations/)\]
+    /// Runs a query until all the pages of a connection are
+    /// fetched and returns the nodes of the connection. The
+    /// query must take a `$cursor: String` variable passed
+    /// to the `after` argument of the connection, and must
+    /// select its `nodes` and its
+    /// `pageInfo { hasNextPage endCursor }`.
+    /// ## Endpoint:
+    /// POST /graphql
+    /// ## Parameters
+    /// * `query`: The query document.
+    /// * `variables`: Values of the other variables of the
+    /// query.
+    /// * `connection`: JSON pointer to the connection in
+    /// the `data` object, ex. "/repository/issues".
+    /// ## Return Values:
+    /// * If the connection, its `nodes` or its `pageInfo`
+    /// are missing from the data: returns Error::GraphQL.
+    fn graphql_nodes<D: serde::Deserialize>(&self, query: &str, variables: BTreeMap<String, Value>, connection: &str) -> Result<Vec<D>, error::Error>;
+}
+
+impl GraphQLExt for Client {
+
+    fn graphql_raw(&self, query: &str, variables: BTreeMap<String, Value>) -> Result<Value, error::Error> {
+
+        try!(self.require_version("POST /graphql", GRAPHQL_VERSION));
+
+        //Create body
+        let body_data = try!(serde_json::to_string(&GraphQLRequest{
+            query:     query,
+            variables: &variables
+        }).map_err(error::Error::Parsing));
+
+        let mut response     = try!(self.request_url_body(Method::Post, self.graphql_url.clone(), None, body_data));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        response_data(&response_str[..])
+    }
+
+    fn graphql<D: serde::Deserialize>(&self, query: &str, variables: BTreeMap<String, Value>) -> Result<D, error::Error> {
+        let data = try!(self.graphql_raw(query, variables));
+        serde_json::from_value(data).map_err(error::Error::Parsing)
+    }
+
+    fn graphql_nodes<D: serde::Deserialize>(&self, query: &str, variables: BTreeMap<String, Value>, connection: &str) -> Result<Vec<D>, error::Error> {
+
+        let mut variables = variables;
+        let mut nodes     = Vec::new();
+
+        loop {
+
+            let data = try!(self.graphql_raw(query, variables.clone()));
+
+            let (mut page_nodes, page_info) = try!(connection_page::<D>(&data, connection));
+            nodes.append(&mut page_nodes);
+
+            match page_info.end_cursor {
+                Some(cursor) if page_info.has_next_page => {
+                    variables.insert("cursor".to_string(), Value::String(cursor));
+                },
+                _ => break
+            }
+        }
+
+        Ok(nodes)
+    }
+}
+
+#[cfg(test)]
+mod graphql_test {
+
+    use serde_json::{self, Value};
+
+    use error;
+    use super::{connection_page, response_data};
+
+    #[test]
+    fn connection() {
+        let data: Value = serde_json::from_str(r#"{
+            "repository": {
+                "issues": {
+                    "nodes":    [{"number": 1}, {"number": 2}],
+                    "pageInfo": {"hasNextPage": true, "endCursor": "Y3Vyc29y"}
+                }
+            }
+        }"#).unwrap();
+
+        let (nodes, page_info) = connection_page::<Value>(&data, "/repository/issues").unwrap();
+        assert!(nodes.len() == 2);
+        assert!(page_info.has_next_page);
+        assert!(page_info.end_cursor == Some(String::from("Y3Vyc29y")));
+
+        //A wrong pointer is not an empty connection
+        match connection_page::<Value>(&data, "/repository/pullRequests") {
+            Err(error::Error::GraphQL(_)) => (),
+            _                             => panic!("the connection should be missing")
+        }
+    }
+
+    #[test]
+    fn connection_missing_fields() {
+        let data: Value = serde_json::from_str(r#"{"repository": {"issues": {"nodes": []}}}"#).unwrap();
+        match connection_page::<Value>(&data, "/repository/issues") {
+            Err(error::Error::GraphQL(errors)) => assert!(errors[0].message.contains("pageInfo")),
+            _                                  => panic!("the pageInfo should be missing")
+        }
+    }
+
+    #[test]
+    fn response_missing_data() {
+        match response_data(r#"{"message": "Bad credentials", "documentation_url": "https://developer.github.com/v4"}"#) {
+            Err(error::Error::GraphQL(errors)) => assert!(errors[0].message == "Bad credentials"),
+            _                                  => panic!("the data should be missing")
+        }
+
+        match response_data(r#"{"errors": []}"#) {
+            Err(error::Error::GraphQL(errors)) => assert!(errors[0].message == r#"{"errors": []}"#),
+            _                                  => panic!("the data should be missing")
+        }
+
+        let data = response_data(r#"{"data": {"viewer": {"login": "octocat"}}}"#).unwrap();
+        assert!(data.pointer("/viewer/login") == Some(&Value::String(String::from("octocat"))));
+    }
+}
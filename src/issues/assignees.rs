@@ -5,20 +5,23 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-//! # Events
+//! # Assignees
 //!
-//! These are the responses and API call functions related
-//! to the event endpoints of the API.
+//! These are the API call functions related to the
+//! assignees endpoints of the API.
 //!
 //! Reference: https://developer.github.com/v3/issues/assignees/
 
+use hyper::method::Method;
+
 use client::Client;
 use common::User;
 use issues::Issue;
 use error;
-use utils;
+use path::{Owner, RepoName};
 
 ///Internal parameter
+#[derive(Serialize)]
 struct Assignees {
     assignees: Vec<String>
 }
@@ -33,17 +36,17 @@ pub trait AssigneesExt {
     /// ## Parameters
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_assignees(&mut self, owner: String, repo: String) -> Result<Vec<User>, error::Error>;
+    fn get_repos_owner_repo_assignees(&self, owner: Owner, repo: RepoName) -> Result<Vec<User>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/assignees/#check-assignee)\]
-    /// Checks is a user is an assignee for the repository.
+    /// Checks if a user is an assignee for the repository.
     /// ## Endpoint:
     /// GET /repos/:owner/:repo/assignees/:assignee
     /// ## Parameters
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `assignee`: Username to be checked.
-    fn get_repos_owner_repo_assignees_assignee(&mut self, owner: String, repo: String, assignee: String) -> Result<(), error::Error>;
+    fn get_repos_owner_repo_assignees_assignee(&self, owner: Owner, repo: RepoName, assignee: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/assignees/#add-assignees-to-an-issue)\]
     /// Adds assignees to the issue.
@@ -54,7 +57,7 @@ pub trait AssigneesExt {
     /// * `repo`: Name of the repository.
     /// * `number`: Issue number to add assignees.
     /// * `assignees`: Vec of Usernames to add as assignees.
-    fn post_repos_owner_repo_issues_number_assignees(&mut self, owner: String, repo: String, number: u64, assignees: Vec<String>) -> Result<Issue, error::Error>;
+    fn post_repos_owner_repo_issues_number_assignees(&self, owner: Owner, repo: RepoName, number: u64, assignees: Vec<String>) -> Result<Issue, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/assignees/#remove-assignees-from-an-issue)\]
     /// Deletes assignees from the issue.
@@ -63,45 +66,58 @@ pub trait AssigneesExt {
     /// ## Parameters
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    /// * `number`: Issue number to add assignees.
-    /// * `assignees`: Vec of Usernames to add as assignees.
-    fn delete_repos_owner_repo_issues_number_assignees(&mut self, owner: String, repo: String, number: u64, assignees: Vec<String>) -> Result<Issue, error::Error>;
+    /// * `number`: Issue number to remove assignees.
+    /// * `assignees`: Vec of Usernames to remove as assignees.
+    fn delete_repos_owner_repo_issues_number_assignees(&self, owner: Owner, repo: RepoName, number: u64, assignees: Vec<String>) -> Result<Issue, error::Error>;
 }
 
 impl AssigneesExt for Client {
 
-    fn get_repos_owner_repo_assignees(&mut self, owner: String, repo: String) -> Result<Vec<User>, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/assignees", owner, repo))
+    fn get_repos_owner_repo_assignees(&self, owner: Owner, repo: RepoName) -> Result<Vec<User>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/assignees")
+            .send_all()
     }
 
-    fn get_repos_owner_repo_assignees_assignee(&mut self, owner: String, repo: String, assignee: String) -> Result<(), error::Error> {
-        match self.put(format!("/repos/{}/{}/assignees/{}", owner, repo, assignee), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn get_repos_owner_repo_assignees_assignee(&self, owner: Owner, repo: RepoName, assignee: String) -> Result<(), error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/assignees")
+            .segment(assignee)
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn post_repos_owner_repo_issues_number_assignees(&mut self, owner: String, repo: String, number: u64, assignees: Vec<String>) -> Result<Issue, error::Error> {
-
-        let assignees_body = try!(serde_json::to_string(&Assignees{
-            assignees: assignees
-            }).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.post_body(format!("/notifications/threads/{}/subscription?{}", id, url.query().unwrap()), None, assignees_body));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn post_repos_owner_repo_issues_number_assignees(&self, owner: Owner, repo: RepoName, number: u64, assignees: Vec<String>) -> Result<Issue, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .path("/assignees")
+            .json(&Assignees {
+                assignees: assignees
+            })
+            .send()
+            .map(|response| response.data)
     }
 
-    fn delete_repos_owner_repo_issues_number_assignees(&mut self, owner: String, repo: String, number: u64, assignees: Vec<String>) -> Result<Issue, error::Error> {
-        let assignees_body = try!(serde_json::to_string(&Assignees{
-            assignees: assignees
-        }).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.delete_body(format!("/notifications/threads/{}/subscription?{}", id, url.query().unwrap()), None, assignees_body));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn delete_repos_owner_repo_issues_number_assignees(&self, owner: Owner, repo: RepoName, number: u64, assignees: Vec<String>) -> Result<Issue, error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .path("/assignees")
+            .json(&Assignees {
+                assignees: assignees
+            })
+            .send()
+            .map(|response| response.data)
     }
-
 }
 
 //TODO: TESTS
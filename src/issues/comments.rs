@@ -12,13 +12,14 @@
 //!
 //! Reference: https://developer.github.com/v3/issues/comments/
 
-use hyper::{Error as HyperError, Url};
+use hyper::method::Method;
 use serde_json;
 
 use client::Client;
-use common::Comment;
+use common::{Comment, DateTime};
 use issues::{Direction, Sort};
 use error;
+use path::{Owner, RepoName};
 use utils;
 
 /// Internal parameter
@@ -43,7 +44,7 @@ pub trait CommentsExt {
     /// * `number`: Issue number.
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_repos_owner_repo_issues_number_comments(&mut self, owner: String, repo: String, number: u64, since: String) -> Result<Vec<Comment>, error::Error>;
+    fn get_repos_owner_repo_issues_number_comments(&self, owner: Owner, repo: RepoName, number: u64, since: DateTime) -> Result<Vec<Comment>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/comments/#list-comments-in-a-repository)\]
     /// Returns the list of comments in a repository.
@@ -58,7 +59,7 @@ pub trait CommentsExt {
     /// sorting of returned issues.
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_repos_owner_repo_issues_comments(&mut self, owner: String, repo: String, sort: Option<Sort>, direction: Option<Direction>, since: String) -> Result<Vec<Comment>, error::Error>;
+    fn get_repos_owner_repo_issues_comments(&self, owner: Owner, repo: RepoName, sort: Option<Sort>, direction: Option<Direction>, since: DateTime) -> Result<Vec<Comment>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/comments/#get-a-single-comment)\]
     /// Returns a single comment.
@@ -68,7 +69,7 @@ pub trait CommentsExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `id`: Comment ID.
-    fn get_repos_owner_repo_issues_comments_id(&mut self, owner: String, repo: String, id: u64) -> Result<Comment, error::Error>;
+    fn get_repos_owner_repo_issues_comments_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<Comment, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/comments/#create-a-comment)\]
     /// Creates a comment.
@@ -79,7 +80,7 @@ pub trait CommentsExt {
     /// * `repo`: Name of the repository.
     /// * `number`: Issue number to put the comment on.
     /// * `body`: Content of the comment.
-    fn post_repos_owner_repo_issues_number_comments(&mut self, owner: String, repo: String, number: u64, body: String) -> Result<Comment, error::Error>;
+    fn post_repos_owner_repo_issues_number_comments(&self, owner: Owner, repo: RepoName, number: u64, body: String) -> Result<Comment, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/comments/#edit-a-comment)\]
     /// Edits a comment.
@@ -90,7 +91,7 @@ pub trait CommentsExt {
     /// * `repo`: Name of the repository.
     /// * `id`: Comment ID.
     /// * `body`: Content of the comment.
-    fn patch_repos_owner_repo_issues_comments_id(&mut self, owner: String, repo: String, id: u64, body: String) -> Result<Comment, error::Error>;
+    fn patch_repos_owner_repo_issues_comments_id(&self, owner: Owner, repo: RepoName, id: u64, body: String) -> Result<Comment, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/comments/#edit-a-comment)\]
     /// Edits a comment.
@@ -100,83 +101,64 @@ pub trait CommentsExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `id`: Comment ID.
-    fn delete_repos_owner_repo_issues_comments_id(&mut self, owner: String, repo: String, id: u64) -> Result<(), error::Error>;
+    fn delete_repos_owner_repo_issues_comments_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<(), error::Error>;
 }
 
 impl CommentsExt for Client {
 
-    fn get_repos_owner_repo_issues_number_comments(&mut self, owner: String, repo: String, number: u64, since: String) -> Result<Vec<Comment>, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/repos/{}/{}/issues/{}/comments", self.api_url, owner, repo, number)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues/{}/comments", owner, repo, number))
+    fn get_repos_owner_repo_issues_number_comments(&self, owner: Owner, repo: RepoName, number: u64, since: DateTime) -> Result<Vec<Comment>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .path("/comments")
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_repos_owner_repo_issues_comments(&mut self, owner: String, repo: String, sort: Option<Sort>, direction: Option<Direction>, since: String) -> Result<Vec<Comment>, error::Error> {
-
-        let mut url = match Url::parse(&format!("{}/repos/{}/{}/issues/comments", self.api_url, owner, repo)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = sort {
-                query_pairs.append_pair("sort", &param.to_str());
-            }
-
-            if let Some(param) = direction {
-                query_pairs.append_pair("direction", &param.to_str());
-            }
-
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues/comments", owner, repo))
+    fn get_repos_owner_repo_issues_comments(&self, owner: Owner, repo: RepoName, sort: Option<Sort>, direction: Option<Direction>, since: DateTime) -> Result<Vec<Comment>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues/comments")
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_repos_owner_repo_issues_comments_id(&mut self, owner: String, repo: String, id: u64) -> Result<Comment, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues/comments/{}", owner, repo, id))
+    fn get_repos_owner_repo_issues_comments_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<Comment, error::Error> {
+        utils::request_endpoint(self, format!("/repos/{}/{}/issues/comments/{}", owner.to_path(), repo.to_path(), id))
     }
 
-    fn post_repos_owner_repo_issues_number_comments(&mut self, owner: String, repo: String, number: u64, body: String) -> Result<Comment, error::Error> {
+    fn post_repos_owner_repo_issues_number_comments(&self, owner: Owner, repo: RepoName, number: u64, body: String) -> Result<Comment, error::Error> {
 
         //Create body
         let body_data = try!(serde_json::to_string(&Body{
             body: body
         }).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/issues/{}/comments", owner, repo, number), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/issues/{}/comments", owner.to_path(), repo.to_path(), number), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn patch_repos_owner_repo_issues_comments_id(&mut self, owner: String, repo: String, id: u64, body: String) -> Result<Comment, error::Error> {
+    fn patch_repos_owner_repo_issues_comments_id(&self, owner: Owner, repo: RepoName, id: u64, body: String) -> Result<Comment, error::Error> {
 
         let body_data = try!(serde_json::to_string(&Body{
             body: body
         }).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.patch_body(format!("/repos/{}/{}/issues/comments/{}", owner, repo, id), None, body_data));
+        let mut response     = try!(self.patch_body(format!("/repos/{}/{}/issues/comments/{}", owner.to_path(), repo.to_path(), id), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn delete_repos_owner_repo_issues_comments_id(&mut self, owner: String, repo: String, id: u64) -> Result<(), error::Error> {
-        match self.delete(format!("/repos/{}/{}/issues/comments/{}", owner, repo, id), None) {
+    fn delete_repos_owner_repo_issues_comments_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<(), error::Error> {
+        match self.delete(format!("/repos/{}/{}/issues/comments/{}", owner.to_path(), repo.to_path(), id), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
@@ -13,34 +13,61 @@
 //! Reference: https://developer.github.com/v3/issues/events/
 
 use client::Client;
-use common::User;
+use common::{DateTime, User};
 use issues::Issue;
 use error;
+use path::{Owner, RepoName};
 use utils;
 
+string_enum! {
+    /// Type of an issue event.
+    pub enum EventType {
+        Closed               => "closed",
+        Reopened             => "reopened",
+        Subscribed           => "subscribed",
+        Unsubscribed         => "unsubscribed",
+        Merged               => "merged",
+        Referenced           => "referenced",
+        Mentioned            => "mentioned",
+        Assigned             => "assigned",
+        Unassigned           => "unassigned",
+        Labeled              => "labeled",
+        Unlabeled            => "unlabeled",
+        Milestoned           => "milestoned",
+        Demilestoned         => "demilestoned",
+        Renamed              => "renamed",
+        Locked               => "locked",
+        Unlocked             => "unlocked",
+        HeadRefDeleted       => "head_ref_deleted",
+        HeadRefRestored      => "head_ref_restored",
+        ReviewRequested      => "review_requested",
+        ReviewRequestRemoved => "review_request_removed",
+        ReviewDismissed      => "review_dismissed"
+    }
+}
 
 /// Response element to the list events for an issue
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct IssueEvent {
     pub id:         u64,
     pub url:        String,
-    pub actor:      User,
-    pub event:      String,
-    pub commit_id:  String,
-    pub commit_url: String,
-    pub created_at: String
+    pub actor:      Option<User>,
+    pub event:      EventType,
+    pub commit_id:  Option<String>,
+    pub commit_url: Option<String>,
+    pub created_at: DateTime
 }
 
 /// Response element to the list events for a repository
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct IssueEventRepo {
-    pub id: u64,
-    pub url: String,
-    pub actor: User,
-    pub event:      String,
-    pub commit_id:  String,
-    pub commit_url: String,
-    pub created_at: String,
+    pub id:         u64,
+    pub url:        String,
+    pub actor:      Option<User>,
+    pub event:      EventType,
+    pub commit_id:  Option<String>,
+    pub commit_url: Option<String>,
+    pub created_at: DateTime,
     pub issue:      Issue
 }
 
@@ -58,7 +85,7 @@ pub trait IssueEventsExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `issue_number`: Number of the issue.
-    fn get_repos_owner_repo_issues_issue_number_events(&mut self, owner: String, repo: String, issue_number: u64) -> Result<Vec<IssueEvent>, error::Error>;
+    fn get_repos_owner_repo_issues_issue_number_events(&self, owner: Owner, repo: RepoName, issue_number: u64) -> Result<Vec<IssueEvent>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/events/#list-events-for-a-repository)\]
     /// Returns the list of events for a repository.
@@ -67,7 +94,7 @@ pub trait IssueEventsExt {
     /// ## Parameters
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_issues_events(&mut self, owner: String, repo: String) -> Result<Vec<IssueEventRepo>, error::Error>;
+    fn get_repos_owner_repo_issues_events(&self, owner: Owner, repo: RepoName) -> Result<Vec<IssueEventRepo>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/events/#get-a-single-event)\]
     /// Returns a single event for a repository.
@@ -77,21 +104,21 @@ pub trait IssueEventsExt {
     /// * `owner`: Owner of the repository.
     /// * `repo`: Name of the repository.
     /// * `id`: Issue ID.
-    fn get_repos_owner_repo_issues_events_id(&mut self, owner: String, repo: String, id: u64) -> Result<Vec<IssueEventRepo>, error::Error>;
+    fn get_repos_owner_repo_issues_events_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<IssueEventRepo, error::Error>;
 }
 
 impl IssueEventsExt for Client {
 
-    fn get_repos_owner_repo_issues_issue_number_events(&mut self, owner: String, repo: String, issue_number: u64) -> Result<Vec<IssueEvent>, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues/{}/events", owner, repo, issue_number))
+    fn get_repos_owner_repo_issues_issue_number_events(&self, owner: Owner, repo: RepoName, issue_number: u64) -> Result<Vec<IssueEvent>, error::Error> {
+        utils::request_endpoint(self, format!("/repos/{}/{}/issues/{}/events", owner.to_path(), repo.to_path(), issue_number))
     }
 
-    fn get_repos_owner_repo_issues_events(&mut self, owner: String, repo: String) -> Result<Vec<IssueEventRepo>, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues/events", owner, repo))
+    fn get_repos_owner_repo_issues_events(&self, owner: Owner, repo: RepoName) -> Result<Vec<IssueEventRepo>, error::Error> {
+        utils::request_endpoint(self, format!("/repos/{}/{}/issues/events", owner.to_path(), repo.to_path()))
     }
 
-    fn get_repos_owner_repo_issues_events_id(&mut self, owner: String, repo: String, id: u64) -> Result<Vec<IssueEventRepo>, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues/events/{}", owner, repo, id))
+    fn get_repos_owner_repo_issues_events_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<IssueEventRepo, error::Error> {
+        utils::request_endpoint(self, format!("/repos/{}/{}/issues/events/{}", owner.to_path(), repo.to_path(), id))
     }
 }
 
@@ -12,22 +12,16 @@
 //!
 //! Reference: https://developer.github.com/v3/issues/labels/
 
+use hyper::method::Method;
 use serde_json;
 
 use client::Client;
+use common::Edit;
 use error;
+pub use issues::Label;
+use path::{self, Owner, RepoName};
 use utils;
 
-/// Response element to the list labels endpoints.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
-pub struct Label {
-    pub id:      u64,
-    pub url:     String,
-    pub name:    String,
-    pub color:   String,
-    pub default: bool
-}
-
 ///Internal parameter
 #[derive(Clone, Debug, PartialEq, Serialize)]
 struct LabelParam {
@@ -35,6 +29,54 @@ struct LabelParam {
     color: String
 }
 
+///Input parameter for updating a label, only the fields
+/// that are set are changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct LabelEdit {
+    /// New name of the label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:        Option<String>,
+    /// Color of the label in 6 character hex code, without
+    /// the leading #.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color:       Option<String>,
+    /// Description of the label.
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub description: Edit<String>
+}
+
+impl LabelEdit {
+
+    /// Creates an edit that leaves the label unchanged.
+    pub fn new() -> LabelEdit {
+        LabelEdit::default()
+    }
+
+    /// Renames the label.
+    pub fn name(mut self, name: &str) -> LabelEdit {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the color of the label.
+    pub fn color(mut self, color: &str) -> LabelEdit {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Sets the description of the label.
+    pub fn description(mut self, description: &str) -> LabelEdit {
+        self.description = Edit::Set(description.to_string());
+        self
+    }
+
+    /// Removes the description of the label.
+    pub fn clear_description(mut self) -> LabelEdit {
+        self.description = Edit::Null;
+        self
+    }
+}
+
 ////////////////////////////////////////////////////////////
 //                    Extension Trait                     //
 ////////////////////////////////////////////////////////////
@@ -48,7 +90,7 @@ pub trait LabelsExt {
     /// ## Parameters
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
-    fn get_repos_owner_repo_labels(&mut self, owner: String, repo: String) -> Result<Vec<Label>, error::Error>;
+    fn get_repos_owner_repo_labels(&self, owner: Owner, repo: RepoName) -> Result<Vec<Label>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#get-a-single-label)\]
     /// Returns a single label.
@@ -58,7 +100,7 @@ pub trait LabelsExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `name`: Name of the label.
-    fn get_repos_owner_repo_labels_name(&mut self, owner: String, repo: String, name: String) -> Result<Label, error::Error>;
+    fn get_repos_owner_repo_labels_name(&self, owner: Owner, repo: RepoName, name: String) -> Result<Label, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#create-a-label)\]
     /// Create sa label.
@@ -70,20 +112,18 @@ pub trait LabelsExt {
     /// * `name`: Name of the label.
     /// * `color`: Color of the label in 6 character hex
     /// code, without the leading #, identifying the color.
-    fn post_repos_owner_repo_labels(&mut self, owner: String, repo: String, name: String, color: String) -> Result<Label, error::Error>;
+    fn post_repos_owner_repo_labels(&self, owner: Owner, repo: RepoName, name: String, color: String) -> Result<Label, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#update-a-label)\]
     /// Updates a label.
     /// ## Endpoint:
-    /// PATCH /repos/:owner/:repo/labels
+    /// PATCH /repos/:owner/:repo/labels/:name
     /// ## Parameters
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `name`: Name of the label.
-    /// * `new_name`: Name of the updated label.
-    /// * `color`: Color of the label in 6 character hex
-    /// code, without the leading #, identifying the color.
-    fn patch_repos_owner_repo_labels_name(&mut self, owner: String, repo: String, name: String, new_name: String, color: String) -> Result<Label, error::Error>;
+    /// * `label`: Input parameters, see `LabelEdit`.
+    fn patch_repos_owner_repo_labels_name(&self, owner: Owner, repo: RepoName, name: String, label: LabelEdit) -> Result<Label, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#delete-a-label)\]
     /// Deletes a label.
@@ -93,7 +133,7 @@ pub trait LabelsExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `name`: Name of the label.
-    fn delete_repos_owner_repo_labels_name(&mut self, owner: String, repo: String, name: String) -> Result<(), error::Error>;
+    fn delete_repos_owner_repo_labels_name(&self, owner: Owner, repo: RepoName, name: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#list-labels-on-an-issue)\]
     /// Returns a list of labels on an issue.
@@ -103,7 +143,7 @@ pub trait LabelsExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the issue.
-    fn get_repos_owner_repo_issues_number_labels(&mut self, owner: String, repo: String, number: u64) -> Result<Label, error::Error>;
+    fn get_repos_owner_repo_issues_number_labels(&self, owner: Owner, repo: RepoName, number: u64) -> Result<Label, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#add-labels-to-an-issue)\]
     /// Adds labels to an issue.
@@ -114,7 +154,7 @@ pub trait LabelsExt {
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the issue.
     /// * `labels`: A vector of label names.
-    fn post_repos_owner_repo_issues_number_labels(&mut self, owner: String, repo: String, number: u64, labels: Vec<String>) -> Result<Vec<Label>, error::Error>;
+    fn post_repos_owner_repo_issues_number_labels(&self, owner: Owner, repo: RepoName, number: u64, labels: Vec<String>) -> Result<Vec<Label>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#remove-a-label-from-an-issue)\]
     /// Removes a labels to an issue.
@@ -125,7 +165,7 @@ pub trait LabelsExt {
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the issue.
     /// * `name`: Name of the label.
-    fn delete_repos_owner_repo_issues_number_labels_name(&mut self, owner: String, repo: String, number: u64, name: String) -> Result<(), error::Error>;
+    fn delete_repos_owner_repo_issues_number_labels_name(&self, owner: Owner, repo: RepoName, number: u64, name: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#replace-all-labels-for-an-issue)\]
     /// Replaces all labels for an issue.
@@ -136,7 +176,7 @@ pub trait LabelsExt {
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the issue.
     /// * `labels`: A vector of label names.
-    fn put_repos_owner_repo_issues_number_labels(&mut self, owner: String, repo: String, number: u64, labels: Vec<String>) -> Result<Vec<Label>, error::Error>;
+    fn put_repos_owner_repo_issues_number_labels(&self, owner: Owner, repo: RepoName, number: u64, labels: Vec<String>) -> Result<Vec<Label>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#remove-all-labels-from-an-issue)\]
     /// Removes all labels for an issue.
@@ -146,7 +186,7 @@ pub trait LabelsExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the issue.
-    fn delete_repos_owner_repo_issues_number_labels(&mut self, owner: String, repo: String, number: u64) -> Result<(), error::Error>;
+    fn delete_repos_owner_repo_issues_number_labels(&self, owner: Owner, repo: RepoName, number: u64) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/labels/#get-labels-for-every-issue-in-a-milestone)\]
     /// Removes all labels for an issue.
@@ -156,20 +196,20 @@ pub trait LabelsExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the issue.
-    fn get_repos_owner_repo_milestones_number_labels(&mut self, owner: String, repo: String, number: u64) -> Result<Vec<Label>, error::Error>;
+    fn get_repos_owner_repo_milestones_number_labels(&self, owner: Owner, repo: RepoName, number: u64) -> Result<Vec<Label>, error::Error>;
 }
 
 impl LabelsExt for Client {
 
-    fn get_repos_owner_repo_labels(&mut self, owner: String, repo: String) -> Result<Vec<Label>, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/labels", owner, repo))
+    fn get_repos_owner_repo_labels(&self, owner: Owner, repo: RepoName) -> Result<Vec<Label>, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/labels", owner.to_path(), repo.to_path()))
     }
 
-    fn get_repos_owner_repo_labels_name(&mut self, owner: String, repo: String, name: String) -> Result<Label, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/labels/{}", owner, repo, name))
+    fn get_repos_owner_repo_labels_name(&self, owner: Owner, repo: RepoName, name: String) -> Result<Label, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/labels/{}", owner.to_path(), repo.to_path(), path::escape_segment(&name)))
     }
 
-    fn post_repos_owner_repo_labels(&mut self, owner: String, repo: String, name: String, color: String) -> Result<Label, error::Error>{
+    fn post_repos_owner_repo_labels(&self, owner: Owner, repo: RepoName, name: String, color: String) -> Result<Label, error::Error>{
 
         //Create body
         let body_data = try!(serde_json::to_string(&LabelParam{
@@ -177,69 +217,67 @@ impl LabelsExt for Client {
             color: color
         }).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/labels", owner, repo), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/labels", owner.to_path(), repo.to_path()), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn patch_repos_owner_repo_labels_name(&mut self, owner: String, repo: String, name: String, new_name: String, color: String) -> Result<Label, error::Error>{
-
-        //Create body
-        let body_data = try!(serde_json::to_string(&LabelParam{
-            name:  new_name,
-            color: color
-        }).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.patch_body(format!("/repos/{}/{}/labels/{}", owner, repo, name), None, body_data));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn patch_repos_owner_repo_labels_name(&self, owner: Owner, repo: RepoName, name: String, label: LabelEdit) -> Result<Label, error::Error>{
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/labels")
+            .segment(name)
+            .json(&label)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn delete_repos_owner_repo_labels_name(&mut self, owner: String, repo: String, name: String) -> Result<(), error::Error>{
-        match self.delete(format!("/repos/{}/{}/labels/{}", owner, repo, name), None) {
+    fn delete_repos_owner_repo_labels_name(&self, owner: Owner, repo: RepoName, name: String) -> Result<(), error::Error>{
+        match self.delete(format!("/repos/{}/{}/labels/{}", owner.to_path(), repo.to_path(), path::escape_segment(&name)), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
     }
 
-    fn get_repos_owner_repo_issues_number_labels(&mut self, owner: String, repo: String, number: u64) -> Result<Label, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues/{}/labels", owner, repo, number))
+    fn get_repos_owner_repo_issues_number_labels(&self, owner: Owner, repo: RepoName, number: u64) -> Result<Label, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/issues/{}/labels", owner.to_path(), repo.to_path(), number))
     }
 
-    fn post_repos_owner_repo_issues_number_labels(&mut self, owner: String, repo: String, number: u64, labels: Vec<String>) -> Result<Vec<Label>, error::Error>{
+    fn post_repos_owner_repo_issues_number_labels(&self, owner: Owner, repo: RepoName, number: u64, labels: Vec<String>) -> Result<Vec<Label>, error::Error>{
 
         let body_data = try!(serde_json::to_string(&labels).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/issues/{}/labels", owner, repo, number), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/issues/{}/labels", owner.to_path(), repo.to_path(), number), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn delete_repos_owner_repo_issues_number_labels_name(&mut self, owner: String, repo: String, number: u64, name: String) -> Result<(), error::Error>{
-        match self.delete(format!("/repos/{}/{}/issues/{}/labels/{}", owner, repo, number, name), None) {
+    fn delete_repos_owner_repo_issues_number_labels_name(&self, owner: Owner, repo: RepoName, number: u64, name: String) -> Result<(), error::Error>{
+        match self.delete(format!("/repos/{}/{}/issues/{}/labels/{}", owner.to_path(), repo.to_path(), number, path::escape_segment(&name)), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
     }
 
-    fn put_repos_owner_repo_issues_number_labels(&mut self, owner: String, repo: String, number: u64, labels: Vec<String>) -> Result<Vec<Label>, error::Error>{
+    fn put_repos_owner_repo_issues_number_labels(&self, owner: Owner, repo: RepoName, number: u64, labels: Vec<String>) -> Result<Vec<Label>, error::Error>{
 
         let body_data = try!(serde_json::to_string(&labels).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.put_body(format!("/repos/{}/{}/issues/{}/labels", owner, repo, number), None, body_data));
+        let mut response     = try!(self.put_body(format!("/repos/{}/{}/issues/{}/labels", owner.to_path(), repo.to_path(), number), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn delete_repos_owner_repo_issues_number_labels(&mut self, owner: String, repo: String, number: u64) -> Result<(), error::Error>{
-        match self.delete(format!("/repos/{}/{}/issues/{}/labels", owner, repo, number), None) {
+    fn delete_repos_owner_repo_issues_number_labels(&self, owner: Owner, repo: RepoName, number: u64) -> Result<(), error::Error>{
+        match self.delete(format!("/repos/{}/{}/issues/{}/labels", owner.to_path(), repo.to_path(), number), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
     }
 
-    fn get_repos_owner_repo_milestones_number_labels(&mut self, owner: String, repo: String, number: u64) -> Result<Vec<Label>, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/milestones/{}/labels", owner, repo, number))
+    fn get_repos_owner_repo_milestones_number_labels(&self, owner: Owner, repo: RepoName, number: u64) -> Result<Vec<Label>, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/milestones/{}/labels", owner.to_path(), repo.to_path(), number))
     }
 }
 
@@ -12,28 +12,22 @@
 //!
 //! Reference: https://developer.github.com/v3/issues/labels/
 
-use hyper::{Error as HyperError, Url};
+use hyper::method::Method;
 use serde_json;
 
 use client::Client;
-use issues::{Direction, Milestone, State};
+use common::{DateTime, Edit};
+use issues::{Direction, Milestone, State, StateFilter};
 use error;
+use path::{Owner, RepoName};
 use utils;
 
-/// Possible values for the state parameter, it indicates
-/// the state of the issues to return.
-#[derive(Clone, Debug, PartialEq)]
-pub enum Sort {
-    Completeness,
-    DueOn
-}
-
-impl Sort {
-    fn to_str(&self) -> String {
-        match self {
-            &Sort::Completeness => "completeness".to_string(),
-            &Sort::DueOn        => "due_on".to_string(),
-        }
+string_enum! {
+    /// Possible values for the sort parameter, it indicates
+    /// the order of the milestones to return.
+    pub enum Sort {
+        Completeness => "completeness",
+        DueOn        => "due_on"
     }
 }
 
@@ -41,14 +35,94 @@ impl Sort {
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct MilestoneParam {
     /// Title of the milestone.
-    title:       String,
+    pub title:       String,
     /// Default: `State::Open`, state of the milestone
-    state:       Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state:       Option<State>,
     /// Description of the milestone.
-    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /// Milestone's due date, a timestamp in ISO 8601
     /// (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    due_on:      String
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on:      Option<DateTime>
+}
+
+impl MilestoneParam {
+
+    /// Creates the parameters of an open milestone
+    /// without description nor due date.
+    pub fn new(title: &str) -> MilestoneParam {
+        MilestoneParam {
+            title:       title.to_string(),
+            state:       None,
+            description: None,
+            due_on:      None
+        }
+    }
+}
+
+///Input parameter for editing a milestone, only the fields
+/// that are set are changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MilestoneEdit {
+    /// Title of the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title:       Option<String>,
+    /// State of the milestone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state:       Option<State>,
+    /// Description of the milestone.
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub description: Edit<String>,
+    /// Milestone's due date, set to `Edit::Null` to remove
+    /// it.
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub due_on:      Edit<DateTime>
+}
+
+impl MilestoneEdit {
+
+    /// Creates an edit that leaves the milestone unchanged.
+    pub fn new() -> MilestoneEdit {
+        MilestoneEdit::default()
+    }
+
+    /// Sets the title of the milestone.
+    pub fn title(mut self, title: &str) -> MilestoneEdit {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets the state of the milestone.
+    pub fn state(mut self, state: State) -> MilestoneEdit {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the description of the milestone.
+    pub fn description(mut self, description: &str) -> MilestoneEdit {
+        self.description = Edit::Set(description.to_string());
+        self
+    }
+
+    /// Removes the description of the milestone.
+    pub fn clear_description(mut self) -> MilestoneEdit {
+        self.description = Edit::Null;
+        self
+    }
+
+    /// Sets the due date of the milestone.
+    pub fn due_on(mut self, due_on: DateTime) -> MilestoneEdit {
+        self.due_on = Edit::Set(due_on);
+        self
+    }
+
+    /// Removes the due date of the milestone.
+    pub fn clear_due_on(mut self) -> MilestoneEdit {
+        self.due_on = Edit::Null;
+        self
+    }
 }
 
 ////////////////////////////////////////////////////////////
@@ -64,14 +138,14 @@ pub trait MilestonesExt {
     /// ## Parameters
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
-    /// * `state`: Default: `State::Open`, indicates
+    /// * `state`: Default: `StateFilter::Open`, indicates
     /// the state of the milestones to return.
     /// * `sort`: Default: `Sort::DueOn`, the requested
     /// sorting of returned milestones.
     /// * `direction`: Default: `Direction::Ascending`,
     /// the requested sorting direction of returned
     /// milestones.
-    fn get_repos_owner_repo_milestones(&mut self, owner: String, repo: String, state: Option<State>, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Milestone>, error::Error>;
+    fn get_repos_owner_repo_milestones(&self, owner: Owner, repo: RepoName, state: Option<StateFilter>, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Milestone>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/milestones/#get-a-single-milestone)\]
     /// Returns the list of milestones for a repository.
@@ -81,7 +155,7 @@ pub trait MilestonesExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the milestone.
-    fn get_repos_owner_repo_milestones_number(&mut self, owner: String, repo: String, number: u64) -> Result<Milestone, error::Error>;
+    fn get_repos_owner_repo_milestones_number(&self, owner: Owner, repo: RepoName, number: u64) -> Result<Milestone, error::Error>;
 
     /// \[[Reference](hhttps://developer.github.com/v3/issues/milestones/#create-a-milestone)\]
     /// Creates a milestone.
@@ -92,7 +166,7 @@ pub trait MilestonesExt {
     /// * `repo`: Name of the repository.
     /// * `milestone`: Input parameters, see
     /// `MilestoneParam`.
-    fn post_repos_owner_repo_milestones(&mut self, owner: String, repo: String, milestone: MilestoneParam) -> Result<Milestone, error::Error>;
+    fn post_repos_owner_repo_milestones(&self, owner: Owner, repo: RepoName, milestone: MilestoneParam) -> Result<Milestone, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/milestones/#update-a-milestone)\]
     /// Updates a milestone.
@@ -103,8 +177,8 @@ pub trait MilestonesExt {
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the milestone to be updated.
     /// * `milestone`: Input parameters, see
-    /// `MilestoneParam`.
-    fn patch_repos_owner_repo_milestones_number(&mut self, owner: String, repo: String, number: u64, milestone: MilestoneParam) -> Result<Milestone, error::Error>;
+    /// `MilestoneEdit`.
+    fn patch_repos_owner_repo_milestones_number(&self, owner: Owner, repo: RepoName, number: u64, milestone: MilestoneEdit) -> Result<Milestone, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/milestones/#update-a-milestone)\]
     /// Deletes a milestone.
@@ -114,65 +188,50 @@ pub trait MilestonesExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `number`: Number of the milestone to be updated.
-    fn delete_repos_owner_repo_milestones_number(&mut self, owner: String, repo: String, number: u64) -> Result<(), error::Error>;
+    fn delete_repos_owner_repo_milestones_number(&self, owner: Owner, repo: RepoName, number: u64) -> Result<(), error::Error>;
 }
 
 impl MilestonesExt for Client {
 
-    fn get_repos_owner_repo_milestones(&mut self, owner: String, repo: String, state: Option<State>, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Milestone>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/repos/{}/{}/milestones", self.api_url, owner, repo)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = state {
-                query_pairs.append_pair("state", &param.to_str());
-            }
-
-            if let Some(param) = sort {
-                query_pairs.append_pair("sort", &param.to_str());
-            }
-
-            if let Some(param) = direction {
-                query_pairs.append_pair("direction", &param.to_str());
-            }
-        }
-
-        utils::request_endpoint(self, format!("/repos/{}/{}/milestones?{}", owner, repo, url.query().unwrap()))
+    fn get_repos_owner_repo_milestones(&self, owner: Owner, repo: RepoName, state: Option<StateFilter>, sort: Option<Sort>, direction: Option<Direction>) -> Result<Vec<Milestone>, error::Error>{
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/milestones")
+            .query_opt("state", state)
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_repos_owner_repo_milestones_number(&mut self, owner: String, repo: String, number: u64) -> Result<Milestone, error::Error>{
-        utils::request_endpoint(self, format!("/repos/{}/{}/milestones/{}", owner, repo, number))
+    fn get_repos_owner_repo_milestones_number(&self, owner: Owner, repo: RepoName, number: u64) -> Result<Milestone, error::Error>{
+        utils::request_endpoint(self, format!("/repos/{}/{}/milestones/{}", owner.to_path(), repo.to_path(), number))
     }
 
-    fn post_repos_owner_repo_milestones(&mut self, owner: String, repo: String, milestone: MilestoneParam) -> Result<Milestone, error::Error>{
+    fn post_repos_owner_repo_milestones(&self, owner: Owner, repo: RepoName, milestone: MilestoneParam) -> Result<Milestone, error::Error>{
 
         //Create body
         let body_data = try!(serde_json::to_string(&milestone).map_err(error::Error::Parsing));
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/milestones", owner, repo), None, body_data));
+        let mut response     = try!(self.post_body(format!("/repos/{}/{}/milestones", owner.to_path(), repo.to_path()), None, body_data));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn patch_repos_owner_repo_milestones_number(&mut self, owner: String, repo: String, number: u64, milestone: MilestoneParam) -> Result<Milestone, error::Error>{
-
-        //Create body
-        let body_data = try!(serde_json::to_string(&milestone).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.patch_body(format!("/repos/{}/{}/milestones/{}", owner, repo, number), None, body_data));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn patch_repos_owner_repo_milestones_number(&self, owner: Owner, repo: RepoName, number: u64, milestone: MilestoneEdit) -> Result<Milestone, error::Error>{
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/milestones")
+            .segment(number)
+            .json(&milestone)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn delete_repos_owner_repo_milestones_number(&mut self, owner: String, repo: String, number: u64) -> Result<(), error::Error>{
-        match self.delete(format!("/repos/{}/{}/milestones/{}", owner, repo, number), None) {
+    fn delete_repos_owner_repo_milestones_number(&self, owner: Owner, repo: RepoName, number: u64) -> Result<(), error::Error>{
+        match self.delete(format!("/repos/{}/{}/milestones/{}", owner.to_path(), repo.to_path(), number), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
@@ -5,8 +5,6 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-//TODO: Add reactions once out of preview
-
 //! # Issues
 //!
 //! Modules for subsections of Issues in the Github API docs
@@ -14,14 +12,16 @@
 //!
 //! Reference: https://developer.github.com/v3/issues/
 
-use hyper::{Error as HyperError, Url};
-use serde_json;
+use hyper::method::Method;
 
 use client::Client;
-use common::{Repository, User};
+use common::{DateTime, Edit, Reactions, Repository, User};
 use error;
-use utils;
+use path::{Owner, RepoName};
+use request::QueryValue;
 
+/// Endpoints for issue assignees.
+pub mod assignees;
 /// Endpoints for issue events.
 pub mod events;
 /// Endpoints for issue comments.
@@ -30,12 +30,13 @@ pub mod comments;
 pub mod labels;
 /// Endpoints for issue milestones.
 pub mod milestones;
-//TODO: Implement timeline once out of preview
-//pub mod timeline;
+/// Endpoints for issue timelines.
+#[cfg(feature = "preview-timeline")]
+pub mod timeline;
 
 /// Return type for Issue endpoints and sub component for
 /// Issue Event responses
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Issue {
     pub id:             u64,
     pub url:            String,
@@ -45,22 +46,23 @@ pub struct Issue {
     pub events_url:     String,
     pub html_url:       String,
     pub number:         u64,
-    pub state:          String,
+    pub state:          State,
     pub title:          String,
-    pub body:           String,
+    pub body:           Option<String>,
     pub user:           User,
     pub labels:         Vec<Label>,
-    pub assignee:       User,
-    pub milestone:      Milestone,
+    pub assignee:       Option<User>,
+    pub milestone:      Option<Milestone>,
     pub locked:         bool,
     pub comments:       u64,
     pub pull_request:   Option<PullRequest>,
-    pub closed_at:      Option<String>,
-    pub created_at:     String,
-    pub updated_at:     String,
+    pub closed_at:      Option<DateTime>,
+    pub created_at:     DateTime,
+    pub updated_at:     DateTime,
     pub repository:     Option<Repository>,
     pub closed_by:      Option<User>,
-    pub assignees:      Option<Vec<User>>
+    pub assignees:      Option<Vec<User>>,
+    pub reactions:      Option<Reactions>
 }
 
 /// Parameters for the creation of an issue.
@@ -86,162 +88,188 @@ pub struct IssueCreate {
     pub labels:    Vec<String>
 }
 
-/// Parameters for the editing of an issue.
-#[derive(Clone, Debug, Serialize, PartialEq)]
+/// Parameters for the editing of an issue, only the fields
+/// that are set are changed.
+///
+/// ```rust,ignore
+/// let edit = IssueEdit::new().state(State::Closed).clear_milestone();
+/// ```
+#[derive(Clone, Debug, Default, Serialize, PartialEq)]
 pub struct IssueEdit {
     /// Title of the issue
-    pub title:     String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title:     Option<String>,
     /// Content of the issue
-    pub body:      String,
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub body:      Edit<String>,
     /// Usernames of the users that should be assigned to
     /// the issue. Set the Vec to empty to remove all
     /// assignees. NOTE: Only users with push access can
     /// set assignees for new issues. Assignees are
     /// silently dropped otherwise.
-    pub assignees: Vec<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<String>>,
     /// The desired state of the issue.
-    pub state:     StateEdit,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state:     Option<State>,
     /// Number of the milestone to be associated with the
-    /// issue. Set to `Option::None` to remove the milestone
+    /// issue. Set to `Edit::Null` to remove the milestone
     /// NOTE: Only users with push access can set labels for
     /// issues. Labels are silently dropped otherwise.
-    pub milestone: Option<u64>,
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub milestone: Edit<u64>,
     /// Labels to be associated to the issue. Set the Vec to
     /// empty to remove all labels. NOTE: Only users with
     /// push access can set labels for issues. Labels are
     /// silently dropped otherwise.
-    pub labels:    Vec<String>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels:    Option<Vec<String>>
 }
 
-/// Sub-component for Issue
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+impl IssueEdit {
+
+    /// Creates an edit that leaves the issue unchanged.
+    pub fn new() -> IssueEdit {
+        IssueEdit::default()
+    }
+
+    /// Sets the title of the issue.
+    pub fn title(mut self, title: &str) -> IssueEdit {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets the content of the issue.
+    pub fn body(mut self, body: &str) -> IssueEdit {
+        self.body = Edit::Set(body.to_string());
+        self
+    }
+
+    /// Removes the content of the issue.
+    pub fn clear_body(mut self) -> IssueEdit {
+        self.body = Edit::Null;
+        self
+    }
+
+    /// Replaces the assignees of the issue.
+    pub fn assignees(mut self, assignees: Vec<String>) -> IssueEdit {
+        self.assignees = Some(assignees);
+        self
+    }
+
+    /// Sets the state of the issue.
+    pub fn state(mut self, state: State) -> IssueEdit {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the milestone of the issue by its number.
+    pub fn milestone(mut self, milestone: u64) -> IssueEdit {
+        self.milestone = Edit::Set(milestone);
+        self
+    }
+
+    /// Removes the milestone of the issue.
+    pub fn clear_milestone(mut self) -> IssueEdit {
+        self.milestone = Edit::Null;
+        self
+    }
+
+    /// Replaces the labels of the issue.
+    pub fn labels(mut self, labels: Vec<String>) -> IssueEdit {
+        self.labels = Some(labels);
+        self
+    }
+}
+
+/// Sub-component for Issue and response element to the
+/// list labels endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Label {
-    pub id:      u64,
-    pub url:     String,
-    pub name:    String,
-    pub color:   String,
-    pub default: bool
+    pub id:          u64,
+    pub url:         String,
+    pub name:        String,
+    pub description: Option<String>,
+    pub color:       String,
+    pub default:     bool
 }
 
 /// Sub-component for Issue
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Milestone {
     pub url:           String,
     pub html_url:      String,
     pub labels_url:    String,
     pub id:            u64,
-    pub number:        String,
-    pub state:         String,
+    pub number:        u64,
+    pub state:         State,
     pub title:         String,
-    pub description:   String,
-    pub creator:       User,
+    pub description:   Option<String>,
+    pub creator:       Option<User>,
     pub open_issues:   u64,
     pub closed_issues: u64,
-    pub created_at:    String,
-    pub updated_at:    String,
-    pub closed_at:     Option<String>,
-    pub due_on:        String
+    pub created_at:    DateTime,
+    pub updated_at:    DateTime,
+    pub closed_at:     Option<DateTime>,
+    pub due_on:        Option<DateTime>
 }
 
 /// Sub-component for Issue
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct PullRequest {
     pub url:       String,
     pub html_url:  String,
     pub diff_url:  String,
-    pub patch_url: String
+    pub patch_url: String,
+    pub merged_at: Option<DateTime>
 }
 
-/// Sub-component of IssueEdit, it represents the desired
-/// state of the issue.
-#[derive(Clone, Debug, Serialize, PartialEq)]
-pub enum StateEdit {
-    Open,
-    Closed
-}
-
-/// Possible values for the filter parameter, it indicates
-/// which kind of issues to return.
-#[derive(Clone, Debug, PartialEq)]
-pub enum Filter {
-    /// Issues assigned to the authenticated user
-    Assigned,
-    /// Issues created by the authenticated user
-    Created,
-    /// Issues mentioning the authenticate user
-    Mentioned,
-    /// Issues that the authenticate user is subscribed to
-    Subscribed,
-    /// All issues the authenticate user can see.
-    All
-}
-
-impl Filter {
-    fn to_str(&self) -> String {
-        match self {
-            &Filter::All =>        "all".to_string(),
-            &Filter::Assigned =>   "assigned".to_string(),
-            &Filter::Created =>    "created".to_string(),
-            &Filter::Mentioned =>  "mentioned".to_string(),
-            &Filter::Subscribed => "subscribed".to_string()
-        }
+string_enum! {
+    /// Possible values for the filter parameter, it
+    /// indicates which kind of issues to return.
+    pub enum Filter {
+        /// Issues assigned to the authenticated user
+        Assigned   => "assigned",
+        /// Issues created by the authenticated user
+        Created    => "created",
+        /// Issues mentioning the authenticate user
+        Mentioned  => "mentioned",
+        /// Issues that the authenticate user is subscribed to
+        Subscribed => "subscribed",
+        /// All issues the authenticate user can see.
+        All        => "all"
     }
 }
 
-/// Possible values for the state parameter, it indicates
-/// the state of the issues to return.
-#[derive(Clone, Debug, PartialEq)]
-pub enum State {
-    Open,
-    Closed,
-    All
-}
-
-impl State {
-    fn to_str(&self) -> String {
-        match self {
-            &State::All    => "all".to_string(),
-            &State::Closed => "closed".to_string(),
-            &State::Open   => "open".to_string()
-        }
+string_enum! {
+    /// State of an issue, a milestone or a project.
+    pub enum State {
+        Open   => "open",
+        Closed => "closed"
     }
 }
 
-/// Possible values for the sorting of returned issues
-#[derive(Clone, Debug, PartialEq)]
-pub enum Sort {
-    Created,
-    Updated,
-    Comments
-}
-
-impl Sort {
-    fn to_str(&self) -> String {
-        match self {
-            &Sort::Comments => "comments".to_string(),
-            &Sort::Created  => "created".to_string(),
-            &Sort::Updated  => "updated".to_string()
-        }
+string_enum! {
+    /// Possible values for the state parameter, it
+    /// indicates the state of the issues to return.
+    pub enum StateFilter {
+        Open   => "open",
+        Closed => "closed",
+        All    => "all"
     }
 }
 
-/// Possible values for the sorting direction of returned
-/// issues.
-#[derive(Clone, Debug, PartialEq)]
-pub enum Direction {
-    Ascending,
-    Descending
-}
-
-impl Direction {
-    fn to_str(&self) -> String {
-        match self {
-            &Direction::Ascending  => "asc".to_string(),
-            &Direction::Descending => "desc".to_string()
-        }
+string_enum! {
+    /// Possible values for the sorting of returned issues
+    pub enum Sort {
+        Created  => "created",
+        Updated  => "updated",
+        Comments => "comments"
     }
 }
 
+pub use common::Direction;
+
 /// Possible values for the milestone parameter, it
 /// indicates which issues to return by the milestone.
 #[derive(Clone, Debug, PartialEq)]
@@ -257,12 +285,12 @@ pub enum MilestoneParam {
 
 }
 
-impl MilestoneParam {
-    fn to_str(self) -> String {
+impl QueryValue for MilestoneParam {
+    fn to_query(&self) -> String {
         match self {
-            MilestoneParam::Integer(param) => format!("{}", param),
-            MilestoneParam::String(param)  => param,
-            MilestoneParam::None           => "none".to_string()
+            &MilestoneParam::Integer(param)    => format!("{}", param),
+            &MilestoneParam::String(ref param) => param.clone(),
+            &MilestoneParam::None              => "none".to_string()
         }
     }
 }
@@ -279,11 +307,11 @@ pub enum Assignee {
     None
 }
 
-impl Assignee {
-    fn to_str(self) -> String {
+impl QueryValue for Assignee {
+    fn to_query(&self) -> String {
         match self {
-            Assignee::User(param) => param,
-            Assignee::None        => "none".to_string()
+            &Assignee::User(ref param) => param.clone(),
+            &Assignee::None            => "none".to_string()
         }
     }
 }
@@ -302,7 +330,7 @@ pub trait IssuesExt {
     /// ## Parameters
     /// * `filter`: Default: `Filter::Assigned`, indicates
     /// which kind of issues to return.
-    /// * `state`: Default: `State::Open`, indicates
+    /// * `state`: Default: `StateFilter::Open`, indicates
     /// the state of the issues to return.
     /// * `labels`: An Vec of labels.
     /// * `sort`: Default: `Sort::Created`, the requested
@@ -311,13 +339,13 @@ pub trait IssuesExt {
     /// the requested sorting direction of returned issues.
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_issues(&mut self,
+    fn get_issues(&self,
                   filter: Option<Filter>,
-                  state: Option<State>,
+                  state: Option<StateFilter>,
                   labels: Vec<String>,
                   sort: Option<Sort>,
                   direction: Option<Direction>,
-                  since: String) -> Result<Vec<Issue>, error::Error>;
+                  since: DateTime) -> Result<Vec<Issue>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#list-stargazers)\]
     /// Returns the list of issues assigned to an
@@ -327,7 +355,7 @@ pub trait IssuesExt {
     /// ## Parameters
     /// * `filter`: Default: `Filter::Assigned`, indicates
     /// which kind of issues to return.
-    /// * `state`: Default: `State::Open`, indicates
+    /// * `state`: Default: `StateFilter::Open`, indicates
     /// the state of the issues to return.
     /// * `labels`: An Vec of labels.
     /// * `sort`: Default: `Sort::Created`, the requested
@@ -336,13 +364,13 @@ pub trait IssuesExt {
     /// the requested sorting direction of returned issues.
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_user_issues(&mut self,
+    fn get_user_issues(&self,
                        filter: Option<Filter>,
-                       state: Option<State>,
+                       state: Option<StateFilter>,
                        labels: Vec<String>,
                        sort: Option<Sort>,
                        direction: Option<Direction>,
-                       since: String) -> Result<Vec<Issue>, error::Error>;
+                       since: DateTime) -> Result<Vec<Issue>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/activity/starring/#list-stargazers)\]
     /// Returns the list of issues assigned to an
@@ -353,7 +381,7 @@ pub trait IssuesExt {
     /// * `org`: Name of the organization.
     /// * `filter`: Default: `Filter::Assigned`, indicates
     /// which kind of issues to return.
-    /// * `state`: Default: `State::Open`, indicates
+    /// * `state`: Default: `StateFilter::Open`, indicates
     /// the state of the issues to return.
     /// * `labels`: An Vec of labels.
     /// * `sort`: Default: `Sort::Created`, the requested
@@ -362,14 +390,14 @@ pub trait IssuesExt {
     /// the requested sorting direction of returned issues.
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_orgs_org_issues(&mut self,
+    fn get_orgs_org_issues(&self,
                            org: String,
                            filter: Option<Filter>,
-                           state: Option<State>,
+                           state: Option<StateFilter>,
                            labels: Vec<String>,
                            sort: Option<Sort>,
                            direction: Option<Direction>,
-                           since: String) -> Result<Vec<Issue>, error::Error>;
+                           since: DateTime) -> Result<Vec<Issue>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/#list-issues-for-a-repository)\]
     /// Returns the list of issues for a repository.
@@ -380,7 +408,7 @@ pub trait IssuesExt {
     /// * `repo`: Name of the repository.
     /// * `milestone`: Milestone of the issues to be
     /// returned.
-    /// * `state`: Default: `State::Open`, indicates
+    /// * `state`: Default: `StateFilter::Open`, indicates
     /// the state of the issues to return.
     /// * `assignee`: Assignee of the issues to be returned.
     /// * `creator`: User that created the issue.
@@ -391,18 +419,18 @@ pub trait IssuesExt {
     /// the requested sorting direction of returned issues.
     /// * `since`: Issues only updated at or after the time
     /// in ISO 8601 (`YYYY-MM-DDTHH:MM:SSZ`) format.
-    fn get_repos_owner_repo_issues(&mut self,
-                                   owner: String,
-                                   repo: String,
+    fn get_repos_owner_repo_issues(&self,
+                                   owner: Owner,
+                                   repo: RepoName,
                                    milestone: MilestoneParam,
-                                   state: Option<State>,
+                                   state: Option<StateFilter>,
                                    assignee: Assignee,
                                    creator: String,
                                    mentioned: Option<String>,
                                    labels: Vec<String>,
                                    sort: Option<Sort>,
                                    direction: Option<Direction>,
-                                   since: String) -> Result<Vec<Issue>, error::Error>;
+                                   since: DateTime) -> Result<Vec<Issue>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/#get-a-single-issue)\]
     /// Returns an issue for a repository.
@@ -412,7 +440,7 @@ pub trait IssuesExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `number`: Issue number.
-    fn get_repos_owner_repo_issues_number(&mut self, owner: String, repo: String, number: u64) -> Result<Issue, error::Error>;
+    fn get_repos_owner_repo_issues_number(&self, owner: Owner, repo: RepoName, number: u64) -> Result<Issue, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/#create-an-issue)\]
     /// Creates an issue for a repository.
@@ -423,7 +451,7 @@ pub trait IssuesExt {
     /// * `owner`: Owner of the repo.
     /// * `repo`: Name of the repository.
     /// * `issue`: Issue parameters.
-    fn post_repos_owner_repo_issues(&mut self, owner: String, repo: String, issue: IssueCreate) -> Result<Issue, error::Error>;
+    fn post_repos_owner_repo_issues(&self, owner: Owner, repo: RepoName, issue: IssueCreate) -> Result<Issue, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/#edit-an-issue)\]
     /// Edits an issue for a repository.
@@ -435,7 +463,7 @@ pub trait IssuesExt {
     /// * `repo`: Name of the repository.
     /// * `number`: Issue number.
     /// * `issue`: Issue edit parameters.
-    fn patch_repos_owner_repo_issues_number(&mut self, owner: String, repo: String, number: u64, issue: IssueEdit) -> Result<Issue, error::Error>;
+    fn patch_repos_owner_repo_issues_number(&self, owner: Owner, repo: RepoName, number: u64, issue: IssueEdit) -> Result<Issue, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/issues/#lock-an-issue)\]
     /// Locks an issue.
@@ -444,270 +472,257 @@ pub trait IssuesExt {
     /// PUT /repos/:owner/:repo/issues/:number/lock
     /// ## Parameters
     /// `number`: Issue number.
-    fn put_repos_owner_repo_issues_number_lock(&mut self, owner: String, repo:String, number: u64) -> Result<(), error::Error>;
-
-    /// \[[Reference](https://developer.github.com/v3/issues/#lock-an-issue)\]
-    /// Unlocks an issue.
-    /// Pull access to the repository is required.
-    /// ## Endpoint:
-    /// DELETE /repos/:owner/:repo/issues/:number/lock
-    /// ## Parameters
-    /// `number`: Issue number.
-    fn delete_repos_owner_repo_issues_number_lock(&mut self, owner: String, repo:String, number: u64) -> Result<(), error::Error>;
+    fn put_repos_owner_repo_issues_number_lock(&self, owner: Owner, repo: RepoName, number: u64) -> Result<(), error::Error> {
+        self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .path("/lock")
+            .send_empty()
+            .map(|_| ())
+    }
 
+    fn delete_repos_owner_repo_issues_number_lock(&self, owner: Owner, repo: RepoName, number: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .path("/lock")
+            .send_empty()
+            .map(|_| ())
+    }
 }
 
 impl IssuesExt for Client {
 
-    fn get_issues(&mut self,
+    fn get_issues(&self,
                   filter: Option<Filter>,
-                  state: Option<State>,
+                  state: Option<StateFilter>,
                   labels: Vec<String>,
                   sort: Option<Sort>,
                   direction: Option<Direction>,
-                  since: String) -> Result<Vec<Issue>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/issues", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = filter {
-                query_pairs.append_pair("filter", &param.to_str());
-            }
-
-            if let Some(param) = state {
-                query_pairs.append_pair("state", &param.to_str());
-            }
-
-            if !labels.is_empty() {
-
-                let mut param = String::new();
-                for label in &labels {
-                    param = format!("{},{}", param, label);
-                }
-
-                query_pairs.append_pair("labels", &param[..]);
-            }
-
-            if let Some(param) = sort {
-                query_pairs.append_pair("sort", &param.to_str());
-            }
-
-            if let Some(param) = direction {
-                query_pairs.append_pair("direction", &param.to_str());
-            }
-
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/issues?{}", url.query().unwrap()))
+                  since: DateTime) -> Result<Vec<Issue>, error::Error>{
+
+        self.request(Method::Get, "/issues")
+            .query_opt("filter", filter)
+            .query_opt("state", state)
+            .query_list("labels", &labels)
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_user_issues(&mut self,
+    fn get_user_issues(&self,
                        filter: Option<Filter>,
-                       state: Option<State>,
+                       state: Option<StateFilter>,
                        labels: Vec<String>,
                        sort: Option<Sort>,
                        direction: Option<Direction>,
-                       since: String) -> Result<Vec<Issue>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/user/issues", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = filter {
-                query_pairs.append_pair("filter", &param.to_str());
-            }
-
-            if let Some(param) = state {
-                query_pairs.append_pair("state", &param.to_str());
-            }
-
-            if !labels.is_empty() {
-
-                let mut param = String::new();
-                for label in &labels {
-                    param = format!("{},{}", param, label);
-                }
-
-                query_pairs.append_pair("labels", &param[..]);
-            }
-
-            if let Some(param) = sort {
-                query_pairs.append_pair("sort", &param.to_str());
-            }
-
-            if let Some(param) = direction {
-                query_pairs.append_pair("direction", &param.to_str());
-            }
-
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/user/issues?{}", url.query().unwrap()))
+                       since: DateTime) -> Result<Vec<Issue>, error::Error>{
+        self.request(Method::Get, "/user/issues")
+            .query_opt("filter", filter)
+            .query_opt("state", state)
+            .query_list("labels", &labels)
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_orgs_org_issues(&mut self,
+    fn get_orgs_org_issues(&self,
                            org: String,
                            filter: Option<Filter>,
-                           state: Option<State>,
+                           state: Option<StateFilter>,
                            labels: Vec<String>,
                            sort: Option<Sort>,
                            direction: Option<Direction>,
-                           since: String) -> Result<Vec<Issue>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/orgs/{}/issues", self.api_url, org)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = filter {
-                query_pairs.append_pair("filter", &param.to_str());
-            }
-
-            if let Some(param) = state {
-                query_pairs.append_pair("state", &param.to_str());
-            }
-
-            if !labels.is_empty() {
-
-                let mut param = String::new();
-                for label in &labels {
-                    param = format!("{},{}", param, label);
-                }
-
-                query_pairs.append_pair("labels", &param[..]);
-            }
-
-            if let Some(param) = sort {
-                query_pairs.append_pair("sort", &param.to_str());
-            }
-
-            if let Some(param) = direction {
-                query_pairs.append_pair("direction", &param.to_str());
-            }
-
-            query_pairs.append_pair("since", &since[..]);
-        }
-
-        utils::request_endpoint(self, format!("/orgs/{}/issues?{}", org, url.query().unwrap()))
+                           since: DateTime) -> Result<Vec<Issue>, error::Error>{
+        self.request(Method::Get, "/orgs")
+            .segment(org)
+            .path("/issues")
+            .query_opt("filter", filter)
+            .query_opt("state", state)
+            .query_list("labels", &labels)
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_repos_owner_repo_issues(&mut self,
-                                   owner: String,
-                                   repo: String,
+    fn get_repos_owner_repo_issues(&self,
+                                   owner: Owner,
+                                   repo: RepoName,
                                    milestone: MilestoneParam,
-                                   state: Option<State>,
+                                   state: Option<StateFilter>,
                                    assignee: Assignee,
                                    creator: String,
                                    mentioned: Option<String>,
                                    labels: Vec<String>,
                                    sort: Option<Sort>,
                                    direction: Option<Direction>,
-                                   since: String) -> Result<Vec<Issue>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/repos/{}/{}/issues", self.api_url, owner, repo)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            query_pairs.append_pair("milestone", &milestone.to_str());
+                                   since: DateTime) -> Result<Vec<Issue>, error::Error>{
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .query("milestone", milestone)
+            .query_opt("state", state)
+            .query("assignee", assignee)
+            .query("creator", creator)
+            .query_opt("mentioned", mentioned)
+            .query_list("labels", &labels)
+            .query_opt("sort", sort)
+            .query_opt("direction", direction)
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
+    }
 
-            if let Some(param) = state {
-                query_pairs.append_pair("state", &param.to_str());
-            }
+    fn get_repos_owner_repo_issues_number(&self, owner: Owner, repo: RepoName, number: u64) -> Result<Issue, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .send()
+            .map(|response| response.data)
+    }
 
-            query_pairs.append_pair("assignee", &assignee.to_str());
-            query_pairs.append_pair("creator", &creator[..]);
+    fn post_repos_owner_repo_issues(&self, owner: Owner, repo: RepoName, issue: IssueCreate) -> Result<Issue, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .json(&issue)
+            .send()
+            .map(|response| response.data)
+    }
 
-            if let Some(param) = mentioned {
-                query_pairs.append_pair("mentioned", &param[..]);
-            }
+    fn patch_repos_owner_repo_issues_number(&self, owner: Owner, repo: RepoName, number: u64, issue: IssueEdit) -> Result<Issue, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .json(&issue)
+            .send()
+            .map(|response| response.data)
+    }
 
-            if !labels.is_empty() {
+    fn put_repos_owner_repo_issues_number_lock(&self, owner: Owner, repo: RepoName, number: u64) -> Result<(), error::Error> {
+        match self.put(format!("/repos/{}/{}/issues/{}/lock", owner.to_path(), repo.to_path(), number), None) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
 
-                let mut param = String::new();
-                for label in &labels {
-                    param = format!("{},{}", param, label);
-                }
+    fn delete_repos_owner_repo_issues_number_lock(&self, owner: Owner, repo: RepoName, number: u64) -> Result<(), error::Error> {
+        match self.delete(format!("/repos/{}/{}/issues/{}/lock", owner.to_path(), repo.to_path(), number), None) {
+            Ok(_)    => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+}
 
-                query_pairs.append_pair("labels", &param[..]);
-            }
+//TODO: Custom media types support
 
-            if let Some(param) = sort {
-                query_pairs.append_pair("sort", &param.to_str());
-            }
+#[cfg(test)]
+mod issues_test {
+
+    use serde_json;
+
+    use common::Comment;
+    use issues::{Issue, IssueEdit, Label, Milestone, State, StateFilter};
+    use issues::events::{EventType, IssueEvent, IssueEventRepo};
+
+    #[test]
+    fn issue() {
+        let issue: Issue = serde_json::from_str(include_str!("../../tests/fixtures/issues/issue.json")).unwrap();
+        assert!(issue.number == 1347);
+        assert!(issue.state == State::Open);
+        assert!(issue.body == Some("I'm having a problem with this.".to_string()));
+        assert!(issue.assignee.unwrap().login == "hubot");
+        assert!(issue.labels[0].description == Some("Something isn't working".to_string()));
+        assert!(issue.pull_request.unwrap().merged_at.is_none());
+        assert!(issue.closed_by.is_none());
+        assert!(issue.reactions.unwrap().plus_one == 5);
+
+        let milestone = issue.milestone.unwrap();
+        assert!(milestone.number == 1);
+        assert!(milestone.due_on.is_none());
+    }
 
-            if let Some(param) = direction {
-                query_pairs.append_pair("direction", &param.to_str());
-            }
+    #[test]
+    fn issues() {
+        let issues: Vec<Issue> = serde_json::from_str(include_str!("../../tests/fixtures/issues/issues.json")).unwrap();
+        assert!(issues.len() == 3);
 
-            query_pairs.append_pair("since", &since[..]);
-        }
+        assert!(issues[1].state == State::Closed);
+        assert!(issues[1].closed_at.is_some());
+        assert!(issues[1].closed_by.is_some());
+        assert!(issues[1].labels[0].description.is_none());
 
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues?{}", owner, repo, url.query().unwrap()))
+        assert!(issues[2].body.is_none());
+        assert!(issues[2].assignee.is_none());
+        assert!(issues[2].milestone.is_none());
+        assert!(issues[2].pull_request.is_none());
     }
 
-    fn get_repos_owner_repo_issues_number(&mut self, owner: String, repo: String, number: u64) -> Result<Issue, error::Error> {
-        utils::request_endpoint(self, format!("/repos/{}/{}/issues/{}", owner, repo, number))
+    #[test]
+    fn milestone() {
+        let milestone: Milestone = serde_json::from_str(include_str!("../../tests/fixtures/issues/milestone.json")).unwrap();
+        assert!(milestone.number == 1);
+        assert!(milestone.description.is_none());
+        assert!(milestone.creator.unwrap().login == "octocat");
+        assert!(milestone.closed_at.is_none());
     }
 
-    fn post_repos_owner_repo_issues(&mut self, owner: String, repo: String, issue: IssueCreate) -> Result<Issue, error::Error> {
-
-        //Create body
-        let body_data = try!(serde_json::to_string(&issue).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/issues", owner, repo), None, body_data));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    #[test]
+    fn labels() {
+        let labels: Vec<Label> = serde_json::from_str(include_str!("../../tests/fixtures/issues/labels.json")).unwrap();
+        assert!(labels.len() == 2);
+        assert!(labels[1].name == "help wanted");
+        assert!(labels[1].default);
     }
 
-    fn patch_repos_owner_repo_issues_number(&mut self, owner: String, repo: String, number: u64, issue: IssueEdit) -> Result<Issue, error::Error> {
+    #[test]
+    fn comments() {
+        let comments: Vec<Comment> = serde_json::from_str(include_str!("../../tests/fixtures/issues/comments.json")).unwrap();
+        assert!(comments[0].body == "Me too");
+    }
 
-        //Create body
-        let body_data = try!(serde_json::to_string(&issue).map_err(error::Error::Parsing));
+    #[test]
+    fn issue_edit() {
+        let edit = IssueEdit::new().title("Found a bug");
+        assert!(serde_json::to_string(&edit).unwrap() == r#"{"title":"Found a bug"}"#);
 
-        let mut response     = try!(self.post_body(format!("/repos/{}/{}/issues/{}", owner, repo, number), None, body_data));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+        let edit = IssueEdit::new().state(State::Closed).clear_milestone();
+        assert!(serde_json::to_string(&edit).unwrap() == r#"{"state":"closed","milestone":null}"#);
     }
 
-    fn put_repos_owner_repo_issues_number_lock(&mut self, owner: String, repo: String, number: u64) -> Result<(), error::Error> {
-        match self.put(format!("/repos/{}/{}/issues/{}/lock", owner, repo, number), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    #[test]
+    fn state() {
+        //"all" is only a filter, not the state of an issue
+        assert!(State::from("all") == State::Other("all".to_string()));
+        assert!(StateFilter::All.as_str() == "all");
     }
 
-    fn delete_repos_owner_repo_issues_number_lock(&mut self, owner: String, repo: String, number: u64) -> Result<(), error::Error> {
-        match self.delete(format!("/repos/{}/{}/issues/{}/lock", owner, repo, number), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    #[test]
+    fn events() {
+        let events: Vec<IssueEvent> = serde_json::from_str(include_str!("../../tests/fixtures/issues/events.json")).unwrap();
+        assert!(events[0].event == EventType::Closed);
+        assert!(events[0].commit_id.is_some());
+        assert!(events[1].actor.is_none());
+        assert!(events[1].commit_id.is_none());
+        assert!(events[2].event == EventType::Other("connected".to_string()));
+
+        let event: IssueEventRepo = serde_json::from_str(include_str!("../../tests/fixtures/issues/event.json")).unwrap();
+        assert!(event.issue.number == 1348);
     }
 }
-
-//TODO: Custom media types support
-//TODO: TESTS
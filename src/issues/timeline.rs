@@ -0,0 +1,326 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Timeline
+//!
+//! These are the responses and API call functions related
+//! to the timeline endpoint of the API, available with the
+//! `preview-timeline` feature.
+//!
+//! Reference: https://developer.github.com/v3/issues/timeline/
+
+use hyper::method::Method;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de;
+use serde_json::{self, Value};
+
+use client::Client;
+use common::{Comment, DateTime, User};
+use error;
+use git_data::commits::Commit;
+use issues::Issue;
+use path::{Owner, RepoName};
+use preview::Preview;
+
+/// Element of the timeline of an issue, the variant is
+/// given by the `event` field of the response.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimelineEvent {
+    /// A comment was added to the issue.
+    Commented(Comment),
+    /// A commit was added to the pull request.
+    Committed(Commit),
+    /// A label was added to the issue.
+    Labeled(LabelEvent),
+    /// A label was removed from the issue.
+    Unlabeled(LabelEvent),
+    /// A user was assigned to the issue.
+    Assigned(AssigneeEvent),
+    /// A user was unassigned from the issue.
+    Unassigned(AssigneeEvent),
+    /// The issue was added to a milestone.
+    Milestoned(MilestoneEvent),
+    /// The issue was removed from a milestone.
+    Demilestoned(MilestoneEvent),
+    /// The title of the issue was changed.
+    Renamed(RenameEvent),
+    /// A review was requested on the pull request.
+    ReviewRequested(ReviewRequestEvent),
+    /// A review request was removed from the pull request.
+    ReviewRequestRemoved(ReviewRequestEvent),
+    /// The issue was referenced from another issue.
+    CrossReferenced(CrossReferenceEvent),
+    /// The issue was referenced from a commit.
+    Referenced(CommitEvent),
+    /// The issue was closed, possibly by a commit.
+    Closed(CommitEvent),
+    /// The issue was reopened.
+    Reopened(CommitEvent),
+    /// The pull request was merged.
+    Merged(CommitEvent),
+    /// Any other event, along with its name and the
+    /// response.
+    Other(String, Value)
+}
+
+/// Timeline event referencing a commit, if any.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CommitEvent {
+    pub id:         u64,
+    pub url:        String,
+    pub actor:      Option<User>,
+    pub commit_id:  Option<String>,
+    pub commit_url: Option<String>,
+    pub created_at: DateTime
+}
+
+/// Timeline event of a label change.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LabelEvent {
+    pub id:         u64,
+    pub url:        String,
+    pub actor:      Option<User>,
+    pub created_at: DateTime,
+    pub label:      EventLabel
+}
+
+/// Sub-component of LabelEvent.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EventLabel {
+    pub name:  String,
+    pub color: String
+}
+
+/// Timeline event of an assignee change.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AssigneeEvent {
+    pub id:         u64,
+    pub url:        String,
+    pub actor:      Option<User>,
+    pub created_at: DateTime,
+    pub assignee:   User
+}
+
+/// Timeline event of a milestone change.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MilestoneEvent {
+    pub id:         u64,
+    pub url:        String,
+    pub actor:      Option<User>,
+    pub created_at: DateTime,
+    pub milestone:  EventMilestone
+}
+
+/// Sub-component of MilestoneEvent.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EventMilestone {
+    pub title: String
+}
+
+/// Timeline event of a title change.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RenameEvent {
+    pub id:         u64,
+    pub url:        String,
+    pub actor:      Option<User>,
+    pub created_at: DateTime,
+    pub rename:     Rename
+}
+
+/// Sub-component of RenameEvent.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Rename {
+    pub from: String,
+    pub to:   String
+}
+
+/// Timeline event of a review request change.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ReviewRequestEvent {
+    pub id:                 u64,
+    pub url:                String,
+    pub actor:              Option<User>,
+    pub created_at:         DateTime,
+    pub review_requester:   Option<User>,
+    pub requested_reviewer: Option<User>
+}
+
+/// Timeline event of a reference from another issue.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CrossReferenceEvent {
+    pub actor:      Option<User>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub source:     EventSource
+}
+
+/// Sub-component of CrossReferenceEvent.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EventSource {
+    #[serde(rename = "type")]
+    pub source_type: String,
+    pub issue:       Option<Issue>
+}
+
+impl TimelineEvent {
+
+    /// Returns the name of the event, ex. "labeled".
+    pub fn event(&self) -> &str {
+        match *self {
+            TimelineEvent::Commented(_)            => "commented",
+            TimelineEvent::Committed(_)            => "committed",
+            TimelineEvent::Labeled(_)              => "labeled",
+            TimelineEvent::Unlabeled(_)            => "unlabeled",
+            TimelineEvent::Assigned(_)             => "assigned",
+            TimelineEvent::Unassigned(_)           => "unassigned",
+            TimelineEvent::Milestoned(_)           => "milestoned",
+            TimelineEvent::Demilestoned(_)         => "demilestoned",
+            TimelineEvent::Renamed(_)              => "renamed",
+            TimelineEvent::ReviewRequested(_)      => "review_requested",
+            TimelineEvent::ReviewRequestRemoved(_) => "review_request_removed",
+            TimelineEvent::CrossReferenced(_)      => "cross-referenced",
+            TimelineEvent::Referenced(_)           => "referenced",
+            TimelineEvent::Closed(_)               => "closed",
+            TimelineEvent::Reopened(_)             => "reopened",
+            TimelineEvent::Merged(_)               => "merged",
+            TimelineEvent::Other(ref event, _)     => &event[..]
+        }
+    }
+}
+
+impl Deserialize for TimelineEvent {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<TimelineEvent, D::Error> {
+
+        let value = try!(Value::deserialize(deserializer));
+        let event = match value.find("event").and_then(Value::as_str) {
+            Some(event) => event.to_string(),
+            None        => return Err(de::Error::missing_field("event"))
+        };
+
+        let timeline_event = match &event[..] {
+            "commented"              => serde_json::from_value(value).map(TimelineEvent::Commented),
+            "committed"              => serde_json::from_value(value).map(TimelineEvent::Committed),
+            "labeled"                => serde_json::from_value(value).map(TimelineEvent::Labeled),
+            "unlabeled"              => serde_json::from_value(value).map(TimelineEvent::Unlabeled),
+            "assigned"               => serde_json::from_value(value).map(TimelineEvent::Assigned),
+            "unassigned"             => serde_json::from_value(value).map(TimelineEvent::Unassigned),
+            "milestoned"             => serde_json::from_value(value).map(TimelineEvent::Milestoned),
+            "demilestoned"           => serde_json::from_value(value).map(TimelineEvent::Demilestoned),
+            "renamed"                => serde_json::from_value(value).map(TimelineEvent::Renamed),
+            "review_requested"       => serde_json::from_value(value).map(TimelineEvent::ReviewRequested),
+            "review_request_removed" => serde_json::from_value(value).map(TimelineEvent::ReviewRequestRemoved),
+            "cross-referenced"       => serde_json::from_value(value).map(TimelineEvent::CrossReferenced),
+            "referenced"             => serde_json::from_value(value).map(TimelineEvent::Referenced),
+            "closed"                 => serde_json::from_value(value).map(TimelineEvent::Closed),
+            "reopened"               => serde_json::from_value(value).map(TimelineEvent::Reopened),
+            "merged"                 => serde_json::from_value(value).map(TimelineEvent::Merged),
+            _                        => Ok(TimelineEvent::Other(event.clone(), value))
+        };
+
+        timeline_event.map_err(|err| de::Error::custom(err.to_string()))
+    }
+}
+
+impl Serialize for TimelineEvent {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+
+        let mut value = match *self {
+            TimelineEvent::Commented(ref event)            => serde_json::to_value(event),
+            TimelineEvent::Committed(ref event)            => serde_json::to_value(event),
+            TimelineEvent::Labeled(ref event)              => serde_json::to_value(event),
+            TimelineEvent::Unlabeled(ref event)            => serde_json::to_value(event),
+            TimelineEvent::Assigned(ref event)             => serde_json::to_value(event),
+            TimelineEvent::Unassigned(ref event)           => serde_json::to_value(event),
+            TimelineEvent::Milestoned(ref event)           => serde_json::to_value(event),
+            TimelineEvent::Demilestoned(ref event)         => serde_json::to_value(event),
+            TimelineEvent::Renamed(ref event)              => serde_json::to_value(event),
+            TimelineEvent::ReviewRequested(ref event)      => serde_json::to_value(event),
+            TimelineEvent::ReviewRequestRemoved(ref event) => serde_json::to_value(event),
+            TimelineEvent::CrossReferenced(ref event)      => serde_json::to_value(event),
+            TimelineEvent::Referenced(ref event)           => serde_json::to_value(event),
+            TimelineEvent::Closed(ref event)               => serde_json::to_value(event),
+            TimelineEvent::Reopened(ref event)             => serde_json::to_value(event),
+            TimelineEvent::Merged(ref event)               => serde_json::to_value(event),
+            TimelineEvent::Other(_, ref value)             => value.clone()
+        };
+
+        if let Value::Object(ref mut map) = value {
+            map.insert("event".to_string(), Value::String(self.event().to_string()));
+        }
+
+        value.serialize(serializer)
+    }
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait TimelineExt {
+
+    /// \[[Reference](https://developer.github.com/v3/issues/timeline/#list-events-for-an-issue)\]
+    /// Returns the timeline of an issue, all the pages of
+    /// the timeline are requested.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/issues/:issue_number/timeline
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `issue_number`: Number of the issue.
+    fn get_repos_owner_repo_issues_issue_number_timeline(&self, owner: Owner, repo: RepoName, issue_number: u64) -> Result<Vec<TimelineEvent>, error::Error>;
+}
+
+impl TimelineExt for Client {
+
+    fn get_repos_owner_repo_issues_issue_number_timeline(&self, owner: Owner, repo: RepoName, issue_number: u64) -> Result<Vec<TimelineEvent>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(issue_number)
+            .path("/timeline")
+            .query("per_page", 100u32)
+            .preview(Preview::Timeline)
+            .send_all()
+    }
+}
+
+#[cfg(test)]
+mod timeline_test {
+
+    use serde_json;
+
+    use super::TimelineEvent;
+
+    #[test]
+    fn timeline() {
+        let timeline: Vec<TimelineEvent> = serde_json::from_str(include_str!("../../tests/fixtures/issues/timeline.json")).unwrap();
+        assert!(timeline.len() == 5);
+
+        match timeline[0] {
+            TimelineEvent::Commented(ref comment) => assert!(comment.body == "Me too"),
+            _                                     => panic!("expected a comment")
+        }
+        match timeline[1] {
+            TimelineEvent::Labeled(ref event) => assert!(event.label.name == "bug"),
+            _                                 => panic!("expected a label event")
+        }
+        match timeline[2] {
+            TimelineEvent::CrossReferenced(ref event) => assert!(event.source.issue.as_ref().unwrap().number == 1348),
+            _                                         => panic!("expected a cross reference")
+        }
+        match timeline[3] {
+            TimelineEvent::Renamed(ref event) => assert!(event.rename.to == "Found a bug"),
+            _                                 => panic!("expected a rename")
+        }
+        assert!(timeline[4].event() == "subscribed");
+
+        let event = serde_json::to_value(&timeline[1]);
+        assert!(event.find("event").and_then(|event| event.as_str()) == Some("labeled"));
+    }
+}
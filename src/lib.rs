@@ -19,8 +19,9 @@
 //! The crate is used by creating a
 //! [Client](client/index.html) and then calling the
 //! function associated to the desired endpoint with
-//! appropriate arguments. Note: Each function borrows a
-//! mutable [Client](client/index.html)
+//! appropriate arguments. Note: Each function borrows the
+//! [Client](client/index.html) immutably, a client can be
+//! cloned cheaply and shared across threads.
 //!
 //! ### Example
 //!
@@ -28,16 +29,16 @@
 //!
 //! extern crate rustyhub;
 //!
-//! use rustyhub::activity::events;
+//! use rustyhub::activity::events::EventsExt;
 //! use rustyhub::auth::auth::Auth;
 //! use rustyhub::client::Client;
 //!
 //! fn main() {
 //!
 //!     let github_token = "0000000000000000000000000000000000000000".to_string();
-//!     let mut client = Client::new("rusyhub-UserAgent", Auth::OAuth2Token(github_token)));
+//!     let client = Client::new("rusyhub-UserAgent", Auth::OAuth2Token(github_token));
 //!
-//!     let events = client.get_events(&mut client).unwrap();
+//!     let events = client.get_events().unwrap();
 //!
 //! }
 //!
@@ -47,6 +48,12 @@
 #![feature(proc_macro)]
 //#![warn(missing_docs)]
 
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "thread-pool")]
+extern crate futures;
+#[cfg(feature = "thread-pool")]
+extern crate futures_cpupool;
 extern crate hyper;
 #[macro_use]
 extern crate log;
@@ -54,28 +61,44 @@ extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
+extern crate url;
+
+#[macro_use]
+mod macros;
 
 //TODO: Payloads
 pub mod activity;
 pub mod auth;
-//TODO: Licenses once out of preview
+#[cfg(feature = "preview-checks")]
+pub mod checks;
+pub mod enterprise;
 pub mod gists;
 pub mod git_data;
+pub mod graphql;
 //TODO: integrations once out of preview
 pub mod issues;
-//TODO: migration once out of preview
+#[cfg(feature = "preview-licenses")]
+pub mod licenses;
+#[cfg(feature = "preview-migrations")]
+pub mod migrations;
 pub mod miscellaneous;
 pub mod organizations;
-//TODO: projects once out of preview
+#[cfg(feature = "preview-projects")]
+pub mod projects;
 //TODO: pull_requests
-//TODO: reactions once out of preview
-//TODO: repositories
+#[cfg(feature = "preview-reactions")]
+pub mod reactions;
+pub mod repositories;
 //TODO: search
 //TODO: users
-//TODO: enterprise
 //TODO: webhooks, include HMAC validation?
 
 pub mod client;
 pub mod common;
 pub mod error;
+pub mod path;
+pub mod preview;
+pub mod request;
+#[cfg(feature = "thread-pool")]
+pub mod thread_pool;
 pub mod utils;
@@ -0,0 +1,195 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Licenses
+//!
+//! These are the responses and API call functions related
+//! to the licenses endpoints of the API, available with the
+//! `preview-licenses` feature.
+//!
+//! Reference: https://developer.github.com/v3/licenses/
+
+use std::collections::BTreeMap;
+
+use hyper::method::Method;
+
+use client::Client;
+use common::Repository;
+pub use common::LicenseSummary;
+use error;
+use path::{Owner, RepoName};
+use preview::Preview;
+
+/// Return type of the get license endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct License {
+    pub key:            String,
+    pub name:           String,
+    pub spdx_id:        Option<String>,
+    pub url:            Option<String>,
+    pub html_url:       Option<String>,
+    pub featured:       bool,
+    pub description:    String,
+    pub implementation: String,
+    pub permissions:    Vec<String>,
+    pub conditions:     Vec<String>,
+    pub limitations:    Vec<String>,
+    /// Full text of the license.
+    pub body:           String
+}
+
+/// Return type of the get repository license endpoint, the
+/// license file along with the detected license.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RepoLicense {
+    pub name:         String,
+    pub path:         String,
+    pub sha:          String,
+    pub size:         u64,
+    pub url:          String,
+    pub html_url:     Option<String>,
+    pub git_url:      Option<String>,
+    pub download_url: Option<String>,
+    #[serde(rename = "type")]
+    pub file_type:    String,
+    /// Content of the license file, encoded as given by
+    /// `encoding`.
+    pub content:      String,
+    pub encoding:     String,
+    pub license:      Option<LicenseSummary>
+}
+
+/// License usage across the repositories of an
+/// organization, see `LicensesExt::get_org_license_usage`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct LicenseUsage {
+    /// Number of repositories by license key.
+    pub licenses:   BTreeMap<String, u64>,
+    /// Names of the repositories without a detected
+    /// license.
+    pub unlicensed: Vec<String>
+}
+
+impl LicenseUsage {
+
+    /// Summarizes the licenses of repositories.
+    pub fn from_repos(repos: &[Repository]) -> LicenseUsage {
+        let mut usage = LicenseUsage::default();
+        for repo in repos {
+            match repo.license {
+                Some(ref license) => *usage.licenses.entry(license.key.clone()).or_insert(0) += 1,
+                None              => usage.unlicensed.push(repo.name.clone())
+            }
+        }
+        usage
+    }
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait LicensesExt {
+
+    /// \[[Reference](https://developer.github.com/v3/licenses/#list-all-licenses)\]
+    /// Returns the licenses known to Github.
+    /// ## Endpoint:
+    /// GET /licenses
+    fn get_licenses(&self) -> Result<Vec<LicenseSummary>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/licenses/#get-an-individual-license)\]
+    /// Returns a license with its full text.
+    /// ## Endpoint:
+    /// GET /licenses/:license
+    /// ## Parameters
+    /// * `license`: Key of the license, ex. "mit".
+    fn get_licenses_license(&self, license: String) -> Result<License, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/licenses/#get-the-contents-of-a-repositorys-license)\]
+    /// Returns the license file of a repository and the
+    /// license detected in it.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/license
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    fn get_repos_owner_repo_license(&self, owner: Owner, repo: RepoName) -> Result<RepoLicense, error::Error>;
+
+    /// Returns the number of repositories of an organization
+    /// by license, all the pages of the repositories of the
+    /// organization are requested.
+    /// ## Parameters
+    /// * `org`: Name of the organization.
+    fn get_org_license_usage(&self, org: String) -> Result<LicenseUsage, error::Error>;
+}
+
+impl LicensesExt for Client {
+
+    fn get_licenses(&self) -> Result<Vec<LicenseSummary>, error::Error> {
+        self.request(Method::Get, "/licenses")
+            .preview(Preview::Licenses)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_licenses_license(&self, license: String) -> Result<License, error::Error> {
+        self.request(Method::Get, "/licenses")
+            .segment(license)
+            .preview(Preview::Licenses)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_license(&self, owner: Owner, repo: RepoName) -> Result<RepoLicense, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/license")
+            .preview(Preview::Licenses)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_org_license_usage(&self, org: String) -> Result<LicenseUsage, error::Error> {
+        let repos: Vec<Repository> = try!(self.request(Method::Get, "/orgs")
+            .segment(org)
+            .path("/repos")
+            .query("per_page", 100u32)
+            .preview(Preview::Licenses)
+            .send_all());
+        Ok(LicenseUsage::from_repos(&repos[..]))
+    }
+}
+
+#[cfg(test)]
+mod licenses_test {
+
+    use serde_json;
+
+    use common::Repository;
+    use super::LicenseUsage;
+
+    #[test]
+    fn license_usage() {
+        let repos: Vec<Repository> = serde_json::from_str(r#"[
+            {"id": 1, "name": "api",     "url": "https://api.github.com/repos/octo-org/api",     "license": {"key": "mit", "name": "MIT License"}},
+            {"id": 2, "name": "docs",    "url": "https://api.github.com/repos/octo-org/docs",    "license": null},
+            {"id": 3, "name": "web",     "url": "https://api.github.com/repos/octo-org/web",     "license": {"key": "mit", "name": "MIT License"}},
+            {"id": 4, "name": "scripts", "url": "https://api.github.com/repos/octo-org/scripts"},
+            {"id": 5, "name": "tools",   "url": "https://api.github.com/repos/octo-org/tools",   "license": {"key": "apache-2.0", "name": "Apache License 2.0"}}
+        ]"#).unwrap();
+
+        let usage = LicenseUsage::from_repos(&repos);
+        assert!(usage.licenses.len() == 2);
+        assert!(usage.licenses.get("mit") == Some(&2));
+        assert!(usage.licenses.get("apache-2.0") == Some(&1));
+        assert!(usage.unlicensed == vec![String::from("docs"), String::from("scripts")]);
+
+        assert!(LicenseUsage::from_repos(&[]) == LicenseUsage::default());
+    }
+}
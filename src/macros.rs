@@ -0,0 +1,105 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Macros
+//!
+//! Macros used to declare the types of the crate.
+
+/// Declares an enum for a closed set of strings of the API.
+/// An `Other` variant holds the values unknown to this
+/// version of the crate so new values added to the API do
+/// not break deserialization. The enum is serialized as
+/// and deserialized from its string and can be used as a
+/// query parameter.
+///
+/// ```ignore
+/// string_enum! {
+///     /// State of an issue.
+///     pub enum State {
+///         Open   => "open",
+///         Closed => "closed"
+///     }
+/// }
+/// ```
+macro_rules! string_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident => $value:tt),+
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        pub enum $name {
+            $($(#[$variant_attr])* $variant,)+
+            /// Value unknown to this version of the crate.
+            Other(String)
+        }
+
+        impl $name {
+            /// Returns the value as used by the API.
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $($name::$variant => $value,)+
+                    $name::Other(ref value) => &value[..]
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(value: &'a str) -> $name {
+                match value {
+                    $($value => $name::$variant,)+
+                    _ => $name::Other(value.to_string())
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl $crate::request::QueryValue for $name {
+            fn to_query(&self) -> String {
+                self.as_str().to_string()
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl ::serde::Deserialize for $name {
+            fn deserialize<D: ::serde::Deserializer>(deserializer: &mut D) -> Result<$name, D::Error> {
+                let value: String = try!(::serde::Deserialize::deserialize(deserializer));
+                Ok($name::from(&value[..]))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod macros_test {
+
+    use serde_json;
+
+    use issues::State;
+
+    #[test]
+    fn string_enum() {
+        let state: State = serde_json::from_str("\"closed\"").unwrap();
+        assert!(state == State::Closed);
+
+        let state: State = serde_json::from_str("\"archived\"").unwrap();
+        assert!(state == State::Other("archived".to_string()));
+        assert!(serde_json::to_string(&state).unwrap() == "\"archived\"");
+    }
+}
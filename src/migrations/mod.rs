@@ -0,0 +1,412 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Migrations
+//!
+//! These are the responses and API call functions related
+//! to the organization and user migrations endpoints of the
+//! API, available with the `preview-migrations` feature.
+//!
+//! Reference: https://developer.github.com/v3/migration/
+
+use std::io::Write;
+use std::time::Duration;
+
+use hyper::method::Method;
+
+use client::Client;
+use common::{DateTime, Repository, User};
+use error;
+use path::RepoName;
+use preview::Preview;
+use utils;
+
+/// Endpoints for source imports.
+#[cfg(feature = "preview-source-imports")]
+pub mod source_imports;
+
+string_enum! {
+    /// State of a migration.
+    pub enum MigrationState {
+        Pending   => "pending",
+        Exporting => "exporting",
+        Exported  => "exported",
+        Failed    => "failed"
+    }
+}
+
+/// Return type of the migrations endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Migration {
+    pub id:                  u64,
+    pub owner:               User,
+    pub guid:                String,
+    pub state:               MigrationState,
+    pub lock_repositories:   bool,
+    pub exclude_attachments: bool,
+    pub repositories:        Vec<Repository>,
+    pub url:                 String,
+    pub created_at:          DateTime,
+    pub updated_at:          DateTime
+}
+
+impl Migration {
+
+    /// Returns whether the migration is over, its archive
+    /// was exported or it failed.
+    pub fn is_finished(&self) -> bool {
+        match self.state {
+            MigrationState::Exported |
+            MigrationState::Failed   => true,
+            _                        => false
+        }
+    }
+}
+
+/// Parameters for starting a migration.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MigrationParam {
+    /// Repositories to migrate, ex. "octocat/Hello-World".
+    pub repositories:        Vec<String>,
+    /// Locks the repositories during the migration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_repositories:   Option<bool>,
+    /// Excludes the attachments of issues and pull requests
+    /// from the archive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_attachments: Option<bool>
+}
+
+impl MigrationParam {
+
+    /// Creates the parameters of a migration of
+    /// repositories, with the default options.
+    pub fn new(repositories: Vec<String>) -> MigrationParam {
+        MigrationParam {
+            repositories:        repositories,
+            lock_repositories:   None,
+            exclude_attachments: None
+        }
+    }
+}
+
+//Polls a migration until it is over or the timeout is reached
+fn wait_migration<F>(timeout: Duration, mut get_migration: F) -> Result<Migration, error::Error>
+    where F: FnMut() -> Result<Migration, error::Error> {
+    utils::poll_with_backoff(timeout, || {
+        let migration = try!(get_migration());
+        Ok(if migration.is_finished() { Some(migration) } else { None })
+    })
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait MigrationsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/migration/migrations/#start-a-migration)\]
+    /// Starts the migration of repositories of an
+    /// organization.
+    /// ## Endpoint:
+    /// POST /orgs/:org/migrations
+    /// ## Parameters
+    /// * `org`: Name of the organization.
+    /// * `migration`: Parameters of the migration.
+    fn post_orgs_org_migrations(&self, org: String, migration: MigrationParam) -> Result<Migration, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/migrations/#get-a-list-of-migrations)\]
+    /// Returns the most recent migrations of an
+    /// organization.
+    /// ## Endpoint:
+    /// GET /orgs/:org/migrations
+    /// ## Parameters
+    /// * `org`: Name of the organization.
+    fn get_orgs_org_migrations(&self, org: String) -> Result<Vec<Migration>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/migrations/#get-the-status-of-a-migration)\]
+    /// Returns a migration of an organization along with its
+    /// state.
+    /// ## Endpoint:
+    /// GET /orgs/:org/migrations/:id
+    /// ## Parameters
+    /// * `org`: Name of the organization.
+    /// * `id`: ID of the migration.
+    fn get_orgs_org_migrations_id(&self, org: String, id: u64) -> Result<Migration, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/migrations/#download-a-migration-archive)\]
+    /// Downloads the archive of a migration of an
+    /// organization to `writer`, returns the size of the
+    /// archive.
+    /// ## Endpoint:
+    /// GET /orgs/:org/migrations/:id/archive
+    /// ## Parameters
+    /// * `org`: Name of the organization.
+    /// * `id`: ID of the migration.
+    /// * `writer`: Destination of the archive, ex. a file.
+    fn get_orgs_org_migrations_id_archive<W: Write>(&self, org: String, id: u64, writer: &mut W) -> Result<u64, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/migrations/#delete-a-migration-archive)\]
+    /// Deletes the archive of a migration of an
+    /// organization.
+    /// ## Endpoint:
+    /// DELETE /orgs/:org/migrations/:id/archive
+    /// ## Parameters
+    /// * `org`: Name of the organization.
+    /// * `id`: ID of the migration.
+    fn delete_orgs_org_migrations_id_archive(&self, org: String, id: u64) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/migrations/#unlock-a-repository)\]
+    /// Unlocks a repository locked by a migration of an
+    /// organization.
+    /// ## Endpoint:
+    /// DELETE /orgs/:org/migrations/:id/repos/:repo_name/lock
+    /// ## Parameters
+    /// * `org`: Name of the organization.
+    /// * `id`: ID of the migration.
+    /// * `repo_name`: Name of the repository.
+    fn delete_orgs_org_migrations_id_repos_repo_name_lock(&self, org: String, id: u64, repo_name: RepoName) -> Result<(), error::Error>;
+
+    /// Waits until a migration of an organization is
+    /// exported or failed, its state is polled with an
+    /// increasing delay. Blocks the current thread.
+    /// ## Parameters
+    /// * `org`: Name of the organization.
+    /// * `id`: ID of the migration.
+    /// * `timeout`: Maximum time to wait, a `TimedOut`
+    /// error is returned past it.
+    fn wait_orgs_org_migrations_id(&self, org: String, id: u64, timeout: Duration) -> Result<Migration, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/users/#start-a-user-migration)\]
+    /// Starts the migration of repositories of the
+    /// authenticated user.
+    /// ## Endpoint:
+    /// POST /user/migrations
+    /// ## Parameters
+    /// * `migration`: Parameters of the migration.
+    fn post_user_migrations(&self, migration: MigrationParam) -> Result<Migration, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/users/#get-a-list-of-user-migrations)\]
+    /// Returns the migrations of the authenticated user.
+    /// ## Endpoint:
+    /// GET /user/migrations
+    fn get_user_migrations(&self) -> Result<Vec<Migration>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/users/#get-the-status-of-a-user-migration)\]
+    /// Returns a migration of the authenticated user along
+    /// with its state.
+    /// ## Endpoint:
+    /// GET /user/migrations/:id
+    /// ## Parameters
+    /// * `id`: ID of the migration.
+    fn get_user_migrations_id(&self, id: u64) -> Result<Migration, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/users/#download-a-user-migration-archive)\]
+    /// Downloads the archive of a migration of the
+    /// authenticated user to `writer`, returns the size of
+    /// the archive.
+    /// ## Endpoint:
+    /// GET /user/migrations/:id/archive
+    /// ## Parameters
+    /// * `id`: ID of the migration.
+    /// * `writer`: Destination of the archive, ex. a file.
+    fn get_user_migrations_id_archive<W: Write>(&self, id: u64, writer: &mut W) -> Result<u64, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/users/#delete-a-user-migration-archive)\]
+    /// Deletes the archive of a migration of the
+    /// authenticated user.
+    /// ## Endpoint:
+    /// DELETE /user/migrations/:id/archive
+    /// ## Parameters
+    /// * `id`: ID of the migration.
+    fn delete_user_migrations_id_archive(&self, id: u64) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/users/#unlock-a-user-repository)\]
+    /// Unlocks a repository locked by a migration of the
+    /// authenticated user.
+    /// ## Endpoint:
+    /// DELETE /user/migrations/:id/repos/:repo_name/lock
+    /// ## Parameters
+    /// * `id`: ID of the migration.
+    /// * `repo_name`: Name of the repository.
+    fn delete_user_migrations_id_repos_repo_name_lock(&self, id: u64, repo_name: RepoName) -> Result<(), error::Error>;
+
+    /// Waits until a migration of the authenticated user is
+    /// exported or failed, its state is polled with an
+    /// increasing delay. Blocks the current thread.
+    /// ## Parameters
+    /// * `id`: ID of the migration.
+    /// * `timeout`: Maximum time to wait, a `TimedOut`
+    /// error is returned past it.
+    fn wait_user_migrations_id(&self, id: u64, timeout: Duration) -> Result<Migration, error::Error>;
+}
+
+impl MigrationsExt for Client {
+
+    fn post_orgs_org_migrations(&self, org: String, migration: MigrationParam) -> Result<Migration, error::Error> {
+        self.request(Method::Post, "/orgs")
+            .segment(org)
+            .path("/migrations")
+            .json(&migration)
+            .preview(Preview::Migrations)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_orgs_org_migrations(&self, org: String) -> Result<Vec<Migration>, error::Error> {
+        self.request(Method::Get, "/orgs")
+            .segment(org)
+            .path("/migrations")
+            .preview(Preview::Migrations)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_orgs_org_migrations_id(&self, org: String, id: u64) -> Result<Migration, error::Error> {
+        self.request(Method::Get, "/orgs")
+            .segment(org)
+            .path("/migrations")
+            .segment(id)
+            .preview(Preview::Migrations)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_orgs_org_migrations_id_archive<W: Write>(&self, org: String, id: u64, writer: &mut W) -> Result<u64, error::Error> {
+        self.request(Method::Get, "/orgs")
+            .segment(org)
+            .path("/migrations")
+            .segment(id)
+            .path("/archive")
+            .preview(Preview::Migrations)
+            .download(writer)
+    }
+
+    fn delete_orgs_org_migrations_id_archive(&self, org: String, id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/orgs")
+            .segment(org)
+            .path("/migrations")
+            .segment(id)
+            .path("/archive")
+            .preview(Preview::Migrations)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn delete_orgs_org_migrations_id_repos_repo_name_lock(&self, org: String, id: u64, repo_name: RepoName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/orgs")
+            .segment(org)
+            .path("/migrations")
+            .segment(id)
+            .path("/repos")
+            .segment(repo_name)
+            .path("/lock")
+            .preview(Preview::Migrations)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn wait_orgs_org_migrations_id(&self, org: String, id: u64, timeout: Duration) -> Result<Migration, error::Error> {
+        wait_migration(timeout, || self.get_orgs_org_migrations_id(org.clone(), id))
+    }
+
+    fn post_user_migrations(&self, migration: MigrationParam) -> Result<Migration, error::Error> {
+        self.request(Method::Post, "/user/migrations")
+            .json(&migration)
+            .preview(Preview::Migrations)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_user_migrations(&self) -> Result<Vec<Migration>, error::Error> {
+        self.request(Method::Get, "/user/migrations")
+            .preview(Preview::Migrations)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_user_migrations_id(&self, id: u64) -> Result<Migration, error::Error> {
+        self.request(Method::Get, "/user/migrations")
+            .segment(id)
+            .preview(Preview::Migrations)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_user_migrations_id_archive<W: Write>(&self, id: u64, writer: &mut W) -> Result<u64, error::Error> {
+        self.request(Method::Get, "/user/migrations")
+            .segment(id)
+            .path("/archive")
+            .preview(Preview::Migrations)
+            .download(writer)
+    }
+
+    fn delete_user_migrations_id_archive(&self, id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/user/migrations")
+            .segment(id)
+            .path("/archive")
+            .preview(Preview::Migrations)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn delete_user_migrations_id_repos_repo_name_lock(&self, id: u64, repo_name: RepoName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/user/migrations")
+            .segment(id)
+            .path("/repos")
+            .segment(repo_name)
+            .path("/lock")
+            .preview(Preview::Migrations)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn wait_user_migrations_id(&self, id: u64, timeout: Duration) -> Result<Migration, error::Error> {
+        wait_migration(timeout, || self.get_user_migrations_id(id))
+    }
+}
+
+#[cfg(test)]
+mod migrations_test {
+
+    use std::io::ErrorKind;
+    use std::time::Duration;
+
+    use serde_json;
+
+    use error;
+    use super::{wait_migration, Migration, MigrationState};
+
+    fn migration(state: MigrationState) -> Migration {
+        let mut migration: Migration = serde_json::from_str(include_str!("../../tests/fixtures/migrations/migration.json")).unwrap();
+        migration.state = state;
+        migration
+    }
+
+    #[test]
+    fn wait_finished() {
+        let mut polls = 0;
+        let exported  = wait_migration(Duration::from_secs(60), || {
+            polls += 1;
+            Ok(migration(MigrationState::Exported))
+        }).unwrap();
+        assert!(exported.state == MigrationState::Exported);
+        assert!(polls == 1);
+
+        let failed = wait_migration(Duration::from_secs(60), || Ok(migration(MigrationState::Failed))).unwrap();
+        assert!(failed.state == MigrationState::Failed);
+    }
+
+    #[test]
+    fn wait_timeout() {
+        match wait_migration(Duration::from_secs(0), || Ok(migration(MigrationState::Exporting))) {
+            Err(error::Error::STDIO(err)) => assert!(err.kind() == ErrorKind::TimedOut),
+            _                             => panic!("the migration should time out")
+        }
+    }
+}
@@ -0,0 +1,409 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Source Imports
+//!
+//! These are the responses and API call functions related
+//! to the source imports endpoints of the API, available
+//! with the `preview-source-imports` feature. Imports are
+//! made from the `importer` addresses of
+//! `miscellaneous::meta::Meta`.
+//!
+//! Reference: https://developer.github.com/v3/migration/source_imports/
+
+use hyper::method::Method;
+
+use client::Client;
+use error;
+use path::{Owner, RepoName};
+use preview::Preview;
+
+string_enum! {
+    /// Version control system of the imported project.
+    pub enum Vcs {
+        Subversion => "subversion",
+        Git        => "git",
+        Mercurial  => "mercurial",
+        Tfvc       => "tfvc"
+    }
+}
+
+string_enum! {
+    /// Status of an import.
+    pub enum ImportStatus {
+        Detecting              => "detecting",
+        Importing              => "importing",
+        Mapping                => "mapping",
+        Pushing                => "pushing",
+        Complete               => "complete",
+        AuthFailed             => "auth_failed",
+        Error                  => "error",
+        DetectionNeedsAuth     => "detection_needs_auth",
+        DetectionFoundNothing  => "detection_found_nothing",
+        DetectionFoundMultiple => "detection_found_multiple"
+    }
+}
+
+string_enum! {
+    /// Preference for the files larger than 100MB of an
+    /// import.
+    pub enum LfsPreference {
+        OptIn  => "opt_in",
+        OptOut => "opt_out"
+    }
+}
+
+/// Return type of the import endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Import {
+    pub vcs:               Option<Vcs>,
+    pub use_lfs:           Option<LfsPreference>,
+    pub vcs_url:           String,
+    pub tfvc_project:      Option<String>,
+    pub status:            ImportStatus,
+    pub status_text:       Option<String>,
+    pub failed_step:       Option<String>,
+    pub error_message:     Option<String>,
+    pub import_percent:    Option<u64>,
+    pub commit_count:      Option<u64>,
+    pub push_percent:      Option<u64>,
+    pub has_large_files:   Option<bool>,
+    pub large_files_size:  Option<u64>,
+    pub large_files_count: Option<u64>,
+    /// Projects found at the URL, one of them is chosen
+    /// with an `ImportEdit` when the status is
+    /// `DetectionFoundMultiple`.
+    pub project_choices:   Option<Vec<ProjectChoice>>,
+    pub message:           Option<String>,
+    pub authors_count:     Option<u64>,
+    pub url:               String,
+    pub html_url:          String,
+    pub authors_url:       String,
+    pub repository_url:    String
+}
+
+/// Sub-component of Import
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ProjectChoice {
+    pub vcs:          Vcs,
+    pub tfvc_project: Option<String>,
+    pub human_name:   String
+}
+
+/// Parameters for starting an import.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ImportParam {
+    /// URL of the project to import.
+    pub vcs_url:      String,
+    /// Version control system of the project, detected if
+    /// not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcs:          Option<Vcs>,
+    /// Username for the project, if it requires
+    /// authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcs_username: Option<String>,
+    /// Password for the project, if it requires
+    /// authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcs_password: Option<String>,
+    /// Project to import, for TFVC only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tfvc_project: Option<String>
+}
+
+impl ImportParam {
+
+    /// Creates the parameters of an import of a project
+    /// with its version control system detected.
+    pub fn new(vcs_url: &str) -> ImportParam {
+        ImportParam {
+            vcs_url:      vcs_url.to_string(),
+            vcs:          None,
+            vcs_username: None,
+            vcs_password: None,
+            tfvc_project: None
+        }
+    }
+}
+
+/// Parameters for the editing of an import, either the
+/// credentials or the chosen project can be changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ImportEdit {
+    /// Username for the project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcs_username: Option<String>,
+    /// Password for the project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcs_password: Option<String>,
+    /// Version control system of the chosen project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcs:          Option<Vcs>,
+    /// Chosen project, for TFVC only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tfvc_project: Option<String>
+}
+
+impl ImportEdit {
+
+    /// Creates an edit that leaves the import unchanged.
+    pub fn new() -> ImportEdit {
+        ImportEdit::default()
+    }
+
+    /// Sets the credentials for the project, ex. after an
+    /// `AuthFailed` status.
+    pub fn credentials(mut self, username: &str, password: &str) -> ImportEdit {
+        self.vcs_username = Some(username.to_string());
+        self.vcs_password = Some(password.to_string());
+        self
+    }
+
+    /// Chooses one of the projects found at the URL.
+    pub fn project(mut self, choice: &ProjectChoice) -> ImportEdit {
+        self.vcs          = Some(choice.vcs.clone());
+        self.tfvc_project = choice.tfvc_project.clone();
+        self
+    }
+}
+
+/// Return type of the import authors endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ImportAuthor {
+    pub id:          u64,
+    /// Identifier of the author in the imported project.
+    pub remote_id:   String,
+    pub remote_name: String,
+    pub email:       String,
+    pub name:        String,
+    pub url:         String,
+    pub import_url:  String
+}
+
+/// Parameters for mapping an author of an import to a
+/// Github user.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct AuthorParam {
+    /// Email used in the rewritten commits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Name used in the rewritten commits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:  Option<String>
+}
+
+impl AuthorParam {
+
+    /// Maps an author to a name and an email.
+    pub fn new(name: &str, email: &str) -> AuthorParam {
+        AuthorParam {
+            email: Some(email.to_string()),
+            name:  Some(name.to_string())
+        }
+    }
+}
+
+/// Return type of the import large files endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LargeFile {
+    pub ref_name: String,
+    pub path:     String,
+    pub oid:      String,
+    pub size:     u64
+}
+
+///Internal parameter
+#[derive(Serialize)]
+struct LfsParam {
+    use_lfs: LfsPreference
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait SourceImportsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/migration/source_imports/#start-an-import)\]
+    /// Starts the import of a project into a repository.
+    /// ## Endpoint:
+    /// PUT /repos/:owner/:repo/import
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `import`: Parameters of the import.
+    fn put_repos_owner_repo_import(&self, owner: Owner, repo: RepoName, import: ImportParam) -> Result<Import, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/source_imports/#get-import-progress)\]
+    /// Returns the import into a repository along with its
+    /// progress.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/import
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    fn get_repos_owner_repo_import(&self, owner: Owner, repo: RepoName) -> Result<Import, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/source_imports/#update-existing-import)\]
+    /// Updates the credentials or the chosen project of an
+    /// import, the import is restarted.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/import
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `import`: Changes to the import.
+    fn patch_repos_owner_repo_import(&self, owner: Owner, repo: RepoName, import: ImportEdit) -> Result<Import, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/source_imports/#cancel-an-import)\]
+    /// Cancels the import into a repository.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/import
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    fn delete_repos_owner_repo_import(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/source_imports/#get-commit-authors)\]
+    /// Returns the authors of the commits of an import.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/import/authors
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `since`: Only authors with an ID greater than this
+    /// one are returned.
+    fn get_repos_owner_repo_import_authors(&self, owner: Owner, repo: RepoName, since: Option<u64>) -> Result<Vec<ImportAuthor>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/source_imports/#map-a-commit-author)\]
+    /// Maps an author of the commits of an import to a name
+    /// and an email, ex. those of a Github user.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/import/authors/:author_id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `author_id`: ID of the author.
+    /// * `author`: Name and email of the author.
+    fn patch_repos_owner_repo_import_authors_author_id(&self, owner: Owner, repo: RepoName, author_id: u64, author: AuthorParam) -> Result<ImportAuthor, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/source_imports/#set-git-lfs-preference)\]
+    /// Sets whether the files larger than 100MB of an import
+    /// are stored with Git LFS.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/import/lfs
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `use_lfs`: Preference for the large files.
+    fn patch_repos_owner_repo_import_lfs(&self, owner: Owner, repo: RepoName, use_lfs: LfsPreference) -> Result<Import, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/migration/source_imports/#get-large-files)\]
+    /// Returns the files larger than 100MB found by an
+    /// import.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/import/large_files
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    fn get_repos_owner_repo_import_large_files(&self, owner: Owner, repo: RepoName) -> Result<Vec<LargeFile>, error::Error>;
+}
+
+impl SourceImportsExt for Client {
+
+    fn put_repos_owner_repo_import(&self, owner: Owner, repo: RepoName, import: ImportParam) -> Result<Import, error::Error> {
+        self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/import")
+            .json(&import)
+            .preview(Preview::SourceImports)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_import(&self, owner: Owner, repo: RepoName) -> Result<Import, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/import")
+            .preview(Preview::SourceImports)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_repos_owner_repo_import(&self, owner: Owner, repo: RepoName, import: ImportEdit) -> Result<Import, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/import")
+            .json(&import)
+            .preview(Preview::SourceImports)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_import(&self, owner: Owner, repo: RepoName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/import")
+            .preview(Preview::SourceImports)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_repos_owner_repo_import_authors(&self, owner: Owner, repo: RepoName, since: Option<u64>) -> Result<Vec<ImportAuthor>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/import/authors")
+            .query_opt("since", since)
+            .preview(Preview::SourceImports)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_repos_owner_repo_import_authors_author_id(&self, owner: Owner, repo: RepoName, author_id: u64, author: AuthorParam) -> Result<ImportAuthor, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/import/authors")
+            .segment(author_id)
+            .json(&author)
+            .preview(Preview::SourceImports)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_repos_owner_repo_import_lfs(&self, owner: Owner, repo: RepoName, use_lfs: LfsPreference) -> Result<Import, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/import/lfs")
+            .json(&LfsParam {
+                use_lfs: use_lfs
+            })
+            .preview(Preview::SourceImports)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_import_large_files(&self, owner: Owner, repo: RepoName) -> Result<Vec<LargeFile>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/import/large_files")
+            .preview(Preview::SourceImports)
+            .send()
+            .map(|response| response.data)
+    }
+}
+
+//TODO: TESTS
@@ -38,11 +38,11 @@ pub trait EmojisExt {
     /// the conversion to String failed, please create an
     /// issue with the debug output of the error if this ever
     /// occurs.
-    fn get_emojis(&mut self) -> Result<Option<BTreeMap<String, String>>, error::Error>;
+    fn get_emojis(&self) -> Result<Option<BTreeMap<String, String>>, error::Error>;
 }
 
 impl EmojisExt for Client {
-    fn get_emojis(&mut self) -> Result<Option<BTreeMap<String, String>>, error::Error> {
+    fn get_emojis(&self) -> Result<Option<BTreeMap<String, String>>, error::Error> {
 
         let mut response     = try!(self.get("/emojis".to_string(), None));
         let     response_str = try!(Client::response_to_string(&mut response));
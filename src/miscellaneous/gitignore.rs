@@ -12,15 +12,13 @@
 //!
 //! Reference: https://developer.github.com/v3/activity/watching/
 
-use hyper::header::{Accept, qitem};
-use hyper::mime::{Mime, TopLevel, SubLevel};
+use hyper::method::Method;
 
 use client::Client;
 use error;
-use utils;
 
 ///The response of the non-raw single template request.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GitignoreTemplate {
     pub name:   String,
     pub source: String
@@ -37,7 +35,7 @@ pub trait GitignoreExt {
     /// repository.
     /// ## Endpoint:
     /// GET /gitignore/templates
-    fn get_gitignore_templates(&mut self) -> Result<Vec<String>, error::Error>;
+    fn get_gitignore_templates(&self) -> Result<Vec<String>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gitignore/#get-a-single-template)\]
     /// Returns a single template.
@@ -45,7 +43,7 @@ pub trait GitignoreExt {
     /// GET /gitignore/templates/:name
     /// ## Parameters:
     /// * `name`: Name of/the language template requested
-    fn get_gitignore_templates_name(&mut self, name: &String) -> Result<GitignoreTemplate, error::Error>;
+    fn get_gitignore_templates_name(&self, name: &String) -> Result<GitignoreTemplate, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/gitignore/#get-a-single-template)\]
     /// Returns a single template in raw form.
@@ -53,26 +51,31 @@ pub trait GitignoreExt {
     /// GET /gitignore/templates/:name
     /// ## Parameters:
     /// * `name`: Name of/the language template requested
-    fn get_gitignore_templates_name_raw(&mut self, name: &String) -> Result<String, error::Error>;
+    fn get_gitignore_templates_name_raw(&self, name: &String) -> Result<String, error::Error>;
 }
 
 impl GitignoreExt for Client {
 
-    fn get_gitignore_templates(&mut self) -> Result<Vec<String>, error::Error> {
-        utils::request_endpoint(self, "/gitignore/templates".into())
+    fn get_gitignore_templates(&self) -> Result<Vec<String>, error::Error> {
+        self.request(Method::Get, "/gitignore/templates")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gitignore_templates_name(&mut self, name: &String) -> Result<GitignoreTemplate, error::Error> {
-        utils::request_endpoint(self, format!("/gitignore/templates/{}", name))
+    fn get_gitignore_templates_name(&self, name: &String) -> Result<GitignoreTemplate, error::Error> {
+        self.request(Method::Get, "/gitignore/templates")
+            .segment(name)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_gitignore_templates_name_raw(&mut self, name: &String) -> Result<String, error::Error> {
-
-        let mut header = self.get_default_headers();
-        header.remove::<Accept>();
-        header.set(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.github.v3.raw".to_string()), vec![]))]));
-
-        utils::request_endpoint_with_headers(self, format!("/gitignore/templates/{}", name), Some(header))
+    fn get_gitignore_templates_name_raw(&self, name: &String) -> Result<String, error::Error> {
+        //The raw template is not JSON
+        let mut response = try!(self.request(Method::Get, "/gitignore/templates")
+            .segment(name)
+            .accept("vnd.github.v3.raw")
+            .send_raw());
+        Client::response_to_string(&mut response)
     }
 }
 
@@ -60,7 +60,7 @@ pub trait MarkdownExt {
     /// POST /markdown
     /// ## Parameters:
     /// * `request_body`: Body of the request, see MarkdownRequest.
-    fn post_markdown(&mut self, request_body: &MarkdownRequest) -> Result<String, error::Error>;
+    fn post_markdown(&self, request_body: &MarkdownRequest) -> Result<String, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/markdown/#render-a-markdown-document-in-raw-mode)\]
     /// Returns the rendered markdown as html.
@@ -69,13 +69,13 @@ pub trait MarkdownExt {
     /// ## Parameters:
     /// * `request_body`: Body of the request, see MarkdownRequest.
     /// * `mime`:
-    fn post_markdown_raw(&mut self, request_body: &MarkdownRequest, mime: MarkdownRawMIME) -> Result<String, error::Error>;
+    fn post_markdown_raw(&self, request_body: &MarkdownRequest, mime: MarkdownRawMIME) -> Result<String, error::Error>;
 
 }
 
 impl MarkdownExt for Client {
 
-    fn post_markdown(&mut self, request_body: &MarkdownRequest) -> Result<String, error::Error> {
+    fn post_markdown(&self, request_body: &MarkdownRequest) -> Result<String, error::Error> {
 
         //Set Accept
         let mut header = self.get_default_headers();
@@ -90,7 +90,7 @@ impl MarkdownExt for Client {
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
 
-    fn post_markdown_raw(&mut self, request_body: &MarkdownRequest, mime: MarkdownRawMIME) -> Result<String, error::Error> {
+    fn post_markdown_raw(&self, request_body: &MarkdownRequest, mime: MarkdownRawMIME) -> Result<String, error::Error> {
 
         //Edit Accept and set Content-Type
         let mut header = self.get_default_headers();
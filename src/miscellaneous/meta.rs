@@ -17,25 +17,33 @@ use error;
 use utils;
 
 ///Response returned by the meta endpoint.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Meta {
     /// Whether authentication with username and password
     /// is supported.
     pub verifiable_password_authentication: bool,
-    /// The currently-deployed SHA of github-services.
-    pub github_services_sha: String,
+    /// The currently-deployed SHA of github-services,
+    /// not reported by Github Enterprise.
+    pub github_services_sha: Option<String>,
+    /// Version of the Github Enterprise server, not
+    /// reported by github.com.
+    pub installed_version: Option<String>,
     /// An Array of IP addresses that incoming service hooks
     /// will originate from on GitHub.com.
+    #[serde(default)]
     pub hooks:    Vec<String>,
     /// An Array of IP addresses specifying the Git servers
     /// for GitHub.com.
+    #[serde(default)]
     pub git:      Vec<String>,
     /// An Array of IP addresses specifying the A records
     /// for GitHub Pages.
+    #[serde(default)]
     pub pages:    Vec<String>,
     /// An Array of IP addresses specifying the addresses
     /// that source imports will originate from on
     /// GitHub.com.
+    #[serde(default)]
     pub importer: Vec<String>,
 }
 
@@ -49,11 +57,11 @@ pub trait MetaExt {
     /// Returns information about GitHub
     /// ## Endpoint:
     /// GET /meta
-    fn get_meta(&mut self) -> Result<Meta, error::Error>;
+    fn get_meta(&self) -> Result<Meta, error::Error>;
 }
 
 impl MetaExt for Client {
-    fn get_meta(&mut self) -> Result<Meta, error::Error> {
+    fn get_meta(&self) -> Result<Meta, error::Error> {
         utils::request_endpoint(self, "/meta".into())
     }
 }
@@ -12,32 +12,46 @@
 //!
 //! Reference: https://developer.github.com/v3/rate_limit/
 
+#[cfg(feature = "chrono")]
+use chrono::{self, TimeZone, UTC};
+
 use client::Client;
 use error;
 use utils;
 
 /// The response to rate_limit endpoint.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RateLimit {
     pub resources: RateLimitResources
     //rate omitted since it is deprecated and will ne removed in the next version of the API
 }
 
 ///Sub-component of the rate_limit response.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RateLimitResources {
     pub core:   RateLimitElement,
     pub search: RateLimitElement
 }
 
 ///Sub-component of the rate_limit response.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RateLimitElement {
     pub limit:     u64,
     pub remaining: u64,
+    /// Time at which the rate limit is reset, in seconds
+    /// since the epoch.
     pub reset:     u64
 }
 
+impl RateLimitElement {
+
+    /// Returns the time at which the rate limit is reset.
+    #[cfg(feature = "chrono")]
+    pub fn reset_to_chrono(&self) -> chrono::DateTime<UTC> {
+        UTC.timestamp(self.reset as i64, 0)
+    }
+}
+
 ////////////////////////////////////////////////////////////
 //                    Extension Trait                     //
 ////////////////////////////////////////////////////////////
@@ -47,11 +61,11 @@ pub trait RateLimitExt {
     /// Returns the current rate limit status.
     /// ## Endpoint:
     /// GET /rate_limit
-    fn get_rate_limit(&mut self) -> Result<RateLimit, error::Error>;
+    fn get_rate_limit(&self) -> Result<RateLimit, error::Error>;
 }
 
 impl RateLimitExt for Client {
-    fn get_rate_limit(&mut self) -> Result<RateLimit, error::Error> {
+    fn get_rate_limit(&self) -> Result<RateLimit, error::Error> {
         utils::request_endpoint(self, "/rate_limit".into())
     }
 }
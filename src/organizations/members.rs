@@ -11,97 +11,68 @@
 ///! to the members endpoints of the API.
 ///!
 
-use hyper::{Error as HyperError, Url};
+use hyper::method::Method;
 use serde_json;
 
 use client::Client;
 use common::User;
 use error;
 use organizations::Organization;
+use path;
 use utils;
 
-/// Parameter to get members.
-/// Filter members returned in the list.
-pub enum Filter {
-    /// Members without two-factor authentication enabled.
-    /// Available for organization owners.
-    TwoFADisabled,
-    /// All members the authenticated user can see.
-    All
-}
-
-impl Filter {
-    fn to_str(&self) -> String {
-        match self {
-            &Filter::TwoFADisabled => "2fa_disabled".to_string(),
-            &Filter::All           => "all".to_string(),
-        }
+string_enum! {
+    /// Parameter to get members.
+    /// Filter members returned in the list.
+    pub enum Filter {
+        /// Members without two-factor authentication enabled.
+        /// Available for organization owners.
+        TwoFADisabled => "2fa_disabled",
+        /// All members the authenticated user can see.
+        All           => "all"
     }
 }
 
-/// Parameter to get members list.
-/// Filter members returned by their role.
-pub enum RoleMemberList {
-    /// All members of the organization, regardless of role.
-    All,
-    /// Organization owners.
-    Admin,
-    /// Non-owner organization members.
-    Member
-}
-
-impl RoleMemberList {
-    fn to_str(&self) -> String {
-        match self {
-            &RoleMemberList::All=> "2fa_disabled".to_string(),
-            &RoleMemberList::Admin=> "all".to_string(),
-            &RoleMemberList::Member=> "all".to_string(),
-        }
+string_enum! {
+    /// Role of a member of an organization, also used to
+    /// filter the members returned by their role.
+    pub enum Role {
+        /// All members of the organization, regardless of
+        /// role. Only valid to filter members.
+        All            => "all",
+        /// Organization owners.
+        Admin          => "admin",
+        /// Non-owner organization members.
+        Member         => "member",
+        /// Billing managers of the organization.
+        BillingManager => "billing_manager"
     }
 }
 
-/// Parameter to get members list.
-/// Filter members returned by their role.
-pub enum RoleUpdateMembership {
-    /// Organization owners.
-    Admin,
-    /// Non-owner organization members.
-    Member
-}
-
-impl RoleUpdateMembership {
-    fn to_str(&self) -> String {
-        match self {
-            &RoleUpdateMembership::Admin=> "all".to_string(),
-            &RoleUpdateMembership::Member=> "all".to_string(),
-        }
+string_enum! {
+    /// State of an organization membership, also used to
+    /// filter the memberships to return.
+    pub enum State {
+        /// User's membership is active.
+        Active  => "active",
+        /// User's membership is pending approval.
+        Pending => "pending"
     }
 }
 
-/// Parameter to list Organization memberships.
-/// FIndicates the state of the memberships to return.
-pub enum State {
-    /// User's membership is active.
-    Active,
-    /// User's membership is pending approval.
-    Pending
-}
-
-impl State {
-    fn to_str(&self) -> String {
-        match self {
-            &State::Active  => "active".to_string(),
-            &State::Pending => "pending".to_string(),
-        }
-    }
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct RoleParam {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<Role>
 }
 
 /// Return type for getting an organization membership.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct OrgMembership {
     pub url:              String,
-    pub state:            String,
-    pub role:             String,
+    pub state:            State,
+    pub role:             Role,
     pub organization_url: String,
     pub organization:     Organization,
     pub user:             User
@@ -124,9 +95,9 @@ pub trait MembersExt {
     /// * `org`: Name of the organization
     /// * `filter`: Default: `Filter::All` Filter members
     /// returned in the list.
-    /// * `role`: Default: `RoleMemberList::All` Filter
+    /// * `role`: Default: `Role::All` Filter
     /// members returned by their role.
-    fn get_orgs_org_members(&mut self, org: String, filter: Option<Filter>, role: Option<RoleMemberList>) -> Result<Vec<User>, error::Error>;
+    fn get_orgs_org_members(&self, org: String, filter: Option<Filter>, role: Option<Role>) -> Result<Vec<User>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#check-membership)\]
     /// Checks if a user is, publicly or privately, a
@@ -137,7 +108,7 @@ pub trait MembersExt {
     /// * `org`: Name of the organization
     /// * `username`: Name of the user
     //TODO: Handle 302
-    fn get_orgs_org_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>;
+    fn get_orgs_org_members_username(&self, org: String, username: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#remove-a-member)\]
     /// Removes a member from the organization.
@@ -146,7 +117,7 @@ pub trait MembersExt {
     /// ## Parameters:
     /// * `org`: Name of the organization
     /// * `username`: Name of the user
-    fn delete_orgs_org_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>;
+    fn delete_orgs_org_members_username(&self, org: String, username: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#public-members-list)\]
     /// Returns a list of an organizations public members.
@@ -154,7 +125,7 @@ pub trait MembersExt {
     /// GET /orgs/:org/public_members
     /// ## Parameters:
     /// * `org`: Name of the organization
-    fn get_orgs_org_public_members(&mut self, org: String) -> Result<Vec<User>, error::Error>;
+    fn get_orgs_org_public_members(&self, org: String) -> Result<Vec<User>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#check-public-membership)\]
     /// Checks if a member is publicly displayed as a
@@ -164,7 +135,7 @@ pub trait MembersExt {
     /// ## Parameters:
     /// * `org`: Name of the organization
     /// * `username`: Name of the user
-    fn get_orgs_org_public_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>;
+    fn get_orgs_org_public_members_username(&self, org: String, username: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#publicize-a-users-membership)\]
     /// Publicizes a user's membership
@@ -173,7 +144,7 @@ pub trait MembersExt {
     /// ## Parameters:
     /// * `org`: Name of the organization
     /// * `username`: Name of the user
-    fn put_orgs_org_public_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>;
+    fn put_orgs_org_public_members_username(&self, org: String, username: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#conceal-a-users-membership)\]
     /// Conceals a user's membership
@@ -182,7 +153,7 @@ pub trait MembersExt {
     /// ## Parameters:
     /// * `org`: Name of the organization
     /// * `username`: Name of the user
-    fn delete_orgs_org_public_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>;
+    fn delete_orgs_org_public_members_username(&self, org: String, username: String) -> Result<(), error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#get-organization-membership)\]
     /// Returns the user's membership.
@@ -191,7 +162,7 @@ pub trait MembersExt {
     /// ## Parameters:
     /// * `org`: Name of the organization
     /// * `username`: Name of the user
-    fn get_orgs_org_memberships_username(&mut self, org: String, username: String) -> Result<OrgMembership, error::Error>;
+    fn get_orgs_org_memberships_username(&self, org: String, username: String) -> Result<OrgMembership, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#add-or-update-organization-membership)\]
     /// Adds/Updated an organization membership.
@@ -200,9 +171,9 @@ pub trait MembersExt {
     /// ## Parameters:
     /// * `org`: Name of the organization
     /// * `username`: Name of the user
-    /// * `role`: Default: `RoleUpdateMembership::Member`
-    /// Filter members returned by their role.
-    fn put_orgs_org_memberships_username(&mut self, org: String, username: String, role: Option<RoleUpdateMembership>) -> Result<OrgMembership, error::Error>;
+    /// * `role`: Default: `Role::Member`, role to give to
+    /// the user in the organization.
+    fn put_orgs_org_memberships_username(&self, org: String, username: String, role: Option<Role>) -> Result<OrgMembership, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#remove-organization-membership)\]
     /// Removes an organization membership.
@@ -211,7 +182,7 @@ pub trait MembersExt {
     /// ## Parameters:
     /// * `org`: Name of the organization
     /// * `username`: Name of the user
-    fn delete_orgs_org_memberships_username(&mut self, org: String, username: String) -> Result<(), error::Error>;
+    fn delete_orgs_org_memberships_username(&self, org: String, username: String) -> Result<(), error::Error>;
 
     //TODO: Once out pf preview.
     /*/// \[[Reference](https://developer.github.com/v3/orgs/members#list-pending-organization-invitations)\]
@@ -219,7 +190,7 @@ pub trait MembersExt {
     /// ## Endpoint:
     /// GET /orgs/:org/invitations
     /// ## Parameters:
-    fn get_orgs_org_invitations(&mut self) -> Result<, error::Error>;*/
+    fn get_orgs_org_invitations(&self) -> Result<, error::Error>;*/
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#list-your-organization-memberships)\]
     /// Lists an authenticated user's memberships.
@@ -228,7 +199,7 @@ pub trait MembersExt {
     /// ## Parameters:
     /// * `state`: Default: Active and Pending. Indicates
     /// the state of the memberships to return.
-    fn get_user_memberships_orgs(&mut self, state: Option<State>) -> Result<Vec<OrgMembership>, error::Error>;
+    fn get_user_memberships_orgs(&self, state: Option<State>) -> Result<Vec<OrgMembership>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#get-your-organization-membership)\]
     /// Gets an authenticates user's membership.
@@ -236,7 +207,7 @@ pub trait MembersExt {
     /// GET /user/memberships/orgs/:org
     /// ## Parameters:
     /// * `org`: Name of the organization
-    fn get_user_memberships_orgs_org(&mut self, org: String) -> Result<OrgMembership, error::Error>;
+    fn get_user_memberships_orgs_org(&self, org: String) -> Result<OrgMembership, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/members#edit-your-organization-membership)\]
     /// Edits an organization membership.
@@ -244,134 +215,98 @@ pub trait MembersExt {
     /// PATCH /user/memberships/orgs/:org
     /// ## Parameters:
     /// * `org`: Name of the organization
-    fn patch_user_memberships_orgs_org(&mut self, org: String) -> Result<OrgMembership, error::Error>;
+    fn patch_user_memberships_orgs_org(&self, org: String) -> Result<OrgMembership, error::Error>;
 }
 
 impl MembersExt for Client {
 
-    fn get_orgs_org_members(&mut self, org: String, filter: Option<Filter>, role: Option<RoleMemberList>) -> Result<Vec<User>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/orgs/{}/members", self.api_url, org)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = filter {
-                query_pairs.append_pair("filter", &param.to_str());
-            }
-
-            if let Some(param) = role {
-                query_pairs.append_pair("role", &param.to_str());
-            }
-        }
-
-        utils::request_endpoint(self, format!("/orgs/{}/members?{}", org, url.query().unwrap()))
+    fn get_orgs_org_members(&self, org: String, filter: Option<Filter>, role: Option<Role>) -> Result<Vec<User>, error::Error>{
+        self.request(Method::Get, "/orgs")
+            .segment(org)
+            .path("/members")
+            .query_opt("filter", filter)
+            .query_opt("role", role)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_orgs_org_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>{
-        match self.get(format!("/orgs/{}/members/{}", org, username), None) {
+    fn get_orgs_org_members_username(&self, org: String, username: String) -> Result<(), error::Error>{
+        match self.get(format!("/orgs/{}/members/{}", path::escape_segment(&org), path::escape_segment(&username)), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
     }
 
-    fn delete_orgs_org_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>{
-        match self.delete(format!("/orgs/{}/members/{}", org, username), None) {
+    fn delete_orgs_org_members_username(&self, org: String, username: String) -> Result<(), error::Error>{
+        match self.delete(format!("/orgs/{}/members/{}", path::escape_segment(&org), path::escape_segment(&username)), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
     }
 
-    fn get_orgs_org_public_members(&mut self, org: String) -> Result<Vec<User>, error::Error>{
-        utils::request_endpoint(self, format!("/orgs/{}/public_members", org))
+    fn get_orgs_org_public_members(&self, org: String) -> Result<Vec<User>, error::Error>{
+        utils::request_endpoint(self, format!("/orgs/{}/public_members", path::escape_segment(&org)))
     }
 
-    fn get_orgs_org_public_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>{
-        match self.get(format!("/orgs/{}/public_members/{}", org, username), None) {
+    fn get_orgs_org_public_members_username(&self, org: String, username: String) -> Result<(), error::Error>{
+        match self.get(format!("/orgs/{}/public_members/{}", path::escape_segment(&org), path::escape_segment(&username)), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
     }
 
-    //TODO: Set content length to 0
-    fn put_orgs_org_public_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>{
-        match self.put(format!("/orgs/{}/public_members/{}", org, username), None) {
-            Ok(_)    => Ok(()),
-            Err(err) => Err(err)
-        }
+    fn put_orgs_org_public_members_username(&self, org: String, username: String) -> Result<(), error::Error>{
+        self.request(Method::Put, "/orgs")
+            .segment(org)
+            .path("/public_members")
+            .segment(username)
+            .send_empty()
+            .map(|_| ())
     }
 
-    fn delete_orgs_org_public_members_username(&mut self, org: String, username: String) -> Result<(), error::Error>{
-        match self.delete(format!("/orgs/{}/public_members/{}", org, username), None) {
+    fn delete_orgs_org_public_members_username(&self, org: String, username: String) -> Result<(), error::Error>{
+        match self.delete(format!("/orgs/{}/public_members/{}", path::escape_segment(&org), path::escape_segment(&username)), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
     }
 
-    fn get_orgs_org_memberships_username(&mut self, org: String, username: String) -> Result<OrgMembership, error::Error>{
-        utils::request_endpoint(self, format!("/orgs/{}/memberships/{}", org, username))
+    fn get_orgs_org_memberships_username(&self, org: String, username: String) -> Result<OrgMembership, error::Error>{
+        utils::request_endpoint(self, format!("/orgs/{}/memberships/{}", path::escape_segment(&org), path::escape_segment(&username)))
     }
 
-    fn put_orgs_org_memberships_username(&mut self, org: String, username: String, role: Option<RoleUpdateMembership>) -> Result<OrgMembership, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/orgs/{}/memberships/{}", self.api_url, org, username)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = role {
-                query_pairs.append_pair("role", &param.to_str());
-            }
-        }
-
-        let mut response     = try!(self.put(format!("/orgs/{}/memberships/{}?{}", org, username, url.query().unwrap()), None));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn put_orgs_org_memberships_username(&self, org: String, username: String, role: Option<Role>) -> Result<OrgMembership, error::Error>{
+        self.request(Method::Put, "/orgs")
+            .segment(org)
+            .path("/memberships")
+            .segment(username)
+            .json(&RoleParam {
+                role: role
+            })
+            .send()
+            .map(|response| response.data)
     }
 
-    fn delete_orgs_org_memberships_username(&mut self, org: String, username: String) -> Result<(), error::Error>{
-        match self.delete(format!("/orgs/{}/memberships/{}", org, username), None) {
+    fn delete_orgs_org_memberships_username(&self, org: String, username: String) -> Result<(), error::Error>{
+        match self.delete(format!("/orgs/{}/memberships/{}", path::escape_segment(&org), path::escape_segment(&username)), None) {
             Ok(_)    => Ok(()),
             Err(err) => Err(err)
         }
     }
 
-    fn get_user_memberships_orgs(&mut self, state: Option<State>) -> Result<Vec<OrgMembership>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/user/memberships/orgs", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-
-            if let Some(param) = state {
-                query_pairs.append_pair("state", &param.to_str());
-            }
-        }
-
-        utils::request_endpoint(self, format!("/user/memberships/orgs?{}", url.query().unwrap()))
+    fn get_user_memberships_orgs(&self, state: Option<State>) -> Result<Vec<OrgMembership>, error::Error>{
+        self.request(Method::Get, "/user/memberships/orgs")
+            .query_opt("state", state)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_user_memberships_orgs_org(&mut self, org: String) -> Result<OrgMembership, error::Error>{
-        utils::request_endpoint(self, format!("/user/memberships/orgs/{}", org))
+    fn get_user_memberships_orgs_org(&self, org: String) -> Result<OrgMembership, error::Error>{
+        utils::request_endpoint(self, format!("/user/memberships/orgs/{}", path::escape_segment(&org)))
     }
 
-    fn patch_user_memberships_orgs_org(&mut self, org: String) -> Result<OrgMembership, error::Error>{
-        let mut response     = try!(self.patch_body(format!("/user/memberships/orgs/{}", org), None, "{\"state\":\"active\"}".to_string()));
+    fn patch_user_memberships_orgs_org(&self, org: String) -> Result<OrgMembership, error::Error>{
+        let mut response     = try!(self.patch_body(format!("/user/memberships/orgs/{}", path::escape_segment(&org)), None, "{\"state\":\"active\"}".to_string()));
         let     response_str = try!(Client::response_to_string(&mut response));
         serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
     }
@@ -12,12 +12,11 @@
 //!
 //! Reference: https://developer.github.com/v3/orgs/
 
-use hyper::{Error as HyperError, Url};
-use serde_json;
+use hyper::method::Method;
 
 use client::Client;
+use common::{DateTime, Edit};
 use error;
-use utils;
 
 /// Endpoints for Members.
 pub mod members;
@@ -26,7 +25,7 @@ pub mod members;
 // pub mod outside_collaborators
 
 /// Return type for Organizations endpoints.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Organization {
     pub login:               String,
     pub id:                  u64,
@@ -49,7 +48,7 @@ pub struct Organization {
     pub followers:           Option<u64>,
     pub following:           Option<u64>,
     pub html_url:            Option<String>,
-    pub created_at:          Option<String>,
+    pub created_at:          Option<DateTime>,
     #[serde(rename = "type")]
     pub org_type:            Option<String>,
     pub total_private_repos: Option<u64>,
@@ -65,33 +64,109 @@ pub struct Organization {
 }
 
 /// Sub-component fot Organization
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Plan {
     pub name:          String,
     pub space:         u64,
     pub private_repos: u64
 }
 
-///Input parameter for editing organizations.
-#[derive(Clone, Debug, PartialEq, Serialize)]
+///Input parameter for editing organizations, only the
+/// fields that are set are changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
 pub struct OrganizationEditParam {
     /// Billing email address.
-    pub billing_email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_email: Option<String>,
     /// Company name.
-    pub company:       String,
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub company:       Edit<String>,
     /// Publicly visible email address.
-    pub email:         String,
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub email:         Edit<String>,
     /// The location of the organization.
-    pub location:      String,
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub location:      Edit<String>,
     /// Shorthand name of the company.
-    pub name:          String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:          Option<String>,
     /// Description of the company.
-    pub description:   String
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub description:   Edit<String>
     //TODO: Once out of preview
     // pub default_repository_permission: String,
     // pub members_can_create_repositories: String
 }
 
+impl OrganizationEditParam {
+
+    /// Creates an edit that leaves the organization
+    /// unchanged.
+    pub fn new() -> OrganizationEditParam {
+        OrganizationEditParam::default()
+    }
+
+    /// Sets the billing email address.
+    pub fn billing_email(mut self, billing_email: &str) -> OrganizationEditParam {
+        self.billing_email = Some(billing_email.to_string());
+        self
+    }
+
+    /// Sets the company name.
+    pub fn company(mut self, company: &str) -> OrganizationEditParam {
+        self.company = Edit::Set(company.to_string());
+        self
+    }
+
+    /// Removes the company name.
+    pub fn clear_company(mut self) -> OrganizationEditParam {
+        self.company = Edit::Null;
+        self
+    }
+
+    /// Sets the publicly visible email address.
+    pub fn email(mut self, email: &str) -> OrganizationEditParam {
+        self.email = Edit::Set(email.to_string());
+        self
+    }
+
+    /// Removes the publicly visible email address.
+    pub fn clear_email(mut self) -> OrganizationEditParam {
+        self.email = Edit::Null;
+        self
+    }
+
+    /// Sets the location of the organization.
+    pub fn location(mut self, location: &str) -> OrganizationEditParam {
+        self.location = Edit::Set(location.to_string());
+        self
+    }
+
+    /// Removes the location of the organization.
+    pub fn clear_location(mut self) -> OrganizationEditParam {
+        self.location = Edit::Null;
+        self
+    }
+
+    /// Sets the shorthand name of the company.
+    pub fn name(mut self, name: &str) -> OrganizationEditParam {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the company.
+    pub fn description(mut self, description: &str) -> OrganizationEditParam {
+        self.description = Edit::Set(description.to_string());
+        self
+    }
+
+    /// Removes the description of the company.
+    pub fn clear_description(mut self) -> OrganizationEditParam {
+        self.description = Edit::Null;
+        self
+    }
+}
+
 
 ////////////////////////////////////////////////////////////
 //                    Extension Trait                     //
@@ -105,7 +180,7 @@ pub trait OrganizationsExt {
     /// are required to use this endpoint.
     /// ## Endpoint:
     /// GET /user/orgs
-    fn get_user_orgs(&mut self) -> Result<Vec<Organization>, error::Error>;
+    fn get_user_orgs(&self) -> Result<Vec<Organization>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/#list-all-organizations)\]
     /// Returns the list of all organizations.
@@ -114,7 +189,7 @@ pub trait OrganizationsExt {
     /// ## Parameters:
     /// * `since`: Integer ID of the last organization you
     /// have seen
-    fn get_organizations(&mut self, since: u64) -> Result<Vec<Organization>, error::Error>;
+    fn get_organizations(&self, since: u64) -> Result<Vec<Organization>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/#list-user-organizations)\]
     /// Returns the list of a user's organizations.
@@ -122,7 +197,7 @@ pub trait OrganizationsExt {
     /// GET /users/:username/orgs
     /// ## Parameters:
     /// * `username`: Name of the user.
-    fn get_users_username_orgs(&mut self, username: String) -> Result<Vec<Organization>, error::Error>;
+    fn get_users_username_orgs(&self, username: String) -> Result<Vec<Organization>, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/#get-an-organization)\]
     /// Returns a single organizations.
@@ -130,7 +205,7 @@ pub trait OrganizationsExt {
     /// GET /orgs/:org
     /// ## Parameters:
     /// * `org`: Name of the oganization.
-    fn get_orgs_org(&mut self, org: String) -> Result<Organization, error::Error>;
+    fn get_orgs_org(&self, org: String) -> Result<Organization, error::Error>;
 
     /// \[[Reference](https://developer.github.com/v3/orgs/#edit-an-organization)\]
     /// Edits an organization.
@@ -140,48 +215,45 @@ pub trait OrganizationsExt {
     /// * `org`: Name of the oganization.
     /// * `org_param`: Parameter for editing an
     /// organization.
-    fn patch_orgs_org(&mut self, org: String, org_param: OrganizationEditParam) -> Result<Organization, error::Error>;
+    fn patch_orgs_org(&self, org: String, org_param: OrganizationEditParam) -> Result<Organization, error::Error>;
 }
 
 impl OrganizationsExt for Client {
 
-    fn get_user_orgs(&mut self) -> Result<Vec<Organization>, error::Error>{
-        utils::request_endpoint(self, "/user/orgs".to_string())
+    fn get_user_orgs(&self) -> Result<Vec<Organization>, error::Error>{
+        self.request(Method::Get, "/user/orgs")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_organizations(&mut self, since: u64) -> Result<Vec<Organization>, error::Error>{
-
-        let mut url = match Url::parse(&format!("{}/organizations", self.api_url)[..]) {
-            Ok(url)  => url,
-            Err(err) => return Err(error::Error::HTTP(HyperError::Uri(err)))
-        };
-
-        //Limits the scope of the mutable borrow
-        {
-            let mut query_pairs = url.query_pairs_mut();
-            query_pairs.clear();
-            query_pairs.append_pair("since", &format!("{}", since)[..]);
-        }
-
-        utils::request_endpoint(self, format!("/organizations?{}", url.query().unwrap()))
+    fn get_organizations(&self, since: u64) -> Result<Vec<Organization>, error::Error>{
+        self.request(Method::Get, "/organizations")
+            .query("since", since)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_users_username_orgs(&mut self, username: String) -> Result<Vec<Organization>, error::Error>{
-        utils::request_endpoint(self, format!("/users/{}/orgs", username))
+    fn get_users_username_orgs(&self, username: String) -> Result<Vec<Organization>, error::Error>{
+        self.request(Method::Get, "/users")
+            .segment(username)
+            .path("/orgs")
+            .send()
+            .map(|response| response.data)
     }
 
-    fn get_orgs_org(&mut self, org: String) -> Result<Organization, error::Error>{
-        utils::request_endpoint(self, format!("/orgs/{}", org))
+    fn get_orgs_org(&self, org: String) -> Result<Organization, error::Error>{
+        self.request(Method::Get, "/orgs")
+            .segment(org)
+            .send()
+            .map(|response| response.data)
     }
 
-    fn patch_orgs_org(&mut self, org: String, org_param: OrganizationEditParam) -> Result<Organization, error::Error>{
-
-        //Create body
-        let body_data = try!(serde_json::to_string(&org_param).map_err(error::Error::Parsing));
-
-        let mut response     = try!(self.patch_body(format!("/orgs/{}", org), None, body_data));
-        let     response_str = try!(Client::response_to_string(&mut response));
-        serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing)
+    fn patch_orgs_org(&self, org: String, org_param: OrganizationEditParam) -> Result<Organization, error::Error>{
+        self.request(Method::Patch, "/orgs")
+            .segment(org)
+            .json(&org_param)
+            .send()
+            .map(|response| response.data)
     }
 }
 
@@ -0,0 +1,239 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Path
+//!
+//! Escaping of the values interpolated in the paths of
+//! endpoints and validated names for the owners,
+//! repositories and references used in those paths.
+//!
+//! ### Example
+//!
+//! ```rust
+//!
+//! extern crate rustyhub;
+//!
+//! use rustyhub::path::{Owner, RefName, RepoName};
+//!
+//! fn main() {
+//!
+//!     let owner: Owner    = "rust-lang".parse().unwrap();
+//!     let repo:  RepoName = "rust".parse().unwrap();
+//!     let head:  RefName  = "heads/feature/ünïcode".parse().unwrap();
+//!
+//!     assert!(head.to_path() == "heads/feature/%C3%BCn%C3%AFcode");
+//!     assert!("bad owner".parse::<Owner>().is_err());
+//!     assert!("..".parse::<RepoName>().is_err());
+//! }
+//!
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use url::percent_encoding::{utf8_percent_encode, PATH_SEGMENT_ENCODE_SET};
+
+use error;
+use request::QueryValue;
+
+/// Escapes a value used as a single segment of a path, ex.
+/// a label name, the '/' it contains are escaped.
+pub fn escape_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, PATH_SEGMENT_ENCODE_SET).collect()
+}
+
+/// Escapes a value used as the tail of a path, ex. a
+/// reference or a file path, the '/' it contains are kept
+/// as separators.
+pub fn escape_tail(tail: &str) -> String {
+    tail.split('/').map(escape_segment).collect::<Vec<String>>().join("/")
+}
+
+fn is_alphanumeric(c: char) -> bool {
+    (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9')
+}
+
+fn invalid(kind: &str, name: &str, reason: &str) -> error::Error {
+    error::Error::InvalidName(error::InvalidNameError {
+        kind:   kind.to_string(),
+        name:   name.to_string(),
+        reason: reason.to_string()
+    })
+}
+
+/// Login of the user or organization owning a repository.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Owner(String);
+
+impl Owner {
+
+    /// Validates the login of an owner, it must only
+    /// contain alphanumeric characters, '-' or '_' and must
+    /// not begin with '-'.
+    pub fn new(name: &str) -> Result<Owner, error::Error> {
+        if name.is_empty() {
+            return Err(invalid("owner", name, "it is empty"));
+        }
+        if name.starts_with('-') {
+            return Err(invalid("owner", name, "it begins with '-'"));
+        }
+        if !name.chars().all(|c| is_alphanumeric(c) || c == '-' || c == '_') {
+            return Err(invalid("owner", name, "it contains characters other than alphanumerics, '-' and '_'"));
+        }
+        Ok(Owner(name.to_string()))
+    }
+
+    /// Returns the login.
+    pub fn as_str(&self) -> &str {
+        &self.0[..]
+    }
+
+    /// Returns the login escaped for a path.
+    pub fn to_path(&self) -> String {
+        escape_segment(&self.0[..])
+    }
+}
+
+/// Name of a repository.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RepoName(String);
+
+impl RepoName {
+
+    /// Validates the name of a repository, it must be at
+    /// most 100 characters long, must only contain
+    /// alphanumeric characters, '-', '_' or '.' and must not
+    /// be "." or "..".
+    pub fn new(name: &str) -> Result<RepoName, error::Error> {
+        if name.is_empty() {
+            return Err(invalid("repository", name, "it is empty"));
+        }
+        if name.len() > 100 {
+            return Err(invalid("repository", name, "it is longer than 100 characters"));
+        }
+        if name == "." || name == ".." {
+            return Err(invalid("repository", name, "it is a relative path"));
+        }
+        if !name.chars().all(|c| is_alphanumeric(c) || c == '-' || c == '_' || c == '.') {
+            return Err(invalid("repository", name, "it contains characters other than alphanumerics, '-', '_' and '.'"));
+        }
+        Ok(RepoName(name.to_string()))
+    }
+
+    /// Returns the name.
+    pub fn as_str(&self) -> &str {
+        &self.0[..]
+    }
+
+    /// Returns the name escaped for a path.
+    pub fn to_path(&self) -> String {
+        escape_segment(&self.0[..])
+    }
+}
+
+/// Name of a git reference, ex. "heads/master" or
+/// "refs/tags/v1.0".
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RefName(String);
+
+impl RefName {
+
+    /// Validates the name of a reference following the
+    /// rules of `git check-ref-format`.
+    pub fn new(name: &str) -> Result<RefName, error::Error> {
+        if name.is_empty() {
+            return Err(invalid("reference", name, "it is empty"));
+        }
+        if name == "@" {
+            return Err(invalid("reference", name, "it is \"@\""));
+        }
+        if name.ends_with('/') || name.ends_with('.') {
+            return Err(invalid("reference", name, "it ends with '/' or '.'"));
+        }
+        if name.contains("..") || name.contains("@{") || name.contains("//") {
+            return Err(invalid("reference", name, "it contains \"..\", \"@{\" or \"//\""));
+        }
+        if name.chars().any(|c| c.is_control() || c == ' ' || "~^:?*[\\".contains(c)) {
+            return Err(invalid("reference", name, "it contains a control character, a space or one of ~^:?*[\\"));
+        }
+        if name.split('/').any(|component| component.starts_with('.') || component.ends_with(".lock")) {
+            return Err(invalid("reference", name, "a component begins with '.' or ends with \".lock\""));
+        }
+        Ok(RefName(name.to_string()))
+    }
+
+    /// Returns the name.
+    pub fn as_str(&self) -> &str {
+        &self.0[..]
+    }
+
+    /// Returns the name escaped for a path, its '/' are
+    /// kept as separators.
+    pub fn to_path(&self) -> String {
+        escape_tail(&self.0[..])
+    }
+}
+
+macro_rules! name_impls {
+    ($name: ident) => {
+        impl FromStr for $name {
+            type Err = error::Error;
+            fn from_str(name: &str) -> Result<$name, error::Error> {
+                $name::new(name)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0[..])
+            }
+        }
+
+        impl QueryValue for $name {
+            fn to_query(&self) -> String {
+                self.0.clone()
+            }
+        }
+    }
+}
+
+name_impls!(Owner);
+name_impls!(RepoName);
+name_impls!(RefName);
+
+#[cfg(test)]
+mod path_test {
+
+    use super::{escape_segment, escape_tail, Owner, RefName, RepoName};
+
+    #[test]
+    fn escape() {
+        assert!(escape_segment("help wanted") == "help%20wanted");
+        assert!(escape_segment("#1/2?") == "%231%2F2%3F");
+        assert!(escape_tail("docs/read me.md") == "docs/read%20me.md");
+    }
+
+    #[test]
+    fn names() {
+        assert!(Owner::new("rust-lang").is_ok());
+        assert!(Owner::new("-rust").is_err());
+        assert!(Owner::new("rust/lang").is_err());
+        assert!(Owner::new("").is_err());
+
+        assert!(RepoName::new("rust.vim").is_ok());
+        assert!(RepoName::new("..").is_err());
+        assert!(RepoName::new("a#b").is_err());
+
+        assert!(RefName::new("heads/feature/x").is_ok());
+        assert!(RefName::new("refs/tags/v1.0").is_ok());
+        assert!(RefName::new("heads/a..b").is_err());
+        assert!(RefName::new("heads/.hidden").is_err());
+        assert!(RefName::new("heads/x.lock").is_err());
+        assert!(RefName::new("heads/a b").is_err());
+        assert!(RefName::new("heads/").is_err());
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Previews
+//!
+//! Preview media types of the API. Each preview is enabled
+//! with its cargo feature, ex. `preview-reactions`, or all
+//! of them with the `previews` feature. Endpoints requiring
+//! a preview send its media type, a preview can also be
+//! sent with every request of a client with
+//! `Client::enable_preview` or with a single request with
+//! `RequestBuilder::preview`.
+//!
+//! Reference: https://developer.github.com/v3/previews/
+
+use hyper::header::{Accept, Headers, qitem};
+use hyper::mime::{Mime, TopLevel, SubLevel};
+
+/// Preview media types of the API.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Preview {
+    /// Check runs and check suites.
+    #[cfg(feature = "preview-checks")]
+    Checks,
+    /// Environments, logs and additional states of
+    /// deployments.
+    #[cfg(feature = "preview-deployments")]
+    Deployments,
+    /// Commit and tag signature verification.
+    #[cfg(feature = "preview-signatures")]
+    Signatures,
+    /// Integrations.
+    #[cfg(feature = "preview-integrations")]
+    Integrations,
+    /// Licenses of repositories.
+    #[cfg(feature = "preview-licenses")]
+    Licenses,
+    /// Organization and user migrations.
+    #[cfg(feature = "preview-migrations")]
+    Migrations,
+    /// Projects.
+    #[cfg(feature = "preview-projects")]
+    Projects,
+    /// Reactions.
+    #[cfg(feature = "preview-reactions")]
+    Reactions,
+    /// Source imports.
+    #[cfg(feature = "preview-source-imports")]
+    SourceImports,
+    /// Timeline of an issue.
+    #[cfg(feature = "preview-timeline")]
+    Timeline
+}
+
+impl Preview {
+
+    /// Returns the media type of the preview without its
+    /// format, ex. "vnd.github.squirrel-girl-preview".
+    pub fn media_type(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "preview-checks")]
+            Preview::Checks        => "vnd.github.antiope-preview",
+            #[cfg(feature = "preview-deployments")]
+            Preview::Deployments   => "vnd.github.ant-man-preview",
+            #[cfg(feature = "preview-signatures")]
+            Preview::Signatures    => "vnd.github.cryptographer-preview",
+            #[cfg(feature = "preview-integrations")]
+            Preview::Integrations  => "vnd.github.machine-man-preview",
+            #[cfg(feature = "preview-licenses")]
+            Preview::Licenses      => "vnd.github.drax-preview",
+            #[cfg(feature = "preview-migrations")]
+            Preview::Migrations    => "vnd.github.wyandotte-preview",
+            #[cfg(feature = "preview-projects")]
+            Preview::Projects      => "vnd.github.inertia-preview",
+            #[cfg(feature = "preview-reactions")]
+            Preview::Reactions     => "vnd.github.squirrel-girl-preview",
+            #[cfg(feature = "preview-source-imports")]
+            Preview::SourceImports => "vnd.github.barred-rock-preview",
+            #[cfg(feature = "preview-timeline")]
+            Preview::Timeline      => "vnd.github.mockingbird-preview"
+        }
+    }
+
+    /// Returns the media type of the preview as a `Mime`,
+    /// in the JSON format.
+    pub fn mime(&self) -> Mime {
+        json_mime(self.media_type())
+    }
+}
+
+/// Returns a media type in the JSON format, ex.
+/// "application/vnd.github.squirrel-girl-preview+json".
+fn json_mime(media_type: &str) -> Mime {
+    Mime(TopLevel::Application, SubLevel::Ext(format!("{}+json", media_type)), vec![])
+}
+
+/// Adds the media type of a preview to the Accept header,
+/// the media types already accepted are kept.
+pub fn add_preview(headers: &mut Headers, preview: Preview) {
+    add_mime(headers, preview.mime())
+}
+
+fn add_mime(headers: &mut Headers, mime: Mime) {
+
+    let mut items = match headers.get::<Accept>() {
+        Some(&Accept(ref items)) => items.clone(),
+        None                     => Vec::new()
+    };
+
+    if !items.iter().any(|item| item.item == mime) {
+        items.push(qitem(mime));
+    }
+
+    headers.set(Accept(items));
+}
+
+#[cfg(test)]
+mod preview_test {
+
+    use hyper::header::{Accept, Headers, qitem};
+    use hyper::mime::{Mime, TopLevel, SubLevel};
+
+    use super::{add_mime, json_mime};
+
+    #[test]
+    fn mime() {
+        let mime = json_mime("vnd.github.squirrel-girl-preview");
+        assert!(mime.to_string() == "application/vnd.github.squirrel-girl-preview+json");
+    }
+
+    #[test]
+    fn add() {
+        let mut headers = Headers::new();
+        headers.set(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Json, vec![]))]));
+        add_mime(&mut headers, json_mime("vnd.github.squirrel-girl-preview"));
+        add_mime(&mut headers, json_mime("vnd.github.squirrel-girl-preview"));
+
+        let accept = headers.get::<Accept>().unwrap();
+        assert!(accept.len() == 2);
+        assert!(accept[0].item.to_string() == "application/json");
+    }
+
+    #[cfg(feature = "preview-reactions")]
+    #[test]
+    fn add_preview() {
+        use super::{add_preview, Preview};
+
+        let mut headers = Headers::new();
+        add_preview(&mut headers, Preview::Reactions);
+        add_preview(&mut headers, Preview::Reactions);
+
+        let accept = headers.get::<Accept>().unwrap();
+        assert!(accept.len() == 1);
+        assert!(accept[0].item.to_string() == "application/vnd.github.squirrel-girl-preview+json");
+    }
+}
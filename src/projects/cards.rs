@@ -0,0 +1,306 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Cards
+//!
+//! These are the responses and API call functions related
+//! to the project card endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/projects/cards/
+
+use hyper::method::Method;
+use serde::{Serialize, Serializer};
+use serde_json;
+
+use client::Client;
+use common::{DateTime, User};
+use error;
+use issues::Issue;
+use preview::Preview;
+
+/// Return type for project card endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ProjectCard {
+    pub url:         Option<String>,
+    pub column_url:  String,
+    /// URL of the issue or the pull request of the card,
+    /// `None` for a note.
+    pub content_url: Option<String>,
+    pub id:          u64,
+    pub note:        Option<String>,
+    pub creator:     Option<User>,
+    pub archived:    Option<bool>,
+    pub created_at:  DateTime,
+    pub updated_at:  DateTime
+}
+
+string_enum! {
+    /// Type of the content of a card.
+    pub enum ContentType {
+        Issue       => "Issue",
+        PullRequest => "PullRequest"
+    }
+}
+
+/// Content of a card to create.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CardContent {
+    /// A note.
+    Note(String),
+    /// An issue, by its ID rather than its number.
+    Issue(u64),
+    /// A pull request, by its ID rather than its number.
+    PullRequest(u64)
+}
+
+/// Parameters for the editing of a card, only the fields
+/// that are set are changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct CardEdit {
+    /// Note of the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note:     Option<String>,
+    /// Whether the card is archived.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>
+}
+
+impl CardEdit {
+
+    /// Creates an edit that leaves the card unchanged.
+    pub fn new() -> CardEdit {
+        CardEdit::default()
+    }
+
+    /// Sets the note of the card.
+    pub fn note(mut self, note: &str) -> CardEdit {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    /// Archives or restores the card.
+    pub fn archived(mut self, archived: bool) -> CardEdit {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+/// Position to move a card to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CardPosition {
+    /// Top of the column.
+    Top,
+    /// Bottom of the column.
+    Bottom,
+    /// After the card with this ID.
+    After(u64)
+}
+
+impl Serialize for CardPosition {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        match *self {
+            CardPosition::Top       => serializer.serialize_str("top"),
+            CardPosition::Bottom    => serializer.serialize_str("bottom"),
+            CardPosition::After(id) => serializer.serialize_str(&format!("after:{}", id)[..])
+        }
+    }
+}
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct CardParam {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note:         Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_id:   Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<ContentType>
+}
+
+impl From<CardContent> for CardParam {
+    fn from(content: CardContent) -> CardParam {
+        let (note, content_id, content_type) = match content {
+            CardContent::Note(note)      => (Some(note), None, None),
+            CardContent::Issue(id)       => (None, Some(id), Some(ContentType::Issue)),
+            CardContent::PullRequest(id) => (None, Some(id), Some(ContentType::PullRequest))
+        };
+        CardParam {
+            note:         note,
+            content_id:   content_id,
+            content_type: content_type
+        }
+    }
+}
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct MoveParam {
+    position:  CardPosition,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column_id: Option<u64>
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait ProjectCardsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/projects/cards/#list-project-cards)\]
+    /// Returns the cards of a column.
+    /// ## Endpoint:
+    /// GET /projects/columns/:column_id/cards
+    /// ## Parameters
+    /// * `column_id`: ID of the column.
+    fn get_projects_columns_column_id_cards(&self, column_id: u64) -> Result<Vec<ProjectCard>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/cards/#get-a-project-card)\]
+    /// Returns a card.
+    /// ## Endpoint:
+    /// GET /projects/columns/cards/:id
+    /// ## Parameters
+    /// * `id`: ID of the card.
+    fn get_projects_columns_cards_id(&self, id: u64) -> Result<ProjectCard, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/cards/#create-a-project-card)\]
+    /// Creates a card.
+    /// ## Endpoint:
+    /// POST /projects/columns/:column_id/cards
+    /// ## Parameters
+    /// * `column_id`: ID of the column.
+    /// * `content`: A note, or the issue or the pull
+    /// request the card is created from.
+    fn post_projects_columns_column_id_cards(&self, column_id: u64, content: CardContent) -> Result<ProjectCard, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/cards/#update-a-project-card)\]
+    /// Updates a card.
+    /// ## Endpoint:
+    /// PATCH /projects/columns/cards/:id
+    /// ## Parameters
+    /// * `id`: ID of the card.
+    /// * `card`: Edit parameters, see `CardEdit`.
+    fn patch_projects_columns_cards_id(&self, id: u64, card: CardEdit) -> Result<ProjectCard, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/cards/#delete-a-project-card)\]
+    /// Deletes a card.
+    /// ## Endpoint:
+    /// DELETE /projects/columns/cards/:id
+    /// ## Parameters
+    /// * `id`: ID of the card.
+    fn delete_projects_columns_cards_id(&self, id: u64) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/cards/#move-a-project-card)\]
+    /// Moves a card within its column or to another column.
+    /// ## Endpoint:
+    /// POST /projects/columns/cards/:id/moves
+    /// ## Parameters
+    /// * `id`: ID of the card.
+    /// * `position`: Position of the card in the column.
+    /// * `column_id`: ID of the column to move the card to,
+    /// `None` to keep it in its column.
+    fn post_projects_columns_cards_id_moves(&self, id: u64, position: CardPosition, column_id: Option<u64>) -> Result<(), error::Error>;
+
+    /// Returns the issue or the pull request, as an issue,
+    /// of a card by requesting its `content_url`, `None`
+    /// for a note.
+    /// ## Parameters
+    /// * `card`: The card.
+    fn get_project_card_content(&self, card: &ProjectCard) -> Result<Option<Issue>, error::Error>;
+}
+
+impl ProjectCardsExt for Client {
+
+    fn get_projects_columns_column_id_cards(&self, column_id: u64) -> Result<Vec<ProjectCard>, error::Error> {
+        self.request(Method::Get, "/projects/columns")
+            .segment(column_id)
+            .path("/cards")
+            .query("per_page", 100u32)
+            .preview(Preview::Projects)
+            .send_all()
+    }
+
+    fn get_projects_columns_cards_id(&self, id: u64) -> Result<ProjectCard, error::Error> {
+        self.request(Method::Get, "/projects/columns/cards")
+            .segment(id)
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_projects_columns_column_id_cards(&self, column_id: u64, content: CardContent) -> Result<ProjectCard, error::Error> {
+        self.request(Method::Post, "/projects/columns")
+            .segment(column_id)
+            .path("/cards")
+            .json(&CardParam::from(content))
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_projects_columns_cards_id(&self, id: u64, card: CardEdit) -> Result<ProjectCard, error::Error> {
+        self.request(Method::Patch, "/projects/columns/cards")
+            .segment(id)
+            .json(&card)
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_projects_columns_cards_id(&self, id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/projects/columns/cards")
+            .segment(id)
+            .preview(Preview::Projects)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn post_projects_columns_cards_id_moves(&self, id: u64, position: CardPosition, column_id: Option<u64>) -> Result<(), error::Error> {
+        self.request(Method::Post, "/projects/columns/cards")
+            .segment(id)
+            .path("/moves")
+            .json(&MoveParam {
+                position:  position,
+                column_id: column_id
+            })
+            .preview(Preview::Projects)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_project_card_content(&self, card: &ProjectCard) -> Result<Option<Issue>, error::Error> {
+
+        let url = match card.content_url {
+            Some(ref url) => url.clone(),
+            None          => return Ok(None)
+        };
+
+        let mut response     = try!(self.request_url(Method::Get, url, None));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..]).map(Some).map_err(error::Error::Parsing)
+    }
+}
+
+#[cfg(test)]
+mod cards_test {
+
+    use serde_json;
+
+    use super::{CardContent, CardParam, CardPosition, MoveParam};
+
+    #[test]
+    fn params() {
+        let card = CardParam::from(CardContent::Issue(42));
+        assert!(serde_json::to_string(&card).unwrap() == r#"{"content_id":42,"content_type":"Issue"}"#);
+
+        let card_move = MoveParam {
+            position:  CardPosition::After(7),
+            column_id: Some(3)
+        };
+        assert!(serde_json::to_string(&card_move).unwrap() == r#"{"position":"after:7","column_id":3}"#);
+    }
+}
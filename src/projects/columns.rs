@@ -0,0 +1,190 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Columns
+//!
+//! These are the responses and API call functions related
+//! to the project column endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/projects/columns/
+
+use hyper::method::Method;
+use serde::{Serialize, Serializer};
+
+use client::Client;
+use common::DateTime;
+use error;
+use preview::Preview;
+
+/// Return type for project column endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ProjectColumn {
+    pub url:         Option<String>,
+    pub project_url: String,
+    pub cards_url:   Option<String>,
+    pub id:          u64,
+    pub name:        String,
+    pub created_at:  DateTime,
+    pub updated_at:  DateTime
+}
+
+/// Position to move a column to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnPosition {
+    /// First column of the project.
+    First,
+    /// Last column of the project.
+    Last,
+    /// After the column with this ID.
+    After(u64)
+}
+
+impl Serialize for ColumnPosition {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        match *self {
+            ColumnPosition::First     => serializer.serialize_str("first"),
+            ColumnPosition::Last      => serializer.serialize_str("last"),
+            ColumnPosition::After(id) => serializer.serialize_str(&format!("after:{}", id)[..])
+        }
+    }
+}
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct NameParam {
+    name: String
+}
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct MoveParam {
+    position: ColumnPosition
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait ProjectColumnsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/projects/columns/#list-project-columns)\]
+    /// Returns the columns of a project.
+    /// ## Endpoint:
+    /// GET /projects/:project_id/columns
+    /// ## Parameters
+    /// * `project_id`: ID of the project.
+    fn get_projects_project_id_columns(&self, project_id: u64) -> Result<Vec<ProjectColumn>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/columns/#get-a-project-column)\]
+    /// Returns a column.
+    /// ## Endpoint:
+    /// GET /projects/columns/:id
+    /// ## Parameters
+    /// * `id`: ID of the column.
+    fn get_projects_columns_id(&self, id: u64) -> Result<ProjectColumn, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/columns/#create-a-project-column)\]
+    /// Creates a column.
+    /// ## Endpoint:
+    /// POST /projects/:project_id/columns
+    /// ## Parameters
+    /// * `project_id`: ID of the project.
+    /// * `name`: Name of the column.
+    fn post_projects_project_id_columns(&self, project_id: u64, name: String) -> Result<ProjectColumn, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/columns/#update-a-project-column)\]
+    /// Renames a column.
+    /// ## Endpoint:
+    /// PATCH /projects/columns/:id
+    /// ## Parameters
+    /// * `id`: ID of the column.
+    /// * `name`: New name of the column.
+    fn patch_projects_columns_id(&self, id: u64, name: String) -> Result<ProjectColumn, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/columns/#delete-a-project-column)\]
+    /// Deletes a column.
+    /// ## Endpoint:
+    /// DELETE /projects/columns/:id
+    /// ## Parameters
+    /// * `id`: ID of the column.
+    fn delete_projects_columns_id(&self, id: u64) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/columns/#move-a-project-column)\]
+    /// Moves a column within its project.
+    /// ## Endpoint:
+    /// POST /projects/columns/:id/moves
+    /// ## Parameters
+    /// * `id`: ID of the column.
+    /// * `position`: Position to move the column to.
+    fn post_projects_columns_id_moves(&self, id: u64, position: ColumnPosition) -> Result<(), error::Error>;
+}
+
+impl ProjectColumnsExt for Client {
+
+    fn get_projects_project_id_columns(&self, project_id: u64) -> Result<Vec<ProjectColumn>, error::Error> {
+        self.request(Method::Get, "/projects")
+            .segment(project_id)
+            .path("/columns")
+            .query("per_page", 100u32)
+            .preview(Preview::Projects)
+            .send_all()
+    }
+
+    fn get_projects_columns_id(&self, id: u64) -> Result<ProjectColumn, error::Error> {
+        self.request(Method::Get, "/projects/columns")
+            .segment(id)
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_projects_project_id_columns(&self, project_id: u64, name: String) -> Result<ProjectColumn, error::Error> {
+        self.request(Method::Post, "/projects")
+            .segment(project_id)
+            .path("/columns")
+            .json(&NameParam {
+                name: name
+            })
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_projects_columns_id(&self, id: u64, name: String) -> Result<ProjectColumn, error::Error> {
+        self.request(Method::Patch, "/projects/columns")
+            .segment(id)
+            .json(&NameParam {
+                name: name
+            })
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_projects_columns_id(&self, id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/projects/columns")
+            .segment(id)
+            .preview(Preview::Projects)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn post_projects_columns_id_moves(&self, id: u64, position: ColumnPosition) -> Result<(), error::Error> {
+        self.request(Method::Post, "/projects/columns")
+            .segment(id)
+            .path("/moves")
+            .json(&MoveParam {
+                position: position
+            })
+            .preview(Preview::Projects)
+            .send_empty()
+            .map(|_| ())
+    }
+}
+
+//TODO: TESTS
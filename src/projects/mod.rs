@@ -0,0 +1,260 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Projects
+//!
+//! Modules for subsections of Projects in the Github API
+//! docs and endpoints for Projects, available with the
+//! `preview-projects` feature.
+//!
+//! Reference: https://developer.github.com/v3/projects/
+
+use hyper::method::Method;
+
+use client::Client;
+use common::{DateTime, Edit, User};
+use error;
+use issues::{State, StateFilter};
+use path::{Owner, RepoName};
+use preview::Preview;
+
+/// Endpoints for project cards.
+pub mod cards;
+/// Endpoints for project columns.
+pub mod columns;
+
+/// Return type for Project endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Project {
+    pub owner_url:   String,
+    pub url:         String,
+    pub html_url:    Option<String>,
+    pub columns_url: Option<String>,
+    pub id:          u64,
+    pub name:        String,
+    pub body:        Option<String>,
+    pub number:      u64,
+    pub state:       Option<State>,
+    pub creator:     User,
+    pub created_at:  DateTime,
+    pub updated_at:  DateTime
+}
+
+/// Parameters for the creation of a project.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProjectParam {
+    /// Name of the project.
+    pub name: String,
+    /// Description of the project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>
+}
+
+impl ProjectParam {
+
+    /// Creates the parameters of a project without
+    /// description.
+    pub fn new(name: &str) -> ProjectParam {
+        ProjectParam {
+            name: name.to_string(),
+            body: None
+        }
+    }
+}
+
+/// Parameters for the editing of a project, only the fields
+/// that are set are changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ProjectEdit {
+    /// Name of the project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:  Option<String>,
+    /// Description of the project.
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub body:  Edit<String>,
+    /// State of the project, `State::Open` or
+    /// `State::Closed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>
+}
+
+impl ProjectEdit {
+
+    /// Creates an edit that leaves the project unchanged.
+    pub fn new() -> ProjectEdit {
+        ProjectEdit::default()
+    }
+
+    /// Sets the name of the project.
+    pub fn name(mut self, name: &str) -> ProjectEdit {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description of the project.
+    pub fn body(mut self, body: &str) -> ProjectEdit {
+        self.body = Edit::Set(body.to_string());
+        self
+    }
+
+    /// Removes the description of the project.
+    pub fn clear_body(mut self) -> ProjectEdit {
+        self.body = Edit::Null;
+        self
+    }
+
+    /// Sets the state of the project.
+    pub fn state(mut self, state: State) -> ProjectEdit {
+        self.state = Some(state);
+        self
+    }
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait ProjectsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/projects/#list-repository-projects)\]
+    /// Returns the projects of a repository.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/projects
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `state`: Default: `StateFilter::Open`, indicates
+    /// the state of the projects to return.
+    fn get_repos_owner_repo_projects(&self, owner: Owner, repo: RepoName, state: Option<StateFilter>) -> Result<Vec<Project>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/#list-organization-projects)\]
+    /// Returns the projects of an organization.
+    /// ## Endpoint:
+    /// GET /orgs/:org/projects
+    /// ## Parameters
+    /// * `org`: Login of the organization.
+    /// * `state`: Default: `StateFilter::Open`, indicates
+    /// the state of the projects to return.
+    fn get_orgs_org_projects(&self, org: Owner, state: Option<StateFilter>) -> Result<Vec<Project>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/#get-a-project)\]
+    /// Returns a project.
+    /// ## Endpoint:
+    /// GET /projects/:id
+    /// ## Parameters
+    /// * `id`: ID of the project.
+    fn get_projects_id(&self, id: u64) -> Result<Project, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/#create-a-repository-project)\]
+    /// Creates a project for a repository.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/projects
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `project`: Parameters of the project.
+    fn post_repos_owner_repo_projects(&self, owner: Owner, repo: RepoName, project: ProjectParam) -> Result<Project, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/#create-an-organization-project)\]
+    /// Creates a project for an organization.
+    /// ## Endpoint:
+    /// POST /orgs/:org/projects
+    /// ## Parameters
+    /// * `org`: Login of the organization.
+    /// * `project`: Parameters of the project.
+    fn post_orgs_org_projects(&self, org: Owner, project: ProjectParam) -> Result<Project, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/#update-a-project)\]
+    /// Updates a project.
+    /// ## Endpoint:
+    /// PATCH /projects/:id
+    /// ## Parameters
+    /// * `id`: ID of the project.
+    /// * `project`: Edit parameters, see `ProjectEdit`.
+    fn patch_projects_id(&self, id: u64, project: ProjectEdit) -> Result<Project, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/projects/#delete-a-project)\]
+    /// Deletes a project.
+    /// ## Endpoint:
+    /// DELETE /projects/:id
+    /// ## Parameters
+    /// * `id`: ID of the project.
+    fn delete_projects_id(&self, id: u64) -> Result<(), error::Error>;
+}
+
+impl ProjectsExt for Client {
+
+    fn get_repos_owner_repo_projects(&self, owner: Owner, repo: RepoName, state: Option<StateFilter>) -> Result<Vec<Project>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/projects")
+            .query_opt("state", state)
+            .query("per_page", 100u32)
+            .preview(Preview::Projects)
+            .send_all()
+    }
+
+    fn get_orgs_org_projects(&self, org: Owner, state: Option<StateFilter>) -> Result<Vec<Project>, error::Error> {
+        self.request(Method::Get, "/orgs")
+            .segment(org)
+            .path("/projects")
+            .query_opt("state", state)
+            .query("per_page", 100u32)
+            .preview(Preview::Projects)
+            .send_all()
+    }
+
+    fn get_projects_id(&self, id: u64) -> Result<Project, error::Error> {
+        self.request(Method::Get, "/projects")
+            .segment(id)
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_projects(&self, owner: Owner, repo: RepoName, project: ProjectParam) -> Result<Project, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/projects")
+            .json(&project)
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_orgs_org_projects(&self, org: Owner, project: ProjectParam) -> Result<Project, error::Error> {
+        self.request(Method::Post, "/orgs")
+            .segment(org)
+            .path("/projects")
+            .json(&project)
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_projects_id(&self, id: u64, project: ProjectEdit) -> Result<Project, error::Error> {
+        self.request(Method::Patch, "/projects")
+            .segment(id)
+            .json(&project)
+            .preview(Preview::Projects)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_projects_id(&self, id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/projects")
+            .segment(id)
+            .preview(Preview::Projects)
+            .send_empty()
+            .map(|_| ())
+    }
+}
+
+//TODO: TESTS
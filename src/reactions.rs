@@ -0,0 +1,291 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Reactions
+//!
+//! These are the responses and API call functions related
+//! to the reactions endpoints of the API, available with
+//! the `preview-reactions` feature.
+//!
+//! Reference: https://developer.github.com/v3/reactions/
+
+use hyper::method::Method;
+
+use client::Client;
+use common::{DateTime, User};
+use error;
+use path::{Owner, RepoName};
+use preview::Preview;
+
+string_enum! {
+    /// Content of a reaction.
+    pub enum ReactionContent {
+        /// :+1:
+        PlusOne  => "+1",
+        /// :-1:
+        MinusOne => "-1",
+        Laugh    => "laugh",
+        Confused => "confused",
+        Heart    => "heart",
+        Hooray   => "hooray"
+    }
+}
+
+/// Response element to the reactions endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Reaction {
+    pub id:         u64,
+    pub user:       User,
+    pub content:    ReactionContent,
+    pub created_at: DateTime
+}
+
+/// Internal parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct ContentParam {
+    content: ReactionContent
+}
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait ReactionsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#list-reactions-for-a-commit-comment)\]
+    /// Returns the reactions to a commit comment.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/comments/:id/reactions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the commit comment.
+    /// * `content`: Only return the reactions with this
+    /// content.
+    fn get_repos_owner_repo_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: Option<ReactionContent>) -> Result<Vec<Reaction>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#create-reaction-for-a-commit-comment)\]
+    /// Reacts to a commit comment, the existing reaction is
+    /// returned if the user already reacted with this
+    /// content.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/comments/:id/reactions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the commit comment.
+    /// * `content`: Content of the reaction.
+    fn post_repos_owner_repo_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: ReactionContent) -> Result<Reaction, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#list-reactions-for-an-issue)\]
+    /// Returns the reactions to an issue.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/issues/:number/reactions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `number`: Number of the issue.
+    /// * `content`: Only return the reactions with this
+    /// content.
+    fn get_repos_owner_repo_issues_number_reactions(&self, owner: Owner, repo: RepoName, number: u64, content: Option<ReactionContent>) -> Result<Vec<Reaction>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#create-reaction-for-an-issue)\]
+    /// Reacts to an issue, the existing reaction is
+    /// returned if the user already reacted with this
+    /// content.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/issues/:number/reactions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `number`: Number of the issue.
+    /// * `content`: Content of the reaction.
+    fn post_repos_owner_repo_issues_number_reactions(&self, owner: Owner, repo: RepoName, number: u64, content: ReactionContent) -> Result<Reaction, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#list-reactions-for-an-issue-comment)\]
+    /// Returns the reactions to an issue comment.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/issues/comments/:id/reactions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the issue comment.
+    /// * `content`: Only return the reactions with this
+    /// content.
+    fn get_repos_owner_repo_issues_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: Option<ReactionContent>) -> Result<Vec<Reaction>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#create-reaction-for-an-issue-comment)\]
+    /// Reacts to an issue comment, the existing reaction is
+    /// returned if the user already reacted with this
+    /// content.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/issues/comments/:id/reactions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the issue comment.
+    /// * `content`: Content of the reaction.
+    fn post_repos_owner_repo_issues_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: ReactionContent) -> Result<Reaction, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#list-reactions-for-a-pull-request-review-comment)\]
+    /// Returns the reactions to a pull request review comment.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/pulls/comments/:id/reactions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the review comment.
+    /// * `content`: Only return the reactions with this
+    /// content.
+    fn get_repos_owner_repo_pulls_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: Option<ReactionContent>) -> Result<Vec<Reaction>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#create-reaction-for-a-pull-request-review-comment)\]
+    /// Reacts to a pull request review comment, the existing reaction is
+    /// returned if the user already reacted with this
+    /// content.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/pulls/comments/:id/reactions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the review comment.
+    /// * `content`: Content of the reaction.
+    fn post_repos_owner_repo_pulls_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: ReactionContent) -> Result<Reaction, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/reactions/#delete-a-reaction)\]
+    /// Deletes a reaction.
+    /// ## Endpoint:
+    /// DELETE /reactions/:id
+    /// ## Parameters
+    /// * `id`: ID of the reaction.
+    fn delete_reactions_id(&self, id: u64) -> Result<(), error::Error>;
+}
+
+impl ReactionsExt for Client {
+
+    fn get_repos_owner_repo_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: Option<ReactionContent>) -> Result<Vec<Reaction>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/comments")
+            .segment(id)
+            .path("/reactions")
+            .query_opt("content", content)
+            .preview(Preview::Reactions)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: ReactionContent) -> Result<Reaction, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/comments")
+            .segment(id)
+            .path("/reactions")
+            .json(&ContentParam {
+                content: content
+            })
+            .preview(Preview::Reactions)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_issues_number_reactions(&self, owner: Owner, repo: RepoName, number: u64, content: Option<ReactionContent>) -> Result<Vec<Reaction>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .path("/reactions")
+            .query_opt("content", content)
+            .preview(Preview::Reactions)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_issues_number_reactions(&self, owner: Owner, repo: RepoName, number: u64, content: ReactionContent) -> Result<Reaction, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues")
+            .segment(number)
+            .path("/reactions")
+            .json(&ContentParam {
+                content: content
+            })
+            .preview(Preview::Reactions)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_issues_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: Option<ReactionContent>) -> Result<Vec<Reaction>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues/comments")
+            .segment(id)
+            .path("/reactions")
+            .query_opt("content", content)
+            .preview(Preview::Reactions)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_issues_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: ReactionContent) -> Result<Reaction, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/issues/comments")
+            .segment(id)
+            .path("/reactions")
+            .json(&ContentParam {
+                content: content
+            })
+            .preview(Preview::Reactions)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_pulls_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: Option<ReactionContent>) -> Result<Vec<Reaction>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/pulls/comments")
+            .segment(id)
+            .path("/reactions")
+            .query_opt("content", content)
+            .preview(Preview::Reactions)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_pulls_comments_id_reactions(&self, owner: Owner, repo: RepoName, id: u64, content: ReactionContent) -> Result<Reaction, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/pulls/comments")
+            .segment(id)
+            .path("/reactions")
+            .json(&ContentParam {
+                content: content
+            })
+            .preview(Preview::Reactions)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_reactions_id(&self, id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/reactions")
+            .segment(id)
+            .preview(Preview::Reactions)
+            .send_empty()
+            .map(|_| ())
+    }
+}
+
+//TODO: TESTS
@@ -0,0 +1,847 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Branches
+//!
+//! These are the responses and API call functions related
+//! to the branches endpoints of the API. The protection of
+//! a branch is read and written with the same
+//! `BranchProtection`, so a desired protection can be
+//! compared with the one of a branch.
+//!
+//! Reference: https://developer.github.com/v3/repos/branches/
+
+use hyper::method::Method;
+use serde::{Deserialize, Deserializer};
+use serde::de;
+use serde_json::Value;
+
+use client::Client;
+use common::{Team, User};
+use error;
+use path::{Owner, RefName, RepoName};
+
+/// Return type for Branch endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Branch {
+    pub name:           String,
+    pub commit:         BranchCommit,
+    pub protected:      Option<bool>,
+    pub protection_url: Option<String>
+}
+
+/// Sub-component of Branch
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BranchCommit {
+    pub sha: String,
+    pub url: String
+}
+
+/// Protection of a branch, returned by the get protection
+/// endpoint and sent to the update protection endpoint.
+/// The protections that are `None` are disabled.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BranchProtection {
+    pub required_status_checks:        Option<RequiredStatusChecks>,
+    /// Enforces the protection for the administrators of
+    /// the repository.
+    #[serde(default, deserialize_with = "deserialize_enforce_admins")]
+    pub enforce_admins:                bool,
+    pub required_pull_request_reviews: Option<RequiredReviews>,
+    /// Users and teams allowed to push, for organization
+    /// repositories only.
+    pub restrictions:                  Option<Restrictions>
+}
+
+impl BranchProtection {
+
+    /// Sorts the contexts, users and teams of the
+    /// protection, the API does not keep their order, so two
+    /// sorted protections can be compared.
+    pub fn sorted(mut self) -> BranchProtection {
+        if let Some(ref mut checks) = self.required_status_checks {
+            checks.contexts.sort();
+        }
+        if let Some(ref mut reviews) = self.required_pull_request_reviews {
+            if let Some(ref mut restrictions) = reviews.dismissal_restrictions {
+                restrictions.sort();
+            }
+        }
+        if let Some(ref mut restrictions) = self.restrictions {
+            restrictions.sort();
+        }
+        self
+    }
+}
+
+/// Status checks required to pass before merging into a
+/// branch.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RequiredStatusChecks {
+    /// Requires the branch to be up to date with the base
+    /// branch before merging.
+    #[serde(default)]
+    pub strict:   bool,
+    /// Contexts of the statuses that must succeed.
+    #[serde(default)]
+    pub contexts: Vec<String>
+}
+
+/// Reviews required before merging a pull request into a
+/// branch.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RequiredReviews {
+    /// Users and teams allowed to dismiss reviews, for
+    /// organization repositories only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions:     Option<Restrictions>,
+    /// Dismisses the approving reviews when a commit is
+    /// pushed.
+    #[serde(default)]
+    pub dismiss_stale_reviews:      bool,
+    /// Requires a review from the code owners of the
+    /// changed files.
+    #[serde(default)]
+    pub require_code_owner_reviews: bool
+}
+
+/// Users and teams allowed to push to a branch or to
+/// dismiss reviews. They are returned as objects by the API
+/// and sent as names, both are accepted when deserializing.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Restrictions {
+    /// Logins of the users.
+    pub users: Vec<String>,
+    /// Slugs of the teams.
+    pub teams: Vec<String>
+}
+
+impl Restrictions {
+
+    /// Sorts the users and teams.
+    pub fn sort(&mut self) {
+        self.users.sort();
+        self.teams.sort();
+    }
+}
+
+impl Deserialize for Restrictions {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Restrictions, D::Error> {
+        let raw = try!(RawRestrictions::deserialize(deserializer));
+        Ok(Restrictions {
+            users: try!(names(raw.users, "login")),
+            teams: try!(names(raw.teams, "slug"))
+        })
+    }
+}
+
+///Internal parameter
+#[derive(Deserialize)]
+struct RawRestrictions {
+    #[serde(default)]
+    users: Vec<Value>,
+    #[serde(default)]
+    teams: Vec<Value>
+}
+
+///Internal parameter
+#[derive(Deserialize)]
+struct EnabledResponse {
+    enabled: bool
+}
+
+///Internal parameter
+#[derive(Serialize)]
+struct ContextsParam {
+    contexts: Vec<String>
+}
+
+//Takes the names of users or teams given as strings or as
+//objects, ex. {"login": "octocat", ...}
+fn names<E: de::Error>(values: Vec<Value>, key: &str) -> Result<Vec<String>, E> {
+    values.into_iter().map(|value| {
+        match value {
+            Value::String(name) => Ok(name),
+            value               => value.find(key)
+                                        .and_then(Value::as_str)
+                                        .map(|name| name.to_string())
+                                        .ok_or_else(|| E::custom(format!("expected a string or an object with \"{}\"", key)))
+        }
+    }).collect()
+}
+
+//Takes enforce_admins given as a boolean or as an object,
+//ex. {"enabled": true, ...}
+fn deserialize_enforce_admins<D: Deserializer>(deserializer: &mut D) -> Result<bool, D::Error> {
+    match try!(Value::deserialize(deserializer)) {
+        Value::Bool(enabled) => Ok(enabled),
+        Value::Null          => Ok(false),
+        value                => value.find("enabled")
+                                     .and_then(Value::as_bool)
+                                     .ok_or_else(|| de::Error::custom(String::from("expected a boolean or an object with \"enabled\"")))
+    }
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait BranchesExt {
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#list-branches)\]
+    /// Returns the branches of a repository.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `protected`: Only returns the protected branches if
+    /// true, the unprotected ones if false.
+    fn get_repos_owner_repo_branches(&self, owner: Owner, repo: RepoName, protected: Option<bool>) -> Result<Vec<Branch>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#get-branch)\]
+    /// Returns a branch.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Branch, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#get-branch-protection)\]
+    /// Returns the protection of a branch.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch/protection
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch_protection(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<BranchProtection, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#update-branch-protection)\]
+    /// Replaces the protection of a branch.
+    /// ## Endpoint:
+    /// PUT /repos/:owner/:repo/branches/:branch/protection
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `protection`: Protection of the branch.
+    fn put_repos_owner_repo_branches_branch_protection(&self, owner: Owner, repo: RepoName, branch: RefName, protection: BranchProtection) -> Result<BranchProtection, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#remove-branch-protection)\]
+    /// Removes the protection of a branch.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/branches/:branch/protection
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn delete_repos_owner_repo_branches_branch_protection(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#get-required-status-checks-of-protected-branch)\]
+    /// Returns the required status checks of a branch.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch/protection/required_status_checks
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch_protection_required_status_checks(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<RequiredStatusChecks, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#update-required-status-checks-of-protected-branch)\]
+    /// Updates the required status checks of a branch.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/branches/:branch/protection/required_status_checks
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `checks`: Required status checks.
+    fn patch_repos_owner_repo_branches_branch_protection_required_status_checks(&self, owner: Owner, repo: RepoName, branch: RefName, checks: RequiredStatusChecks) -> Result<RequiredStatusChecks, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#remove-required-status-checks-of-protected-branch)\]
+    /// Removes the required status checks of a branch.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/branches/:branch/protection/required_status_checks
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn delete_repos_owner_repo_branches_branch_protection_required_status_checks(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#list-required-status-checks-contexts-of-protected-branch)\]
+    /// Returns the contexts of the required status checks
+    /// of a branch.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch/protection/required_status_checks/contexts
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch_protection_required_status_checks_contexts(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Vec<String>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#replace-required-status-checks-contexts-of-protected-branch)\]
+    /// Replaces the contexts of the required status checks
+    /// of a branch.
+    /// ## Endpoint:
+    /// PUT /repos/:owner/:repo/branches/:branch/protection/required_status_checks/contexts
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `contexts`: Contexts of the required statuses.
+    fn put_repos_owner_repo_branches_branch_protection_required_status_checks_contexts(&self, owner: Owner, repo: RepoName, branch: RefName, contexts: Vec<String>) -> Result<Vec<String>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#add-required-status-checks-contexts-of-protected-branch)\]
+    /// Adds contexts to the required status checks of a
+    /// branch.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/branches/:branch/protection/required_status_checks/contexts
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `contexts`: Contexts to add.
+    fn post_repos_owner_repo_branches_branch_protection_required_status_checks_contexts(&self, owner: Owner, repo: RepoName, branch: RefName, contexts: Vec<String>) -> Result<Vec<String>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#remove-required-status-checks-contexts-of-protected-branch)\]
+    /// Removes contexts from the required status checks of
+    /// a branch.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/branches/:branch/protection/required_status_checks/contexts
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `contexts`: Contexts to remove.
+    fn delete_repos_owner_repo_branches_branch_protection_required_status_checks_contexts(&self, owner: Owner, repo: RepoName, branch: RefName, contexts: Vec<String>) -> Result<Vec<String>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#get-pull-request-review-enforcement-of-protected-branch)\]
+    /// Returns the reviews required for the pull requests
+    /// into a branch.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch/protection/required_pull_request_reviews
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch_protection_required_pull_request_reviews(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<RequiredReviews, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#update-pull-request-review-enforcement-of-protected-branch)\]
+    /// Updates the reviews required for the pull requests
+    /// into a branch.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/branches/:branch/protection/required_pull_request_reviews
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `reviews`: Required reviews.
+    fn patch_repos_owner_repo_branches_branch_protection_required_pull_request_reviews(&self, owner: Owner, repo: RepoName, branch: RefName, reviews: RequiredReviews) -> Result<RequiredReviews, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#remove-pull-request-review-enforcement-of-protected-branch)\]
+    /// Removes the reviews required for the pull requests
+    /// into a branch.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/branches/:branch/protection/required_pull_request_reviews
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn delete_repos_owner_repo_branches_branch_protection_required_pull_request_reviews(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#get-admin-enforcement-of-protected-branch)\]
+    /// Returns whether the protection of a branch is
+    /// enforced for administrators.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch/protection/enforce_admins
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch_protection_enforce_admins(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<bool, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#add-admin-enforcement-of-protected-branch)\]
+    /// Enforces the protection of a branch for
+    /// administrators.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/branches/:branch/protection/enforce_admins
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn post_repos_owner_repo_branches_branch_protection_enforce_admins(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<bool, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#remove-admin-enforcement-of-protected-branch)\]
+    /// Stops enforcing the protection of a branch for
+    /// administrators.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/branches/:branch/protection/enforce_admins
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn delete_repos_owner_repo_branches_branch_protection_enforce_admins(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#get-restrictions-of-protected-branch)\]
+    /// Returns the users and teams allowed to push to a
+    /// branch.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch/protection/restrictions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch_protection_restrictions(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Restrictions, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#remove-restrictions-of-protected-branch)\]
+    /// Removes the push restrictions of a branch.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/branches/:branch/protection/restrictions
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn delete_repos_owner_repo_branches_branch_protection_restrictions(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#list-team-restrictions-of-protected-branch)\]
+    /// Returns the teams allowed to push to a branch.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch/protection/restrictions/teams
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch_protection_restrictions_teams(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Vec<Team>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#replace-team-restrictions-of-protected-branch)\]
+    /// Replaces the teams allowed to push to a branch.
+    /// ## Endpoint:
+    /// PUT /repos/:owner/:repo/branches/:branch/protection/restrictions/teams
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `teams`: Slugs of the teams.
+    fn put_repos_owner_repo_branches_branch_protection_restrictions_teams(&self, owner: Owner, repo: RepoName, branch: RefName, teams: Vec<String>) -> Result<Vec<Team>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#add-team-restrictions-of-protected-branch)\]
+    /// Allows teams to push to a branch.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/branches/:branch/protection/restrictions/teams
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `teams`: Slugs of the teams.
+    fn post_repos_owner_repo_branches_branch_protection_restrictions_teams(&self, owner: Owner, repo: RepoName, branch: RefName, teams: Vec<String>) -> Result<Vec<Team>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#remove-team-restrictions-of-protected-branch)\]
+    /// Stops allowing teams to push to a branch.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/branches/:branch/protection/restrictions/teams
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `teams`: Slugs of the teams.
+    fn delete_repos_owner_repo_branches_branch_protection_restrictions_teams(&self, owner: Owner, repo: RepoName, branch: RefName, teams: Vec<String>) -> Result<Vec<Team>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#list-user-restrictions-of-protected-branch)\]
+    /// Returns the users allowed to push to a branch.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/branches/:branch/protection/restrictions/users
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    fn get_repos_owner_repo_branches_branch_protection_restrictions_users(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Vec<User>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#replace-user-restrictions-of-protected-branch)\]
+    /// Replaces the users allowed to push to a branch.
+    /// ## Endpoint:
+    /// PUT /repos/:owner/:repo/branches/:branch/protection/restrictions/users
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `users`: Logins of the users.
+    fn put_repos_owner_repo_branches_branch_protection_restrictions_users(&self, owner: Owner, repo: RepoName, branch: RefName, users: Vec<String>) -> Result<Vec<User>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#add-user-restrictions-of-protected-branch)\]
+    /// Allows users to push to a branch.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/branches/:branch/protection/restrictions/users
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `users`: Logins of the users.
+    fn post_repos_owner_repo_branches_branch_protection_restrictions_users(&self, owner: Owner, repo: RepoName, branch: RefName, users: Vec<String>) -> Result<Vec<User>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/branches/#remove-user-restrictions-of-protected-branch)\]
+    /// Stops allowing users to push to a branch.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/branches/:branch/protection/restrictions/users
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `branch`: Name of the branch.
+    /// * `users`: Logins of the users.
+    fn delete_repos_owner_repo_branches_branch_protection_restrictions_users(&self, owner: Owner, repo: RepoName, branch: RefName, users: Vec<String>) -> Result<Vec<User>, error::Error>;
+}
+
+impl BranchesExt for Client {
+
+    fn get_repos_owner_repo_branches(&self, owner: Owner, repo: RepoName, protected: Option<bool>) -> Result<Vec<Branch>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .query_opt("protected", protected)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_branches_branch(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Branch, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_branches_branch_protection(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<BranchProtection, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn put_repos_owner_repo_branches_branch_protection(&self, owner: Owner, repo: RepoName, branch: RefName, protection: BranchProtection) -> Result<BranchProtection, error::Error> {
+        self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection")
+            .json(&protection)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_branches_branch_protection(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection")
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_repos_owner_repo_branches_branch_protection_required_status_checks(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<RequiredStatusChecks, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_status_checks")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_repos_owner_repo_branches_branch_protection_required_status_checks(&self, owner: Owner, repo: RepoName, branch: RefName, checks: RequiredStatusChecks) -> Result<RequiredStatusChecks, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_status_checks")
+            .json(&checks)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_branches_branch_protection_required_status_checks(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_status_checks")
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_repos_owner_repo_branches_branch_protection_required_status_checks_contexts(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Vec<String>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_status_checks/contexts")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn put_repos_owner_repo_branches_branch_protection_required_status_checks_contexts(&self, owner: Owner, repo: RepoName, branch: RefName, contexts: Vec<String>) -> Result<Vec<String>, error::Error> {
+        self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_status_checks/contexts")
+            .json(&ContextsParam {
+                contexts: contexts
+            })
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_branches_branch_protection_required_status_checks_contexts(&self, owner: Owner, repo: RepoName, branch: RefName, contexts: Vec<String>) -> Result<Vec<String>, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_status_checks/contexts")
+            .json(&ContextsParam {
+                contexts: contexts
+            })
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_branches_branch_protection_required_status_checks_contexts(&self, owner: Owner, repo: RepoName, branch: RefName, contexts: Vec<String>) -> Result<Vec<String>, error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_status_checks/contexts")
+            .json(&ContextsParam {
+                contexts: contexts
+            })
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_branches_branch_protection_required_pull_request_reviews(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<RequiredReviews, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_pull_request_reviews")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_repos_owner_repo_branches_branch_protection_required_pull_request_reviews(&self, owner: Owner, repo: RepoName, branch: RefName, reviews: RequiredReviews) -> Result<RequiredReviews, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_pull_request_reviews")
+            .json(&reviews)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_branches_branch_protection_required_pull_request_reviews(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/required_pull_request_reviews")
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_repos_owner_repo_branches_branch_protection_enforce_admins(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<bool, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/enforce_admins")
+            .send::<EnabledResponse>()
+            .map(|response| response.data.enabled)
+    }
+
+    fn post_repos_owner_repo_branches_branch_protection_enforce_admins(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<bool, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/enforce_admins")
+            .send::<EnabledResponse>()
+            .map(|response| response.data.enabled)
+    }
+
+    fn delete_repos_owner_repo_branches_branch_protection_enforce_admins(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/enforce_admins")
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_repos_owner_repo_branches_branch_protection_restrictions(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Restrictions, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_branches_branch_protection_restrictions(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions")
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_repos_owner_repo_branches_branch_protection_restrictions_teams(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Vec<Team>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions/teams")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn put_repos_owner_repo_branches_branch_protection_restrictions_teams(&self, owner: Owner, repo: RepoName, branch: RefName, teams: Vec<String>) -> Result<Vec<Team>, error::Error> {
+        self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions/teams")
+            .json(&teams)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_branches_branch_protection_restrictions_teams(&self, owner: Owner, repo: RepoName, branch: RefName, teams: Vec<String>) -> Result<Vec<Team>, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions/teams")
+            .json(&teams)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_branches_branch_protection_restrictions_teams(&self, owner: Owner, repo: RepoName, branch: RefName, teams: Vec<String>) -> Result<Vec<Team>, error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions/teams")
+            .json(&teams)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_branches_branch_protection_restrictions_users(&self, owner: Owner, repo: RepoName, branch: RefName) -> Result<Vec<User>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions/users")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn put_repos_owner_repo_branches_branch_protection_restrictions_users(&self, owner: Owner, repo: RepoName, branch: RefName, users: Vec<String>) -> Result<Vec<User>, error::Error> {
+        self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions/users")
+            .json(&users)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_branches_branch_protection_restrictions_users(&self, owner: Owner, repo: RepoName, branch: RefName, users: Vec<String>) -> Result<Vec<User>, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions/users")
+            .json(&users)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_branches_branch_protection_restrictions_users(&self, owner: Owner, repo: RepoName, branch: RefName, users: Vec<String>) -> Result<Vec<User>, error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/branches")
+            .tail(branch.as_str())
+            .path("/protection/restrictions/users")
+            .json(&users)
+            .send()
+            .map(|response| response.data)
+    }
+}
+
+#[cfg(test)]
+mod branches_test {
+
+    use serde_json;
+
+    use super::BranchProtection;
+
+    #[test]
+    fn branch_protection() {
+        let actual: BranchProtection = serde_json::from_str(include_str!("../../tests/fixtures/repositories/branch_protection.json")).unwrap();
+        assert!(actual.enforce_admins);
+        assert!(actual.required_pull_request_reviews.as_ref().unwrap().require_code_owner_reviews);
+        assert!(actual.restrictions.as_ref().unwrap().users == vec![String::from("octocat")]);
+
+        //The policy is written as sent to the API
+        let desired: BranchProtection = serde_json::from_value(serde_json::to_value(&actual.clone().sorted())).unwrap();
+        assert!(desired == actual.clone().sorted());
+        assert!(desired != actual);
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Collaborators
+//!
+//! These are the responses and API call functions related
+//! to the collaborators endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/repos/collaborators/
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use serde_json;
+
+use client::Client;
+use common::{Permissions, User};
+use error;
+use path::{Owner, RepoName};
+use repositories::invitations::Invitation;
+
+string_enum! {
+    /// Filter of the collaborators by their affiliation.
+    pub enum Affiliation {
+        /// Outside collaborators of an organization
+        /// repository.
+        Outside => "outside",
+        /// Collaborators with permissions on the repository
+        /// itself, members of the organization or not.
+        Direct  => "direct",
+        All     => "all"
+    }
+}
+
+string_enum! {
+    /// Permission given to a collaborator when adding them.
+    pub enum Permission {
+        Pull  => "pull",
+        Push  => "push",
+        Admin => "admin"
+    }
+}
+
+string_enum! {
+    /// Permission level of a user on a repository, also the
+    /// permission given by an invitation.
+    pub enum PermissionLevel {
+        Admin => "admin",
+        Write => "write",
+        Read  => "read",
+        None  => "none"
+    }
+}
+
+/// Return type for Collaborator endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Collaborator {
+    pub login:       String,
+    pub id:          u64,
+    pub avatar_url:  String,
+    pub url:         String,
+    pub html_url:    Option<String>,
+    #[serde(rename = "type")]
+    pub user_type:   Option<String>,
+    pub site_admin:  Option<bool>,
+    pub permissions: Option<Permissions>
+}
+
+/// Return type of the permission level endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CollaboratorPermission {
+    pub permission: PermissionLevel,
+    pub user:       User
+}
+
+///Internal parameter
+#[derive(Serialize)]
+struct CollaboratorParam {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<Permission>
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait CollaboratorsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/repos/collaborators/#list-collaborators)\]
+    /// Returns the collaborators of a repository, with their
+    /// permissions.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/collaborators
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `affiliation`: Filter of the collaborators, all of
+    /// them by default.
+    fn get_repos_owner_repo_collaborators(&self, owner: Owner, repo: RepoName, affiliation: Option<Affiliation>) -> Result<Vec<Collaborator>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/collaborators/#check-if-a-user-is-a-collaborator)\]
+    /// Returns whether a user is a collaborator of a
+    /// repository.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/collaborators/:username
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `username`: Login of the user.
+    fn get_repos_owner_repo_collaborators_username(&self, owner: Owner, repo: RepoName, username: String) -> Result<bool, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/collaborators/#review-a-users-permission-level)\]
+    /// Returns the permission level of a user on a
+    /// repository.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/collaborators/:username/permission
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `username`: Login of the user.
+    fn get_repos_owner_repo_collaborators_username_permission(&self, owner: Owner, repo: RepoName, username: String) -> Result<CollaboratorPermission, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/collaborators/#add-user-as-a-collaborator)\]
+    /// Invites a user to collaborate on a repository, or
+    /// updates the permission of a collaborator. Returns the
+    /// invitation, or `None` if the user already is a
+    /// collaborator.
+    /// ## Endpoint:
+    /// PUT /repos/:owner/:repo/collaborators/:username
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `username`: Login of the user.
+    /// * `permission`: Permission of the user, only for
+    /// organization repositories, `Push` by default.
+    fn put_repos_owner_repo_collaborators_username(&self, owner: Owner, repo: RepoName, username: String, permission: Option<Permission>) -> Result<Option<Invitation>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/collaborators/#remove-user-as-a-collaborator)\]
+    /// Removes a collaborator of a repository.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/collaborators/:username
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `username`: Login of the user.
+    fn delete_repos_owner_repo_collaborators_username(&self, owner: Owner, repo: RepoName, username: String) -> Result<(), error::Error>;
+}
+
+impl CollaboratorsExt for Client {
+
+    fn get_repos_owner_repo_collaborators(&self, owner: Owner, repo: RepoName, affiliation: Option<Affiliation>) -> Result<Vec<Collaborator>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/collaborators")
+            .query_opt("affiliation", affiliation)
+            .send_all()
+    }
+
+    fn get_repos_owner_repo_collaborators_username(&self, owner: Owner, repo: RepoName, username: String) -> Result<bool, error::Error> {
+        let response = self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/collaborators")
+            .segment(username)
+            .send_empty();
+
+        //Only the empty 404 of the endpoint means "not a
+        //collaborator", an error message means the repository
+        //itself was not found
+        match response {
+            Ok(_)                                           => Ok(true),
+            Err(error::Error::Status(StatusCode::NotFound)) => Ok(false),
+            Err(err)                                        => Err(err)
+        }
+    }
+
+    fn get_repos_owner_repo_collaborators_username_permission(&self, owner: Owner, repo: RepoName, username: String) -> Result<CollaboratorPermission, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/collaborators")
+            .segment(username)
+            .path("/permission")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn put_repos_owner_repo_collaborators_username(&self, owner: Owner, repo: RepoName, username: String, permission: Option<Permission>) -> Result<Option<Invitation>, error::Error> {
+        let mut response = try!(self.request(Method::Put, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/collaborators")
+            .segment(username)
+            .json(&CollaboratorParam {
+                permission: permission
+            })
+            .send_raw());
+
+        //No content when the user already is a collaborator
+        if response.status != StatusCode::Created {
+            return Ok(None);
+        }
+        let response_str = try!(Client::response_to_string(&mut response));
+        serde_json::from_str(&response_str[..])
+            .map(Some)
+            .map_err(error::Error::Parsing)
+    }
+
+    fn delete_repos_owner_repo_collaborators_username(&self, owner: Owner, repo: RepoName, username: String) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/collaborators")
+            .segment(username)
+            .send_empty()
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod collaborators_test {
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use serde_json;
+
+    use auth::auth::Auth;
+    use client::Client;
+    use error;
+    use path::{Owner, RepoName};
+    use super::{CollaboratorParam, CollaboratorPermission, CollaboratorsExt, Permission, PermissionLevel};
+
+    //Answers `responses` in order, one per connection, and
+    //returns the request line of each request
+    fn serve(responses: Vec<String>) -> (Client, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address  = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            responses.into_iter().map(|response| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request_line = String::new();
+                {
+                    let mut reader = BufReader::new(&stream);
+                    let mut line   = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+                    while reader.read_line(&mut line).unwrap() > 2 {
+                        line.clear();
+                    }
+                }
+                stream.write_all(response.as_bytes()).unwrap();
+                request_line
+            }).collect()
+        });
+        (Client::with_url(&address[..], "rustyhub-test/0.0.0", Auth::NoAuth), server)
+    }
+
+    fn response(status: &str, body: &str) -> String {
+        format!("HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body)
+    }
+
+    fn repo() -> (Owner, RepoName) {
+        ("octocat".parse().unwrap(), "Hello-World".parse().unwrap())
+    }
+
+    #[test]
+    fn permission() {
+        let permission: CollaboratorPermission = serde_json::from_str(include_str!("../../tests/fixtures/repositories/collaborator_permission.json")).unwrap();
+        assert!(permission.permission == PermissionLevel::Write);
+        assert!(permission.user.login == "octocat");
+
+        let level: PermissionLevel = serde_json::from_str("\"maintain\"").unwrap();
+        assert!(level == PermissionLevel::Other(String::from("maintain")));
+
+        let param = CollaboratorParam { permission: Some(Permission::Push) };
+        assert!(serde_json::to_string(&param).unwrap() == r#"{"permission":"push"}"#);
+        let param = CollaboratorParam { permission: None };
+        assert!(serde_json::to_string(&param).unwrap() == "{}");
+    }
+
+    #[test]
+    fn is_collaborator() {
+        let (client, server) = serve(vec![
+            response("204 No Content", ""),
+            response("404 Not Found", ""),
+            response("404 Not Found", r#"{"message": "Not Found", "documentation_url": "https://developer.github.com/v3"}"#)
+        ]);
+
+        let (owner, repo) = repo();
+        assert!(client.get_repos_owner_repo_collaborators_username(owner.clone(), repo.clone(), String::from("octocat")).unwrap());
+        assert!(!client.get_repos_owner_repo_collaborators_username(owner.clone(), repo.clone(), String::from("hubot")).unwrap());
+
+        //A missing repository is an error
+        match client.get_repos_owner_repo_collaborators_username(owner, repo, String::from("octocat")) {
+            Err(error::Error::Github(err)) => assert!(err.message == "Not Found"),
+            _                              => panic!("expected Error::Github")
+        }
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /repos/octocat/Hello-World/collaborators/octocat "));
+    }
+
+    #[test]
+    fn add_collaborator() {
+        let (client, server) = serve(vec![
+            response("201 Created", include_str!("../../tests/fixtures/repositories/invitation.json")),
+            response("204 No Content", "")
+        ]);
+
+        let (owner, repo) = repo();
+        let invitation    = client.put_repos_owner_repo_collaborators_username(owner.clone(), repo.clone(), String::from("octocat"), Some(Permission::Push)).unwrap();
+        assert!(invitation.map(|invitation| invitation.id) == Some(1));
+
+        //An existing collaborator is not invited
+        assert!(client.put_repos_owner_repo_collaborators_username(owner, repo, String::from("octocat"), None).unwrap().is_none());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("PUT /repos/octocat/Hello-World/collaborators/octocat "));
+    }
+}
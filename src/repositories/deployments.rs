@@ -0,0 +1,383 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Deployments
+//!
+//! These are the responses and API call functions related
+//! to the deployments endpoints of the API. The environment
+//! flags, the log and environment URLs and the `InProgress`
+//! and `Queued` states are sent with the
+//! `preview-deployments` feature.
+//!
+//! Reference: https://developer.github.com/v3/repos/deployments/
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use serde_json::{self, Value};
+
+use client::Client;
+use common::{DateTime, User};
+use error::{self, GithubError};
+use path::{Owner, RepoName};
+#[cfg(feature = "preview-deployments")]
+use preview::Preview;
+
+string_enum! {
+    /// State of a deployment status.
+    pub enum DeploymentState {
+        Error      => "error",
+        Failure    => "failure",
+        Inactive   => "inactive",
+        InProgress => "in_progress",
+        Queued     => "queued",
+        Pending    => "pending",
+        Success    => "success"
+    }
+}
+
+/// Return type for Deployment endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Deployment {
+    pub url:                    String,
+    pub id:                     u64,
+    pub sha:                    String,
+    #[serde(rename = "ref")]
+    pub _ref:                   String,
+    pub task:                   String,
+    /// Data given at the creation of the deployment.
+    pub payload:                Value,
+    pub environment:            String,
+    pub original_environment:   Option<String>,
+    pub description:            Option<String>,
+    pub creator:                Option<User>,
+    pub created_at:             DateTime,
+    pub updated_at:             DateTime,
+    pub statuses_url:           String,
+    pub repository_url:         String,
+    pub transient_environment:  Option<bool>,
+    pub production_environment: Option<bool>
+}
+
+/// Return type for Deployment status endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DeploymentStatus {
+    pub url:             String,
+    pub id:              u64,
+    pub state:           DeploymentState,
+    pub creator:         Option<User>,
+    pub description:     Option<String>,
+    pub environment:     Option<String>,
+    pub target_url:      Option<String>,
+    pub log_url:         Option<String>,
+    pub environment_url: Option<String>,
+    pub created_at:      DateTime,
+    pub updated_at:      DateTime,
+    pub deployment_url:  String,
+    pub repository_url:  String
+}
+
+/// Outcome of the creation of a deployment.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeploymentOutcome {
+    /// The deployment was created.
+    Created(Deployment),
+    /// The default branch was merged into the ref, no
+    /// deployment was created, a new one should be requested
+    /// for the merge commit. Holds the message of the API.
+    AutoMerged(String),
+    /// The ref could not be merged or the required contexts
+    /// of its statuses did not all succeed, their states
+    /// are in the `contexts` of the errors.
+    Conflict(GithubError)
+}
+
+/// Parameters for the creation of a deployment.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DeploymentParam {
+    /// Branch, tag or SHA to deploy.
+    #[serde(rename = "ref")]
+    pub _ref:                   String,
+    /// Task to execute, "deploy" if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task:                   Option<String>,
+    /// Merges the default branch into the ref if it is
+    /// behind, true if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_merge:             Option<bool>,
+    /// Contexts of the statuses that must succeed, all the
+    /// contexts if not set, none if empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_contexts:      Option<Vec<String>>,
+    /// Data for the deployment system, any JSON value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload:                Option<Value>,
+    /// Name of the environment, "production" if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment:            Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description:            Option<String>,
+    /// Marks the environment as removed at some point, ex.
+    /// the environment of a pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transient_environment:  Option<bool>,
+    /// Marks the environment as used by end-users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub production_environment: Option<bool>
+}
+
+impl DeploymentParam {
+
+    /// Creates the parameters of a deployment of a ref with
+    /// the default options.
+    pub fn new(_ref: &str) -> DeploymentParam {
+        DeploymentParam {
+            _ref:                   _ref.to_string(),
+            task:                   None,
+            auto_merge:             None,
+            required_contexts:      None,
+            payload:                None,
+            environment:            None,
+            description:            None,
+            transient_environment:  None,
+            production_environment: None
+        }
+    }
+}
+
+/// Parameters for the creation of a deployment status.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DeploymentStatusParam {
+    /// State of the deployment.
+    pub state:           DeploymentState,
+    /// URL of the output of the deployment, prefer
+    /// `log_url`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_url:      Option<String>,
+    /// URL of the output of the deployment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_url:         Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description:     Option<String>,
+    /// Name of the environment, replaces the environment
+    /// of the deployment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment:     Option<String>,
+    /// URL of the deployed environment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment_url: Option<String>,
+    /// Marks the previous successful deployments to the
+    /// environment as `Inactive` on success, true if not
+    /// set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_inactive:   Option<bool>
+}
+
+impl DeploymentStatusParam {
+
+    /// Creates the parameters of a deployment status.
+    pub fn new(state: DeploymentState) -> DeploymentStatusParam {
+        DeploymentStatusParam {
+            state:           state,
+            target_url:      None,
+            log_url:         None,
+            description:     None,
+            environment:     None,
+            environment_url: None,
+            auto_inactive:   None
+        }
+    }
+}
+
+///Internal parameter
+#[derive(Deserialize)]
+struct MessageResponse {
+    message: String
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait DeploymentsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/repos/deployments/#list-deployments)\]
+    /// Returns the deployments of a repository.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/deployments
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `sha`: Only returns the deployments of this SHA.
+    /// * `_ref`: Only returns the deployments of this
+    /// branch, tag or SHA.
+    /// * `task`: Only returns the deployments of this task.
+    /// * `environment`: Only returns the deployments to this
+    /// environment.
+    fn get_repos_owner_repo_deployments(&self,
+                                        owner: Owner,
+                                        repo: RepoName,
+                                        sha: Option<String>,
+                                        _ref: Option<String>,
+                                        task: Option<String>,
+                                        environment: Option<String>) -> Result<Vec<Deployment>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/deployments/#get-a-single-deployment)\]
+    /// Returns a deployment.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/deployments/:deployment_id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `deployment_id`: ID of the deployment.
+    fn get_repos_owner_repo_deployments_deployment_id(&self, owner: Owner, repo: RepoName, deployment_id: u64) -> Result<Deployment, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/deployments/#create-a-deployment)\]
+    /// Creates a deployment. An automatic merge of the
+    /// default branch and a conflict are returned as
+    /// outcomes rather than errors.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/deployments
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `deployment`: Parameters of the deployment.
+    fn post_repos_owner_repo_deployments(&self, owner: Owner, repo: RepoName, deployment: DeploymentParam) -> Result<DeploymentOutcome, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/deployments/#list-deployment-statuses)\]
+    /// Returns the statuses of a deployment.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/deployments/:deployment_id/statuses
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `deployment_id`: ID of the deployment.
+    fn get_repos_owner_repo_deployments_deployment_id_statuses(&self, owner: Owner, repo: RepoName, deployment_id: u64) -> Result<Vec<DeploymentStatus>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/deployments/#create-a-deployment-status)\]
+    /// Creates a status for a deployment.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/deployments/:deployment_id/statuses
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `deployment_id`: ID of the deployment.
+    /// * `status`: Parameters of the status.
+    fn post_repos_owner_repo_deployments_deployment_id_statuses(&self, owner: Owner, repo: RepoName, deployment_id: u64, status: DeploymentStatusParam) -> Result<DeploymentStatus, error::Error>;
+}
+
+impl DeploymentsExt for Client {
+
+    fn get_repos_owner_repo_deployments(&self,
+                                        owner: Owner,
+                                        repo: RepoName,
+                                        sha: Option<String>,
+                                        _ref: Option<String>,
+                                        task: Option<String>,
+                                        environment: Option<String>) -> Result<Vec<Deployment>, error::Error> {
+        let request = self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/deployments")
+            .query_opt("sha", sha)
+            .query_opt("ref", _ref)
+            .query_opt("task", task)
+            .query_opt("environment", environment);
+
+        #[cfg(feature = "preview-deployments")]
+        let request = request.preview(Preview::Deployments);
+
+        request.send().map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_deployments_deployment_id(&self, owner: Owner, repo: RepoName, deployment_id: u64) -> Result<Deployment, error::Error> {
+        let request = self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/deployments")
+            .segment(deployment_id);
+
+        #[cfg(feature = "preview-deployments")]
+        let request = request.preview(Preview::Deployments);
+
+        request.send().map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_deployments(&self, owner: Owner, repo: RepoName, deployment: DeploymentParam) -> Result<DeploymentOutcome, error::Error> {
+        let request = self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/deployments")
+            .json(&deployment);
+
+        #[cfg(feature = "preview-deployments")]
+        let request = request.preview(Preview::Deployments);
+
+        let mut response = match request.send_raw() {
+            Ok(response)                          => response,
+            Err(error::Error::Conflict(conflict)) => return Ok(DeploymentOutcome::Conflict(conflict)),
+            Err(err)                              => return Err(err)
+        };
+        let response_str = try!(Client::response_to_string(&mut response));
+
+        if response.status == StatusCode::Accepted {
+            let merged: MessageResponse = try!(serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing));
+            return Ok(DeploymentOutcome::AutoMerged(merged.message));
+        }
+        serde_json::from_str(&response_str[..])
+            .map(DeploymentOutcome::Created)
+            .map_err(error::Error::Parsing)
+    }
+
+    fn get_repos_owner_repo_deployments_deployment_id_statuses(&self, owner: Owner, repo: RepoName, deployment_id: u64) -> Result<Vec<DeploymentStatus>, error::Error> {
+        let request = self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/deployments")
+            .segment(deployment_id)
+            .path("/statuses");
+
+        #[cfg(feature = "preview-deployments")]
+        let request = request.preview(Preview::Deployments);
+
+        request.send().map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_deployments_deployment_id_statuses(&self, owner: Owner, repo: RepoName, deployment_id: u64, status: DeploymentStatusParam) -> Result<DeploymentStatus, error::Error> {
+        let request = self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/deployments")
+            .segment(deployment_id)
+            .path("/statuses")
+            .json(&status);
+
+        #[cfg(feature = "preview-deployments")]
+        let request = request.preview(Preview::Deployments);
+
+        request.send().map(|response| response.data)
+    }
+}
+
+#[cfg(test)]
+mod deployments_test {
+
+    use serde_json;
+
+    use error::GithubError;
+
+    #[test]
+    fn conflict() {
+        let conflict: GithubError = serde_json::from_str(include_str!("../../tests/fixtures/repositories/deployment_conflict.json")).unwrap();
+        let errors = conflict.errors.unwrap();
+        assert!(errors[0].field == Some(String::from("required_contexts")));
+
+        let contexts = errors[0].contexts.clone().unwrap();
+        assert!(contexts.len() == 2);
+        assert!(contexts[1].context == "security/brakeman" && contexts[1].state == "failure");
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Invitations
+//!
+//! These are the responses and API call functions related
+//! to the repository invitations endpoints of the API, for
+//! the repositories inviting users and for the invited
+//! authenticated user.
+//!
+//! Reference: https://developer.github.com/v3/repos/invitations/
+
+use hyper::method::Method;
+
+use client::Client;
+use common::{DateTime, Repository, User};
+use error;
+use path::{Owner, RepoName};
+use repositories::collaborators::PermissionLevel;
+
+/// Return type for Invitation endpoints, also returned when
+/// adding a collaborator.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Invitation {
+    pub id:          u64,
+    pub repository:  Repository,
+    pub invitee:     User,
+    pub inviter:     User,
+    pub permissions: PermissionLevel,
+    pub created_at:  DateTime,
+    pub url:         String,
+    pub html_url:    String
+}
+
+///Internal parameter
+#[derive(Serialize)]
+struct InvitationEdit {
+    permissions: PermissionLevel
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait InvitationsExt {
+
+    /// \[[Reference](https://developer.github.com/v3/repos/invitations/#list-invitations-for-a-repository)\]
+    /// Returns the pending invitations of a repository.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/invitations
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    fn get_repos_owner_repo_invitations(&self, owner: Owner, repo: RepoName) -> Result<Vec<Invitation>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/invitations/#update-a-repository-invitation)\]
+    /// Updates the permission given by an invitation.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/invitations/:invitation_id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `invitation_id`: ID of the invitation.
+    /// * `permissions`: Permission given by the invitation,
+    /// `Read`, `Write` or `Admin`.
+    fn patch_repos_owner_repo_invitations_invitation_id(&self, owner: Owner, repo: RepoName, invitation_id: u64, permissions: PermissionLevel) -> Result<Invitation, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/invitations/#delete-a-repository-invitation)\]
+    /// Deletes an invitation of a repository.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/invitations/:invitation_id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `invitation_id`: ID of the invitation.
+    fn delete_repos_owner_repo_invitations_invitation_id(&self, owner: Owner, repo: RepoName, invitation_id: u64) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/invitations/#list-a-users-repository-invitations)\]
+    /// Returns the pending invitations of the authenticated
+    /// user.
+    /// ## Endpoint:
+    /// GET /user/repository_invitations
+    fn get_user_repository_invitations(&self) -> Result<Vec<Invitation>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/invitations/#accept-a-repository-invitation)\]
+    /// Accepts an invitation of the authenticated user.
+    /// ## Endpoint:
+    /// PATCH /user/repository_invitations/:invitation_id
+    /// ## Parameters
+    /// * `invitation_id`: ID of the invitation.
+    fn patch_user_repository_invitations_invitation_id(&self, invitation_id: u64) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/invitations/#decline-a-repository-invitation)\]
+    /// Declines an invitation of the authenticated user.
+    /// ## Endpoint:
+    /// DELETE /user/repository_invitations/:invitation_id
+    /// ## Parameters
+    /// * `invitation_id`: ID of the invitation.
+    fn delete_user_repository_invitations_invitation_id(&self, invitation_id: u64) -> Result<(), error::Error>;
+}
+
+impl InvitationsExt for Client {
+
+    fn get_repos_owner_repo_invitations(&self, owner: Owner, repo: RepoName) -> Result<Vec<Invitation>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/invitations")
+            .send_all()
+    }
+
+    fn patch_repos_owner_repo_invitations_invitation_id(&self, owner: Owner, repo: RepoName, invitation_id: u64, permissions: PermissionLevel) -> Result<Invitation, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/invitations")
+            .segment(invitation_id)
+            .json(&InvitationEdit {
+                permissions: permissions
+            })
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_invitations_invitation_id(&self, owner: Owner, repo: RepoName, invitation_id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/invitations")
+            .segment(invitation_id)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_user_repository_invitations(&self) -> Result<Vec<Invitation>, error::Error> {
+        self.request(Method::Get, "/user/repository_invitations")
+            .send_all()
+    }
+
+    fn patch_user_repository_invitations_invitation_id(&self, invitation_id: u64) -> Result<(), error::Error> {
+        self.request(Method::Patch, "/user/repository_invitations")
+            .segment(invitation_id)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn delete_user_repository_invitations_invitation_id(&self, invitation_id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/user/repository_invitations")
+            .segment(invitation_id)
+            .send_empty()
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod invitations_test {
+
+    use serde_json;
+
+    use repositories::collaborators::PermissionLevel;
+    use super::{Invitation, InvitationEdit};
+
+    #[test]
+    fn invitation() {
+        let invitation: Invitation = serde_json::from_str(include_str!("../../tests/fixtures/repositories/invitation.json")).unwrap();
+        assert!(invitation.permissions == PermissionLevel::Write);
+        assert!(invitation.repository.name == "Hello-World");
+        assert!(invitation.invitee.login == "octocat");
+
+        let edit = InvitationEdit { permissions: PermissionLevel::Admin };
+        assert!(serde_json::to_string(&edit).unwrap() == r#"{"permissions":"admin"}"#);
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Repositories
+//!
+//! Modules for subsections of Repositories in the Github API
+//! docs.
+//!
+//! Reference: https://developer.github.com/v3/repos/
+
+//TODO: Endpoints for repositories
+pub mod branches;
+pub mod collaborators;
+//TODO: comments
+//TODO: commits
+//TODO: contents
+//TODO: deploy_keys
+pub mod deployments;
+//TODO: forks
+pub mod invitations;
+//TODO: merging
+//TODO: pages
+pub mod releases;
+//TODO: statistics
+pub mod statuses;
+//TODO: webhooks
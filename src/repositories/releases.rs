@@ -0,0 +1,554 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Releases
+//!
+//! These are the responses and API call functions related
+//! to the releases endpoints of the API. Assets are uploaded
+//! to the uploads URL of the client and downloaded from the
+//! storage the API redirects to, both are streamed.
+//!
+//! Reference: https://developer.github.com/v3/repos/releases/
+
+use std::io::{Read, Write};
+
+use hyper::header::{Accept, ContentType, qitem};
+use hyper::method::Method;
+use hyper::mime::{Mime, TopLevel, SubLevel};
+
+use client::Client;
+use common::{DateTime, Edit, User};
+use error;
+use path::{Owner, RefName, RepoName};
+
+/// Return type for Release endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Release {
+    pub url:              String,
+    pub html_url:         String,
+    pub assets_url:       String,
+    /// URI template of the uploads URL of the assets, ex.
+    /// "https://uploads.github.com/repos/octocat/Hello-World/releases/1/assets{?name,label}".
+    pub upload_url:       String,
+    pub tarball_url:      Option<String>,
+    pub zipball_url:      Option<String>,
+    pub id:               u64,
+    pub tag_name:         String,
+    pub target_commitish: String,
+    pub name:             Option<String>,
+    pub body:             Option<String>,
+    pub draft:            bool,
+    pub prerelease:       bool,
+    pub created_at:       DateTime,
+    pub published_at:     Option<DateTime>,
+    pub author:           User,
+    pub assets:           Vec<ReleaseAsset>
+}
+
+/// Return type for Release asset endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ReleaseAsset {
+    pub url:                  String,
+    pub browser_download_url: String,
+    pub id:                   u64,
+    pub name:                 String,
+    pub label:                Option<String>,
+    pub state:                String,
+    pub content_type:         String,
+    pub size:                 u64,
+    pub download_count:       u64,
+    pub created_at:           DateTime,
+    pub updated_at:           DateTime,
+    pub uploader:             Option<User>
+}
+
+/// Parameters for the creation of a release.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ReleaseParam {
+    /// Name of the tag of the release, it is created if it
+    /// does not exist.
+    pub tag_name:         String,
+    /// Branch or commit the tag is created from, the
+    /// default branch if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    /// Name of the release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:             Option<String>,
+    /// Description of the release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body:             Option<String>,
+    /// Creates an unpublished release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft:            Option<bool>,
+    /// Identifies the release as a prerelease.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerelease:       Option<bool>
+}
+
+impl ReleaseParam {
+
+    /// Creates the parameters of a published release of a
+    /// tag.
+    pub fn new(tag_name: &str) -> ReleaseParam {
+        ReleaseParam {
+            tag_name:         tag_name.to_string(),
+            target_commitish: None,
+            name:             None,
+            body:             None,
+            draft:            None,
+            prerelease:       None
+        }
+    }
+}
+
+/// Parameters for the editing of a release, only the fields
+/// that are set are changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ReleaseEdit {
+    /// Name of the tag of the release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_name:         Option<String>,
+    /// Branch or commit the tag is created from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    /// Name of the release.
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub name:             Edit<String>,
+    /// Description of the release.
+    #[serde(skip_serializing_if = "Edit::is_unchanged")]
+    pub body:             Edit<String>,
+    /// Whether the release is unpublished.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft:            Option<bool>,
+    /// Whether the release is a prerelease.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerelease:       Option<bool>
+}
+
+impl ReleaseEdit {
+
+    /// Creates an edit that leaves the release unchanged.
+    pub fn new() -> ReleaseEdit {
+        ReleaseEdit::default()
+    }
+
+    /// Sets the name of the tag of the release.
+    pub fn tag_name(mut self, tag_name: &str) -> ReleaseEdit {
+        self.tag_name = Some(tag_name.to_string());
+        self
+    }
+
+    /// Sets the branch or commit the tag is created from.
+    pub fn target_commitish(mut self, target_commitish: &str) -> ReleaseEdit {
+        self.target_commitish = Some(target_commitish.to_string());
+        self
+    }
+
+    /// Sets the name of the release.
+    pub fn name(mut self, name: &str) -> ReleaseEdit {
+        self.name = Edit::Set(name.to_string());
+        self
+    }
+
+    /// Removes the name of the release.
+    pub fn clear_name(mut self) -> ReleaseEdit {
+        self.name = Edit::Null;
+        self
+    }
+
+    /// Sets the description of the release.
+    pub fn body(mut self, body: &str) -> ReleaseEdit {
+        self.body = Edit::Set(body.to_string());
+        self
+    }
+
+    /// Removes the description of the release.
+    pub fn clear_body(mut self) -> ReleaseEdit {
+        self.body = Edit::Null;
+        self
+    }
+
+    /// Sets whether the release is unpublished.
+    pub fn draft(mut self, draft: bool) -> ReleaseEdit {
+        self.draft = Some(draft);
+        self
+    }
+
+    /// Sets whether the release is a prerelease.
+    pub fn prerelease(mut self, prerelease: bool) -> ReleaseEdit {
+        self.prerelease = Some(prerelease);
+        self
+    }
+}
+
+/// Parameters for the editing of a release asset.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AssetEdit {
+    /// File name of the asset.
+    pub name:  String,
+    /// Label shown instead of the file name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>
+}
+
+impl AssetEdit {
+
+    /// Creates an edit renaming an asset.
+    pub fn new(name: &str) -> AssetEdit {
+        AssetEdit {
+            name:  name.to_string(),
+            label: None
+        }
+    }
+
+    /// Sets the label of the asset.
+    pub fn label(mut self, label: &str) -> AssetEdit {
+        self.label = Some(label.to_string());
+        self
+    }
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait ReleasesExt {
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#list-releases-for-a-repository)\]
+    /// Returns the releases of a repository, drafts are only
+    /// returned to users with push access.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/releases
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    fn get_repos_owner_repo_releases(&self, owner: Owner, repo: RepoName) -> Result<Vec<Release>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#get-a-single-release)\]
+    /// Returns a release.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/releases/:id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the release.
+    fn get_repos_owner_repo_releases_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<Release, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#get-the-latest-release)\]
+    /// Returns the latest published release that is not a
+    /// prerelease.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/releases/latest
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    fn get_repos_owner_repo_releases_latest(&self, owner: Owner, repo: RepoName) -> Result<Release, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#get-a-release-by-tag-name)\]
+    /// Returns the release of a tag.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/releases/tags/:tag
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `tag`: Name of the tag, ex. "v1.0.0".
+    fn get_repos_owner_repo_releases_tags_tag(&self, owner: Owner, repo: RepoName, tag: RefName) -> Result<Release, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#create-a-release)\]
+    /// Creates a release.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/releases
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `release`: Parameters of the release.
+    fn post_repos_owner_repo_releases(&self, owner: Owner, repo: RepoName, release: ReleaseParam) -> Result<Release, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#edit-a-release)\]
+    /// Edits a release.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/releases/:id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the release.
+    /// * `release`: Changes to the release.
+    fn patch_repos_owner_repo_releases_id(&self, owner: Owner, repo: RepoName, id: u64, release: ReleaseEdit) -> Result<Release, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#delete-a-release)\]
+    /// Deletes a release, its tag is kept.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/releases/:id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the release.
+    fn delete_repos_owner_repo_releases_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<(), error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#list-assets-for-a-release)\]
+    /// Returns the assets of a release.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/releases/:id/assets
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the release.
+    fn get_repos_owner_repo_releases_id_assets(&self, owner: Owner, repo: RepoName, id: u64) -> Result<Vec<ReleaseAsset>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#upload-a-release-asset)\]
+    /// Uploads an asset to a release, its content is
+    /// streamed from `asset` to the uploads URL of the
+    /// client.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/releases/:id/assets
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the release.
+    /// * `name`: File name of the asset.
+    /// * `label`: Label shown instead of the file name.
+    /// * `content_type`: Media type of the asset, ex.
+    /// "application/zip".
+    /// * `length`: Size of the asset in bytes.
+    /// * `asset`: Content of the asset, ex. a file.
+    fn post_repos_owner_repo_releases_id_assets<R: Read>(&self,
+                                                         owner: Owner,
+                                                         repo: RepoName,
+                                                         id: u64,
+                                                         name: String,
+                                                         label: Option<String>,
+                                                         content_type: Mime,
+                                                         length: u64,
+                                                         asset: &mut R) -> Result<ReleaseAsset, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#get-a-single-release-asset)\]
+    /// Returns a release asset.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/releases/assets/:id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the asset.
+    fn get_repos_owner_repo_releases_assets_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<ReleaseAsset, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#get-a-single-release-asset)\]
+    /// Downloads the content of a release asset to `writer`,
+    /// returns the size of the asset.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/releases/assets/:id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the asset.
+    /// * `writer`: Destination of the asset, ex. a file.
+    fn download_repos_owner_repo_releases_assets_id<W: Write>(&self, owner: Owner, repo: RepoName, id: u64, writer: &mut W) -> Result<u64, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#edit-a-release-asset)\]
+    /// Edits a release asset.
+    /// ## Endpoint:
+    /// PATCH /repos/:owner/:repo/releases/assets/:id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the asset.
+    /// * `asset`: Changes to the asset.
+    fn patch_repos_owner_repo_releases_assets_id(&self, owner: Owner, repo: RepoName, id: u64, asset: AssetEdit) -> Result<ReleaseAsset, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/releases/#delete-a-release-asset)\]
+    /// Deletes a release asset.
+    /// ## Endpoint:
+    /// DELETE /repos/:owner/:repo/releases/assets/:id
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `id`: ID of the asset.
+    fn delete_repos_owner_repo_releases_assets_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<(), error::Error>;
+}
+
+impl ReleasesExt for Client {
+
+    fn get_repos_owner_repo_releases(&self, owner: Owner, repo: RepoName) -> Result<Vec<Release>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases")
+            .query("per_page", 100u32)
+            .send_all()
+    }
+
+
+    fn get_repos_owner_repo_releases_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<Release, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases")
+            .segment(id)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_releases_latest(&self, owner: Owner, repo: RepoName) -> Result<Release, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases/latest")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_releases_tags_tag(&self, owner: Owner, repo: RepoName, tag: RefName) -> Result<Release, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases/tags")
+            .tail(tag.as_str())
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn post_repos_owner_repo_releases(&self, owner: Owner, repo: RepoName, release: ReleaseParam) -> Result<Release, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases")
+            .json(&release)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn patch_repos_owner_repo_releases_id(&self, owner: Owner, repo: RepoName, id: u64, release: ReleaseEdit) -> Result<Release, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases")
+            .segment(id)
+            .json(&release)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_releases_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases")
+            .segment(id)
+            .send_empty()
+            .map(|_| ())
+    }
+
+    fn get_repos_owner_repo_releases_id_assets(&self, owner: Owner, repo: RepoName, id: u64) -> Result<Vec<ReleaseAsset>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases")
+            .segment(id)
+            .path("/assets")
+            .query("per_page", 100u32)
+            .send_all()
+    }
+
+
+    fn post_repos_owner_repo_releases_id_assets<R: Read>(&self,
+                                                         owner: Owner,
+                                                         repo: RepoName,
+                                                         id: u64,
+                                                         name: String,
+                                                         label: Option<String>,
+                                                         content_type: Mime,
+                                                         length: u64,
+                                                         asset: &mut R) -> Result<ReleaseAsset, error::Error> {
+        self.request(Method::Post, "/repos")
+            .uploads()
+            .segment(owner)
+            .segment(repo)
+            .path("/releases")
+            .segment(id)
+            .path("/assets")
+            .query("name", name)
+            .query_opt("label", label)
+            .header(ContentType(content_type))
+            .upload(asset, length)
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_releases_assets_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<ReleaseAsset, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases/assets")
+            .segment(id)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn download_repos_owner_repo_releases_assets_id<W: Write>(&self, owner: Owner, repo: RepoName, id: u64, writer: &mut W) -> Result<u64, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases/assets")
+            .segment(id)
+            .header(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::OctetStream, vec![]))]))
+            .download(writer)
+    }
+
+    fn patch_repos_owner_repo_releases_assets_id(&self, owner: Owner, repo: RepoName, id: u64, asset: AssetEdit) -> Result<ReleaseAsset, error::Error> {
+        self.request(Method::Patch, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases/assets")
+            .segment(id)
+            .json(&asset)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn delete_repos_owner_repo_releases_assets_id(&self, owner: Owner, repo: RepoName, id: u64) -> Result<(), error::Error> {
+        self.request(Method::Delete, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/releases/assets")
+            .segment(id)
+            .send_empty()
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod releases_test {
+
+    use serde_json;
+
+    use super::{Release, ReleaseEdit};
+
+    #[test]
+    fn release() {
+        let release: Release = serde_json::from_str(include_str!("../../tests/fixtures/repositories/release.json")).unwrap();
+        assert!(release.tag_name == "v1.0.0");
+        assert!(release.author.login == "octocat");
+        assert!(release.upload_url.ends_with("/releases/1/assets{?name,label}"));
+
+        let asset = &release.assets[0];
+        assert!(asset.name == "example.zip");
+        assert!(asset.label == Some(String::from("short description")));
+        assert!(asset.size == 1024 && asset.download_count == 42);
+        assert!(asset.uploader.as_ref().map(|uploader| &uploader.login[..]) == Some("octocat"));
+    }
+
+    #[test]
+    fn release_edit() {
+        let edit = ReleaseEdit::new().name("v1.0.1").draft(false);
+        assert!(serde_json::to_string(&edit).unwrap() == r#"{"name":"v1.0.1","draft":false}"#);
+
+        let edit = ReleaseEdit::new().clear_name().clear_body();
+        assert!(serde_json::to_string(&edit).unwrap() == r#"{"name":null,"body":null}"#);
+
+        assert!(serde_json::to_string(&ReleaseEdit::new()).unwrap() == "{}");
+    }
+}
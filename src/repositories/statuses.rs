@@ -0,0 +1,343 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Statuses
+//!
+//! These are the responses and API call functions related
+//! to the statuses endpoints of the API.
+//!
+//! Reference: https://developer.github.com/v3/repos/statuses/
+
+use std::time::Duration;
+
+use hyper::header::{Accept, qitem};
+use hyper::method::Method;
+use hyper::mime::{Mime, TopLevel, SubLevel};
+
+use client::Client;
+use common::{DateTime, Repository, User};
+use error;
+use path::{Owner, RefName, RepoName};
+use utils;
+
+string_enum! {
+    /// State of a status or of the combined status of a
+    /// reference.
+    pub enum StatusState {
+        Pending => "pending",
+        Success => "success",
+        Error   => "error",
+        Failure => "failure"
+    }
+}
+
+impl StatusState {
+
+    /// Returns whether the state is `Error` or `Failure`.
+    pub fn is_failed(&self) -> bool {
+        match *self {
+            StatusState::Error   |
+            StatusState::Failure => true,
+            _                    => false
+        }
+    }
+}
+
+/// Return type of the statuses endpoints.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Status {
+    pub id:          u64,
+    pub url:         String,
+    pub state:       StatusState,
+    pub target_url:  Option<String>,
+    pub description: Option<String>,
+    /// Label differentiating the status from the statuses
+    /// of other systems, ex. "continuous-integration/travis-ci".
+    pub context:     String,
+    pub creator:     Option<User>,
+    pub created_at:  DateTime,
+    pub updated_at:  DateTime
+}
+
+/// Return type of the combined status endpoint, the latest
+/// status of each context of a reference.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CombinedStatus {
+    /// `Failure` if a status is `Error` or `Failure`,
+    /// `Pending` if there are no statuses or a status is
+    /// `Pending`, otherwise `Success`.
+    pub state:       StatusState,
+    pub sha:         String,
+    pub total_count: u64,
+    pub statuses:    Vec<Status>,
+    pub repository:  Repository,
+    pub commit_url:  String,
+    pub url:         String
+}
+
+impl CombinedStatus {
+
+    /// Returns the latest status of a context, if any.
+    pub fn status(&self, context: &str) -> Option<&Status> {
+        self.statuses.iter().find(|status| status.context == context)
+    }
+
+    /// Returns whether the statuses of `contexts` are
+    /// settled, either all of them are `Success` or one of
+    /// them failed. With no contexts, the combined state is
+    /// used.
+    pub fn is_settled(&self, contexts: &[String]) -> bool {
+        if contexts.is_empty() {
+            return self.state != StatusState::Pending;
+        }
+
+        let states = contexts.iter()
+                             .map(|context| self.status(&context[..]).map(|status| status.state.clone()))
+                             .collect::<Vec<Option<StatusState>>>();
+
+        states.iter().any(|state| state.as_ref().map_or(false, |state| state.is_failed())) ||
+        states.iter().all(|state| *state == Some(StatusState::Success))
+    }
+}
+
+/// Parameters for the creation of a status.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StatusParam {
+    /// State of the status.
+    pub state:       StatusState,
+    /// URL of the details of the status, ex. the build
+    /// output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_url:  Option<String>,
+    /// Short description of the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Label differentiating the status from the statuses
+    /// of other systems, "default" if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context:     Option<String>
+}
+
+impl StatusParam {
+
+    /// Creates the parameters of a status with the
+    /// "default" context.
+    pub fn new(state: StatusState) -> StatusParam {
+        StatusParam {
+            state:       state,
+            target_url:  None,
+            description: None,
+            context:     None
+        }
+    }
+}
+
+//A full SHA is used as is, anything else is resolved
+fn is_sha(_ref: &RefName) -> bool {
+    _ref.as_str().len() == 40 && _ref.as_str().chars().all(|c| c.is_digit(16))
+}
+
+//Returns the SHA of the commit of a branch, a tag or a
+//qualified ref, ex. "tags/v1.0", as the API resolves them
+fn commit_sha(client: &Client, owner: Owner, repo: RepoName, _ref: RefName) -> Result<RefName, error::Error> {
+    let mut response = try!(client.request(Method::Get, "/repos")
+                                  .segment(owner)
+                                  .segment(repo)
+                                  .path("/commits")
+                                  .tail(_ref.as_str())
+                                  .header(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext("vnd.github.sha".to_string()), vec![]))]))
+                                  .send_raw());
+    let sha = try!(Client::response_to_string(&mut response));
+    RefName::new(sha.trim())
+}
+
+
+////////////////////////////////////////////////////////////
+//                    Extension Trait                     //
+////////////////////////////////////////////////////////////
+
+pub trait StatusesExt {
+
+    /// \[[Reference](https://developer.github.com/v3/repos/statuses/#create-a-status)\]
+    /// Creates a status for a commit.
+    /// ## Endpoint:
+    /// POST /repos/:owner/:repo/statuses/:sha
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `sha`: SHA of the commit.
+    /// * `status`: Parameters of the status.
+    fn post_repos_owner_repo_statuses_sha(&self, owner: Owner, repo: RepoName, sha: String, status: StatusParam) -> Result<Status, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/statuses/#list-statuses-for-a-specific-ref)\]
+    /// Returns the statuses of a reference, most recent
+    /// first.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/commits/:ref/statuses
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `_ref`: SHA, branch name or tag name.
+    fn get_repos_owner_repo_commits_ref_statuses(&self, owner: Owner, repo: RepoName, _ref: RefName) -> Result<Vec<Status>, error::Error>;
+
+    /// \[[Reference](https://developer.github.com/v3/repos/statuses/#get-the-combined-status-for-a-specific-ref)\]
+    /// Returns the combined status of a reference.
+    /// ## Endpoint:
+    /// GET /repos/:owner/:repo/commits/:ref/status
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `_ref`: SHA, branch name or tag name.
+    fn get_repos_owner_repo_commits_ref_status(&self, owner: Owner, repo: RepoName, _ref: RefName) -> Result<CombinedStatus, error::Error>;
+
+    /// Waits until the statuses of `contexts` are settled
+    /// for a commit, see `CombinedStatus::is_settled`. A
+    /// branch or a tag is resolved to its SHA first so a
+    /// later push to it does not change the polled commit. The combined
+    /// status is polled with an increasing delay, blocking
+    /// the current thread.
+    /// ## Parameters
+    /// * `owner`: Owner of the repository.
+    /// * `repo`: Name of the repository.
+    /// * `_ref`: SHA, branch name or tag name.
+    /// * `contexts`: Required contexts, ex.
+    /// "continuous-integration/travis-ci".
+    /// * `timeout`: Maximum time to wait, a `TimedOut`
+    /// error is returned past it.
+    fn wait_repos_owner_repo_commits_ref_status(&self, owner: Owner, repo: RepoName, _ref: RefName, contexts: Vec<String>, timeout: Duration) -> Result<CombinedStatus, error::Error>;
+}
+
+impl StatusesExt for Client {
+
+    fn post_repos_owner_repo_statuses_sha(&self, owner: Owner, repo: RepoName, sha: String, status: StatusParam) -> Result<Status, error::Error> {
+        self.request(Method::Post, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/statuses")
+            .segment(sha)
+            .json(&status)
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_commits_ref_statuses(&self, owner: Owner, repo: RepoName, _ref: RefName) -> Result<Vec<Status>, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/commits")
+            .tail(_ref.as_str())
+            .path("/statuses")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn get_repos_owner_repo_commits_ref_status(&self, owner: Owner, repo: RepoName, _ref: RefName) -> Result<CombinedStatus, error::Error> {
+        self.request(Method::Get, "/repos")
+            .segment(owner)
+            .segment(repo)
+            .path("/commits")
+            .tail(_ref.as_str())
+            .path("/status")
+            .send()
+            .map(|response| response.data)
+    }
+
+    fn wait_repos_owner_repo_commits_ref_status(&self, owner: Owner, repo: RepoName, _ref: RefName, contexts: Vec<String>, timeout: Duration) -> Result<CombinedStatus, error::Error> {
+
+        let sha = if is_sha(&_ref) {
+            _ref
+        } else {
+            try!(commit_sha(self, owner.clone(), repo.clone(), _ref))
+        };
+
+        utils::poll_with_backoff(timeout, || {
+            let status = try!(self.get_repos_owner_repo_commits_ref_status(owner.clone(), repo.clone(), sha.clone()));
+            Ok(if status.is_settled(&contexts[..]) { Some(status) } else { None })
+        })
+    }
+}
+
+#[cfg(test)]
+mod statuses_test {
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    use serde_json;
+
+    use auth::auth::Auth;
+    use client::Client;
+    use path::{Owner, RefName, RepoName};
+    use super::{CombinedStatus, StatusState, StatusesExt};
+
+    //Answers `responses` in order, one per connection, and
+    //returns the request line of each request
+    fn serve(responses: Vec<String>) -> (Client, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address  = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            responses.into_iter().map(|response| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request_line = String::new();
+                {
+                    let mut reader = BufReader::new(&stream);
+                    let mut line   = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+                    while reader.read_line(&mut line).unwrap() > 2 {
+                        line.clear();
+                    }
+                }
+                stream.write_all(response.as_bytes()).unwrap();
+                request_line
+            }).collect()
+        });
+        (Client::with_url(&address[..], "rustyhub-test/0.0.0", Auth::NoAuth), server)
+    }
+
+    fn response(body: &str) -> String {
+        format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body)
+    }
+
+    #[test]
+    fn combined_status() {
+        let mut combined: CombinedStatus = serde_json::from_str(include_str!("../../tests/fixtures/repositories/combined_status.json")).unwrap();
+        assert!(combined.state == StatusState::Pending);
+
+        let ci     = String::from("continuous-integration/jenkins");
+        let review = String::from("security/brakeman");
+        assert!(combined.is_settled(&[ci.clone()]));
+        assert!(!combined.is_settled(&[ci.clone(), review.clone()]));
+        assert!(!combined.is_settled(&[]));
+
+        combined.statuses[1].state = StatusState::Failure;
+        assert!(combined.is_settled(&[ci.clone(), review.clone()]));
+    }
+
+    #[test]
+    fn wait_tag() {
+        let (client, server) = serve(vec![
+            response("6dcb09b5b57875f334f61aebed695e2e4193db5e"),
+            response(include_str!("../../tests/fixtures/repositories/combined_status.json"))
+        ]);
+
+        let owner: Owner    = "octocat".parse().unwrap();
+        let repo:  RepoName = "Hello-World".parse().unwrap();
+        let tag:   RefName  = "v1.0".parse().unwrap();
+        let contexts        = vec![String::from("continuous-integration/jenkins")];
+
+        let status = client.wait_repos_owner_repo_commits_ref_status(owner, repo, tag, contexts, Duration::from_secs(60)).unwrap();
+        assert!(status.sha == "6dcb09b5b57875f334f61aebed695e2e4193db5e");
+
+        //The tag is resolved by the API, then its commit is polled
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /repos/octocat/Hello-World/commits/v1.0 "));
+        assert!(requests[1].starts_with("GET /repos/octocat/Hello-World/commits/6dcb09b5b57875f334f61aebed695e2e4193db5e/status "));
+    }
+}
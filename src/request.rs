@@ -0,0 +1,448 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Request
+//!
+//! Builder for requests to the API, it takes care of path
+//! escaping, query strings, media types and JSON bodies so
+//! endpoints only describe what they send and receive.
+//!
+//! ### Example
+//!
+//! ```rust,no_run
+//!
+//! extern crate hyper;
+//! extern crate rustyhub;
+//!
+//! use hyper::method::Method;
+//!
+//! use rustyhub::auth::auth::Auth;
+//! use rustyhub::client::Client;
+//! use rustyhub::common::Repository;
+//! use rustyhub::request::ApiResponse;
+//!
+//! fn main() {
+//!
+//!     let client = Client::new("rusyhub-UserAgent", Auth::NoAuth);
+//!
+//!     let response: ApiResponse<Vec<Repository>> = client.request(Method::Get, "/users")
+//!                                                        .segment("octocat")
+//!                                                        .path("/starred")
+//!                                                        .query("sort", "updated")
+//!                                                        .send()
+//!                                                        .unwrap();
+//!
+//!     println!("{} repositories, next page: {:?}", response.data.len(), response.links.get("next"));
+//! }
+//!
+//! ```
+
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+use hyper::client::Response;
+use hyper::header::{Accept, ContentLength, Header, HeaderFormat, Headers, qitem};
+use hyper::method::Method;
+use hyper::mime::{Mime, TopLevel, SubLevel};
+use hyper::status::StatusCode;
+use serde;
+use serde_json;
+use url::form_urlencoded;
+
+use client::Client;
+use common::DateTime;
+use error;
+use miscellaneous::rate_limit::RateLimitElement;
+use path;
+use preview::{self, Preview};
+use utils;
+
+/// Values which can be used in a query string.
+pub trait QueryValue {
+    /// Returns the value as expected by the API.
+    fn to_query(&self) -> String;
+}
+
+impl QueryValue for str {
+    fn to_query(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl QueryValue for String {
+    fn to_query(&self) -> String {
+        self.clone()
+    }
+}
+
+impl QueryValue for bool {
+    fn to_query(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl QueryValue for u32 {
+    fn to_query(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl QueryValue for u64 {
+    fn to_query(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl QueryValue for DateTime {
+    fn to_query(&self) -> String {
+        self.as_str().to_string()
+    }
+}
+
+impl<'a, T: QueryValue + ?Sized> QueryValue for &'a T {
+    fn to_query(&self) -> String {
+        (**self).to_query()
+    }
+}
+
+/// Typed response of a request along with its metadata.
+#[derive(Debug)]
+pub struct ApiResponse<D> {
+    /// Deserialized body of the response.
+    pub data:       D,
+    /// Status code of the response.
+    pub status:     StatusCode,
+    /// Headers of the response.
+    pub headers:    Headers,
+    /// Rate limit status reported by the response.
+    pub rate_limit: Option<RateLimitElement>,
+    /// Pagination links indexed by their `rel` value, ex.
+    /// "next", "last".
+    pub links:      BTreeMap<String, String>
+}
+
+/// Builder for a request to an endpoint of the API.
+pub struct RequestBuilder<'a> {
+    client:  &'a Client,
+    method:  Method,
+    //API or uploads URL the path is appended to
+    base:    String,
+    path:    String,
+    query:   Vec<(String, String)>,
+    headers: Headers,
+    body:    Option<String>,
+    //Error deferred until the request is sent
+    error:   Option<error::Error>
+}
+
+impl<'a> RequestBuilder<'a> {
+
+    /// Creates a request to an endpoint of the API with the
+    /// default headers of the client.
+    /// ## Parameters
+    /// * `path`: Literal path of the endpoint, ex. "/users",
+    /// it is not escaped.
+    pub fn new(client: &'a Client, method: Method, path: &str) -> RequestBuilder<'a> {
+        RequestBuilder {
+            client:  client,
+            method:  method,
+            base:    client.api_url.clone(),
+            path:    path.to_string(),
+            query:   Vec::new(),
+            headers: client.get_default_headers(),
+            body:    None,
+            error:   None
+        }
+    }
+
+    /// Sends the request to the uploads URL of the client
+    /// rather than its API URL, ex. for release assets.
+    pub fn uploads(mut self) -> RequestBuilder<'a> {
+        self.base = self.client.uploads_url.clone();
+        self
+    }
+
+    /// Appends a literal part of the path, ex. "/issues",
+    /// it is not escaped.
+    pub fn path(mut self, path: &str) -> RequestBuilder<'a> {
+        self.path.push_str(path);
+        self
+    }
+
+    /// Appends a segment to the path, escaping the
+    /// characters having a meaning in a path, ex. '/', '#'
+    /// or '?'.
+    pub fn segment<S: QueryValue>(mut self, segment: S) -> RequestBuilder<'a> {
+        self.path.push('/');
+        self.path.push_str(&path::escape_segment(&segment.to_query()[..])[..]);
+        self
+    }
+
+    /// Appends a tail to the path, ex. a reference or a
+    /// file path, escaping each of its '/' separated
+    /// segments.
+    pub fn tail(mut self, tail: &str) -> RequestBuilder<'a> {
+        self.path.push('/');
+        self.path.push_str(&path::escape_tail(tail)[..]);
+        self
+    }
+
+    /// Adds a parameter to the query string.
+    pub fn query<V: QueryValue>(mut self, name: &str, value: V) -> RequestBuilder<'a> {
+        self.query.push((name.to_string(), value.to_query()));
+        self
+    }
+
+    /// Adds a parameter to the query string if it has a
+    /// value.
+    pub fn query_opt<V: QueryValue>(self, name: &str, value: Option<V>) -> RequestBuilder<'a> {
+        match value {
+            Some(value) => self.query(name, value),
+            None        => self
+        }
+    }
+
+    /// Adds a comma separated list to the query string if
+    /// it is not empty, ex. the labels of issues.
+    pub fn query_list<V: QueryValue>(self, name: &str, values: &[V]) -> RequestBuilder<'a> {
+        if values.is_empty() {
+            return self;
+        }
+        let value = values.iter().map(|value| value.to_query()).collect::<Vec<String>>().join(",");
+        self.query(name, value)
+    }
+
+    /// Replaces the Accept header by a Github media type.
+    /// ## Parameters
+    /// * `media_type`: The media type, ex.
+    /// "vnd.github.v3.star+json".
+    pub fn accept(mut self, media_type: &str) -> RequestBuilder<'a> {
+        self.headers.remove::<Accept>();
+        self.headers.set(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Ext(media_type.to_string()), vec![]))]));
+        self
+    }
+
+    /// Adds the media type of a preview to the Accept
+    /// header.
+    pub fn preview(mut self, preview: Preview) -> RequestBuilder<'a> {
+        preview::add_preview(&mut self.headers, preview);
+        self
+    }
+
+    /// Sets a header of the request.
+    pub fn header<H: Header + HeaderFormat>(mut self, header: H) -> RequestBuilder<'a> {
+        self.headers.set(header);
+        self
+    }
+
+    /// Sets the body of the request to the JSON form of
+    /// `body`.
+    pub fn json<S: serde::Serialize>(mut self, body: &S) -> RequestBuilder<'a> {
+        match serde_json::to_string(body) {
+            Ok(body_data) => self.body  = Some(body_data),
+            Err(err)      => self.error = Some(error::Error::Parsing(err))
+        }
+        self
+    }
+
+    /// Returns the endpoint, with its query string, the
+    /// request will be sent to.
+    pub fn endpoint(&self) -> String {
+        if self.query.is_empty() {
+            self.path.clone()
+        } else {
+            let query = form_urlencoded::Serializer::new(String::new()).extend_pairs(self.query.iter()).finish();
+            format!("{}?{}", self.path, query)
+        }
+    }
+
+    /// Sends the request and returns the raw response.
+    pub fn send_raw(self) -> Result<Response, error::Error> {
+
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let     url     = format!("{}{}", self.base, self.endpoint());
+        let mut headers = self.headers;
+
+        match self.body {
+            Some(body_data) => self.client.request_url_body(self.method, url, Some(headers), body_data),
+            None            => {
+                if self.method == Method::Put || self.method == Method::Post || self.method == Method::Patch {
+                    headers.set(ContentLength(0u64));
+                }
+                self.client.request_url(self.method, url, Some(headers))
+            }
+        }
+    }
+
+    /// Sends the request and deserializes the response.
+    pub fn send<D: serde::Deserialize>(self) -> Result<ApiResponse<D>, error::Error> {
+        let mut response     = try!(self.send_raw());
+        let     response_str = try!(Client::response_to_string(&mut response));
+        let     data         = try!(serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing));
+        Ok(to_api_response(data, response))
+    }
+
+    /// Sends the request and the requests of the next pages
+    /// of the response, returns the elements of all pages.
+    pub fn send_all<D: serde::Deserialize>(self) -> Result<Vec<D>, error::Error> {
+        self.send_all_with(|page: Vec<D>| page)
+    }
+
+    /// Same as `send_all` for endpoints wrapping the elements
+    /// of a page in an object, ex. `{"total_count": 2,
+    /// "check_runs": [...]}`, `elements` returns the elements
+    /// of a page.
+    pub fn send_all_with<P, D, F>(self, elements: F) -> Result<Vec<D>, error::Error>
+        where P: serde::Deserialize,
+              F: Fn(P) -> Vec<D>
+    {
+        let client  = self.client;
+        let method  = self.method.clone();
+        let headers = self.headers.clone();
+
+        let mut all_elements = Vec::new();
+        let mut response: ApiResponse<P> = try!(self.send());
+
+        loop {
+            all_elements.append(&mut elements(response.data));
+
+            let next = match response.links.get("next") {
+                Some(url) => url.clone(),
+                None      => return Ok(all_elements)
+            };
+
+            let mut page     = try!(client.request_url(method.clone(), next, Some(headers.clone())));
+            let     page_str = try!(Client::response_to_string(&mut page));
+            let     data     = try!(serde_json::from_str(&page_str[..]).map_err(error::Error::Parsing));
+            response = to_api_response(data, page);
+        }
+    }
+
+    /// Sends the request and writes the body of the
+    /// response to `writer`, following redirects to the
+    /// storage of files, ex. archives or assets. Returns the
+    /// number of bytes written.
+    pub fn download<W: Write>(self, writer: &mut W) -> Result<u64, error::Error> {
+
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let url = format!("{}{}", self.base, self.endpoint());
+        self.client.download_url(url, Some(self.headers), writer)
+    }
+
+    /// Sends the request with the content of `reader` as its
+    /// body, streamed rather than buffered, and
+    /// deserializes the response. The content type of the
+    /// body is set with `header`.
+    /// ## Parameters
+    /// * `length`: Number of bytes read from `reader`.
+    pub fn upload<D: serde::Deserialize, R: Read>(self, reader: &mut R, length: u64) -> Result<ApiResponse<D>, error::Error> {
+
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let     url          = format!("{}{}", self.base, self.endpoint());
+        let mut response     = try!(self.client.upload_url(self.method, url, Some(self.headers), reader, length));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        let     data         = try!(serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing));
+        Ok(to_api_response(data, response))
+    }
+
+    /// Sends the request and ignores the body of the
+    /// response, for endpoints returning no content.
+    pub fn send_empty(self) -> Result<ApiResponse<()>, error::Error> {
+        let response = try!(self.send_raw());
+        Ok(to_api_response((), response))
+    }
+
+    /// Returns the request of the first page of a paginated
+    /// endpoint, detached from the client so the pages can
+    /// be requested later or from another thread.
+    pub fn page(self) -> Result<PageRequest, error::Error> {
+
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        Ok(PageRequest {
+            url:     format!("{}{}", self.base, self.endpoint()),
+            method:  self.method,
+            headers: self.headers
+        })
+    }
+}
+
+/// Request of a page of a paginated endpoint, see
+/// `RequestBuilder::page`.
+#[derive(Clone, Debug)]
+pub struct PageRequest {
+    method:  Method,
+    url:     String,
+    headers: Headers
+}
+
+impl PageRequest {
+
+    /// Sends the request, returns the elements of the page
+    /// and the request of the next page, if any.
+    pub fn send<D: serde::Deserialize>(self, client: &Client) -> Result<(Vec<D>, Option<PageRequest>), error::Error> {
+        let mut response     = try!(client.request_url(self.method.clone(), self.url, Some(self.headers.clone())));
+        let     response_str = try!(Client::response_to_string(&mut response));
+        let     page         = try!(serde_json::from_str(&response_str[..]).map_err(error::Error::Parsing));
+        let     next         = utils::get_links(&response).remove("next").map(|url| PageRequest {
+            method:  self.method,
+            url:     url,
+            headers: self.headers
+        });
+        Ok((page, next))
+    }
+}
+
+fn to_api_response<D>(data: D, response: Response) -> ApiResponse<D> {
+    ApiResponse {
+        data:       data,
+        status:     response.status,
+        rate_limit: utils::get_rate_limit(&response.headers),
+        links:      utils::get_links(&response),
+        headers:    response.headers.clone()
+    }
+}
+
+#[cfg(test)]
+mod request_test {
+
+    use hyper::method::Method;
+
+    use auth::auth::Auth;
+    use client::Client;
+
+    #[test]
+    fn request_endpoint() {
+
+        let client = Client::new("rustyhub-test", Auth::NoAuth);
+
+        let request = client.request(Method::Get, "/repos")
+                            .segment("owner")
+                            .segment("a repo/#1")
+                            .path("/issues")
+                            .query("state", "open")
+                            .query_opt("since", None::<String>)
+                            .query_list("labels", &["bug", "help wanted"])
+                            .query("per_page", 100u64);
+        assert!(request.endpoint() == String::from("/repos/owner/a%20repo%2F%231/issues?state=open&labels=bug%2Chelp+wanted&per_page=100"));
+
+        let request = client.request(Method::Get, "/user/starred")
+                            .query_list::<&str>("labels", &[]);
+        assert!(request.endpoint() == String::from("/user/starred"));
+    }
+}
@@ -0,0 +1,321 @@
+// Copyright 2016 Alex Frappier Lachapelle
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Thread Pool
+//!
+//! Runs requests of the blocking client on a pool of
+//! threads and returns their results as futures, available
+//! with the `thread-pool` feature. The requests are still
+//! blocking, each request in flight holds one of the
+//! threads of the pool.
+//!
+//! ### Example
+//!
+//! ```rust,no_run
+//!
+//! extern crate futures;
+//! extern crate hyper;
+//! extern crate rustyhub;
+//!
+//! use futures::{Future, Stream};
+//! use hyper::method::Method;
+//! use rustyhub::auth::auth::Auth;
+//! use rustyhub::issues::{Issue, IssuesExt};
+//! use rustyhub::path::{Owner, RepoName};
+//! use rustyhub::thread_pool::ThreadPool;
+//!
+//! fn main() {
+//!
+//!     let pool = ThreadPool::new("rustyhub-UserAgent", Auth::NoAuth, 8);
+//!
+//!     let owner: Owner    = "rust-lang".parse().unwrap();
+//!     let repo:  RepoName = "rust".parse().unwrap();
+//!
+//!     let request = pool.client().request(Method::Get, "/repos")
+//!                                .segment(owner.clone())
+//!                                .segment(repo.clone())
+//!                                .path("/issues")
+//!                                .query("per_page", 100u32);
+//!
+//!     let issues: Vec<Issue> = pool.paginate(request)
+//!                                  .take(200)
+//!                                  .collect()
+//!                                  .wait()
+//!                                  .unwrap();
+//!
+//!     let issue = pool.spawn(move |client| client.get_repos_owner_repo_issues_number(owner, repo, 1))
+//!                     .wait()
+//!                     .unwrap();
+//! }
+//!
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use futures::{Async, Future, Poll, Stream};
+use futures::stream;
+use futures_cpupool::{CpuFuture, CpuPool};
+use serde;
+
+use auth::auth::Auth;
+use client::Client;
+use error;
+use request::{PageRequest, RequestBuilder};
+
+/// Pool of threads running requests of a blocking client,
+/// cloning it is cheap and clones share the same threads
+/// and client.
+#[derive(Clone)]
+pub struct ThreadPool {
+    pool:   CpuPool,
+    client: Client
+}
+
+impl ThreadPool {
+
+    /// Creates a pool with a client using the default API
+    /// URL.
+    /// ## Parameters
+    /// * `user_agent`: Value of the User-Agent header.
+    /// * `auth`: Authentication method.
+    /// * `threads`: Number of threads, which is the number
+    /// of requests that can be run at the same time.
+    pub fn new(user_agent: &str, auth: Auth, threads: usize) -> ThreadPool {
+        ThreadPool::from_client(&Client::new(user_agent, auth), threads)
+    }
+
+    /// Creates a pool sharing the connection pool and the
+    /// settings of a client, ex. one created with
+    /// `Client::enterprise`.
+    /// ## Parameters
+    /// * `client`: The blocking client.
+    /// * `threads`: Number of threads, which is the number
+    /// of requests that can be run at the same time.
+    pub fn from_client(client: &Client, threads: usize) -> ThreadPool {
+        ThreadPool {
+            pool:   CpuPool::new(threads),
+            client: client.clone()
+        }
+    }
+
+    /// Returns the client used to run the requests.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Runs a request on the pool, the closure is given a
+    /// client on which any of the endpoint extension traits
+    /// can be used.
+    pub fn spawn<F, T>(&self, request: F) -> CpuFuture<T, error::Error>
+        where F: FnOnce(&Client) -> Result<T, error::Error> + Send + 'static,
+              T: Send + 'static
+    {
+        let client = self.client.clone();
+        self.pool.spawn_fn(move || request(&client))
+    }
+
+    /// Returns a stream of the elements of a paginated
+    /// endpoint, pages are requested as the stream is
+    /// consumed.
+    /// ## Parameters
+    /// * `request`: Request of the first page, ex. built
+    /// with `client().request(...)`.
+    pub fn paginate<D>(&self, request: RequestBuilder) -> PageStream<D>
+        where D: serde::Deserialize + Send + 'static
+    {
+        let pending = match request.page() {
+            Ok(page) => self.spawn(move |client| page.send(client)),
+            Err(err) => self.spawn(move |_| Err(err))
+        };
+
+        PageStream {
+            pool:    self.clone(),
+            buffer:  VecDeque::new(),
+            pending: Some(pending)
+        }
+    }
+
+    /// Runs a request for each item with at most `limit`
+    /// requests in flight, results are returned in the
+    /// order they complete.
+    /// ## Parameters
+    /// * `items`: Items to make a request for, ex. the
+    /// names of the repositories of an organization.
+    /// * `limit`: Maximum number of concurrent requests,
+    /// bounded by the number of threads, 0 is treated as 1.
+    /// * `request`: The request to make for an item.
+    pub fn fan_out<I, F, T>(&self, items: I, limit: usize, request: F) -> Box<Stream<Item = T, Error = error::Error> + Send>
+        where I: IntoIterator,
+              I::IntoIter: Send + 'static,
+              I::Item: Send + 'static,
+              F: Fn(&Client, I::Item) -> Result<T, error::Error> + Send + Sync + 'static,
+              T: Send + 'static
+    {
+        let pool    = self.clone();
+        let request = Arc::new(request);
+
+        Box::new(stream::iter_ok(items.into_iter())
+            .map(move |item| {
+                let request = request.clone();
+                pool.spawn(move |client| request(client, item))
+            })
+            .buffer_unordered(limit.max(1)))
+    }
+}
+
+/// Stream of the elements of a paginated endpoint, see
+/// `ThreadPool::paginate`. The stream ends after the last
+/// page or after returning an error.
+pub struct PageStream<D: Send + 'static> {
+    pool:    ThreadPool,
+    buffer:  VecDeque<D>,
+    //Request of the next page, `None` once the stream ended
+    pending: Option<CpuFuture<(Vec<D>, Option<PageRequest>), error::Error>>
+}
+
+impl<D> Stream for PageStream<D>
+    where D: serde::Deserialize + Send + 'static
+{
+    type Item  = D;
+    type Error = error::Error;
+
+    fn poll(&mut self) -> Poll<Option<D>, error::Error> {
+        loop {
+
+            if let Some(item) = self.buffer.pop_front() {
+                return Ok(Async::Ready(Some(item)));
+            }
+
+            let (page, next) = match self.pending.as_mut().map(Future::poll) {
+                Some(Ok(Async::Ready(page))) => page,
+                Some(Ok(Async::NotReady))    => return Ok(Async::NotReady),
+                Some(Err(err))               => {
+                    self.pending = None;
+                    return Err(err);
+                },
+                None                         => return Ok(Async::Ready(None))
+            };
+
+            self.buffer.extend(page);
+
+            let pool     = &self.pool;
+            let request  = next.map(|next| pool.spawn(move |client| next.send(client)));
+            self.pending = request;
+        }
+    }
+}
+
+#[cfg(test)]
+mod thread_pool_test {
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use futures::{Future, Stream};
+    use hyper::method::Method;
+
+    use auth::auth::Auth;
+    use client::Client;
+    use error;
+    use super::ThreadPool;
+
+    //Answers the responses built from the address of the
+    //server in order, one per connection, and returns the
+    //request line of each request
+    fn serve<F>(responses: F) -> (ThreadPool, thread::JoinHandle<Vec<String>>)
+        where F: FnOnce(&str) -> Vec<String>
+    {
+        let listener  = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address   = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses(&address[..]);
+
+        let server = thread::spawn(move || {
+            responses.into_iter().map(|response| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request_line = String::new();
+                {
+                    let mut reader = BufReader::new(&stream);
+                    let mut line   = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+                    while reader.read_line(&mut line).unwrap() > 2 {
+                        line.clear();
+                    }
+                }
+                stream.write_all(response.as_bytes()).unwrap();
+                request_line
+            }).collect()
+        });
+
+        let client = Client::with_url(&address[..], "rustyhub-test/0.0.0", Auth::NoAuth);
+        (ThreadPool::from_client(&client, 2), server)
+    }
+
+    fn response(status: &str, links: &str, body: &str) -> String {
+        format!("HTTP/1.1 {}\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status, links, body.len(), body)
+    }
+
+    #[test]
+    fn spawn() {
+        let (pool, server) = serve(|_| vec![response("200 OK", "", "[1, 2, 3]")]);
+
+        let items: Vec<u64> = pool.spawn(|client| client.request(Method::Get, "/items").send_all())
+                                  .wait()
+                                  .unwrap();
+        assert!(items == vec![1, 2, 3]);
+        assert!(server.join().unwrap()[0].starts_with("GET /items "));
+    }
+
+    #[test]
+    fn paginate() {
+        let (pool, server) = serve(|address| vec![
+            response("200 OK", &format!("Link: <{}/items?page=2>; rel=\"next\"\r\n", address), "[1, 2]"),
+            response("200 OK", "", "[3]")
+        ]);
+
+        let request = pool.client().request(Method::Get, "/items");
+        let items: Vec<u64> = pool.paginate(request).collect().wait().unwrap();
+        assert!(items == vec![1, 2, 3]);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /items "));
+        assert!(requests[1].starts_with("GET /items?page=2 "));
+    }
+
+    #[test]
+    fn paginate_error() {
+        let (pool, server) = serve(|_| vec![response("404 Not Found", "", "{\"message\": \"Not Found\"}")]);
+
+        //The stream ends after the error
+        let request   = pool.client().request(Method::Get, "/items");
+        let mut items = pool.paginate::<u64>(request).wait();
+        match items.next() {
+            Some(Err(error::Error::Github(err))) => assert!(err.message == "Not Found"),
+            _ => panic!("expected Error::Github")
+        }
+        assert!(items.next().is_none());
+        assert!(server.join().unwrap().len() == 1);
+    }
+
+    #[test]
+    fn fan_out() {
+        let pool = ThreadPool::from_client(&Client::new("rustyhub-test/0.0.0", Auth::NoAuth), 4);
+
+        let mut items: Vec<u64> = pool.fan_out(0..20, 4, |_, item: u64| Ok(item * 2))
+                                      .collect()
+                                      .wait()
+                                      .unwrap();
+        items.sort();
+        assert!(items == (0..20).map(|item| item * 2).collect::<Vec<u64>>());
+
+        //A limit of 0 still makes progress
+        let items: Vec<u64> = pool.fan_out(vec![1], 0, |_, item: u64| Ok(item)).collect().wait().unwrap();
+        assert!(items == vec![1]);
+    }
+}
@@ -7,12 +7,21 @@
 // copied, modified, or distributed except according to those terms.
 
 
+use std::cmp;
+use std::collections::BTreeMap;
+use std::io::{self, ErrorKind};
+use std::str;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hyper::client::Response;
 use hyper::header::Headers;
 use serde_json;
 use serde;
 
 use client::Client;
 use error;
+use miscellaneous::rate_limit::RateLimitElement;
 
 pub fn request_endpoint_with_headers<D:serde::Deserialize>(client: &Client, 
                                               endpoint: String,
@@ -26,3 +35,127 @@ pub fn request_endpoint<D:serde::Deserialize>(client: &Client,
                                               endpoint: String) -> Result<D, error::Error> {
     request_endpoint_with_headers(client, endpoint, None)
 }
+
+/// Returns the links of the `Link` header of a response,
+/// indexed by their `rel` value, ex. "next", "last".
+pub fn get_links(response: &Response) -> BTreeMap<String, String> {
+    match response.headers.get_raw("Link") {
+        Some(values) => {
+            let mut links = BTreeMap::new();
+            for value in values {
+                if let Ok(value_str) = str::from_utf8(&value[..]) {
+                    links.append(&mut parse_link_header(value_str));
+                }
+            }
+            links
+        },
+        None => BTreeMap::new()
+    }
+}
+
+/// Parses the value of a `Link` header.
+pub fn parse_link_header(value: &str) -> BTreeMap<String, String> {
+
+    let mut links = BTreeMap::new();
+
+    for link in value.split(',') {
+
+        let mut parts = link.split(';');
+        let url = match parts.next() {
+            Some(url) => url.trim().trim_left_matches('<').trim_right_matches('>'),
+            None      => continue
+        };
+
+        for param in parts {
+            let param = param.trim();
+            if param.starts_with("rel=") {
+                links.insert(param[4..].trim_matches('"').to_string(), url.to_string());
+            }
+        }
+    }
+
+    links
+}
+
+/// Returns the rate limit status reported by the headers
+/// of a response, if any.
+pub fn get_rate_limit(headers: &Headers) -> Option<RateLimitElement> {
+
+    let limit     = header_u64(headers, "X-RateLimit-Limit");
+    let remaining = header_u64(headers, "X-RateLimit-Remaining");
+    let reset     = header_u64(headers, "X-RateLimit-Reset");
+
+    match (limit, remaining, reset) {
+        (Some(limit), Some(remaining), Some(reset)) => Some(RateLimitElement {
+            limit:     limit,
+            remaining: remaining,
+            reset:     reset
+        }),
+        _ => None
+    }
+}
+
+fn header_u64(headers: &Headers, field: &str) -> Option<u64> {
+    headers.get_raw(field)
+           .and_then(|values| values.first())
+           .and_then(|value| str::from_utf8(&value[..]).ok())
+           .and_then(|value| value.trim().parse::<u64>().ok())
+}
+
+//Delays between the calls of poll_with_backoff, in seconds
+const POLL_FIRST_DELAY: u64 = 1;
+const POLL_MAX_DELAY:   u64 = 60;
+
+//Doubles the delay, up to the maximum
+fn next_delay(delay: u64) -> u64 {
+    cmp::min(delay * 2, POLL_MAX_DELAY)
+}
+
+/// Calls `poll` until it returns a value, waiting between
+/// the calls with a delay doubling from 1 up to 60 seconds.
+/// Blocks the current thread, a `TimedOut` error is returned
+/// once `timeout` is reached.
+pub fn poll_with_backoff<T, F>(timeout: Duration, mut poll: F) -> Result<T, error::Error>
+    where F: FnMut() -> Result<Option<T>, error::Error> {
+
+    let     start = Instant::now();
+    let mut delay = POLL_FIRST_DELAY;
+
+    loop {
+        if let Some(value) = try!(poll()) {
+            return Ok(value);
+        }
+
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return Err(error::Error::STDIO(io::Error::new(ErrorKind::TimedOut, "the expected state was not reached before the timeout")));
+        }
+
+        thread::sleep(cmp::min(Duration::from_secs(delay), timeout - elapsed));
+        delay = next_delay(delay);
+    }
+}
+
+#[cfg(test)]
+mod utils_test {
+
+    #[test]
+    fn parse_link_header() {
+        let links = super::parse_link_header("<https://api.github.com/user/repos?page=3&per_page=100>; rel=\"next\", \
+                                              <https://api.github.com/user/repos?page=50&per_page=100>; rel=\"last\"");
+        assert!(links.len() == 2);
+        assert!(links.get("next") == Some(&String::from("https://api.github.com/user/repos?page=3&per_page=100")));
+        assert!(links.get("last") == Some(&String::from("https://api.github.com/user/repos?page=50&per_page=100")));
+        assert!(super::parse_link_header("").is_empty());
+    }
+
+    #[test]
+    fn poll_backoff() {
+        let mut delays = vec![super::POLL_FIRST_DELAY];
+        for _ in 0..8 {
+            let delay = super::next_delay(*delays.last().unwrap());
+            delays.push(delay);
+        }
+        assert!(delays == vec![1, 2, 4, 8, 16, 32, 60, 60, 60]);
+    }
+}